@@ -1,12 +1,35 @@
 use crate::errors::ContractError;
 use crate::events::{
-    publish_account_initialized_event, publish_account_verified_event,
-    publish_refund_processed_event, publish_token_added_event,
+    publish_account_initialized_event, publish_account_paused_event,
+    publish_account_restriction_set_event, publish_account_unpaused_event,
+    publish_account_verified_event, publish_deposit_received_event,
+    publish_earmark_protection_set_event, publish_guardians_updated_event,
+    publish_invoice_funds_credited_event, publish_invoice_funds_released_event,
+    publish_recovery_approved_event, publish_recovery_cancelled_event,
+    publish_recovery_executed_event, publish_recovery_started_event,
+    publish_refund_processed_event, publish_signers_updated_event, publish_token_added_event,
+    publish_withdrawal_approved_event, publish_withdrawal_cancelled_event,
+    publish_withdrawal_requested_event, publish_withdrawal_to_event,
 };
 use crate::interface::MerchantAccountTrait;
-use crate::types::{AccountInfo, DataKey, TokenBalance};
-use soroban_sdk::{contract, contractimpl, panic_with_error, token, Address, Env, Vec};
-use crate::events::publish_withdrawal_to_event;
+use crate::types::{
+    AccountDetails, AccountInfo, DataKey, RecoveryRequest, SignerSignature, StatementEntry,
+    StatementEntryKind, TokenBalance, WithdrawalRequest,
+};
+use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, token, Address, Bytes, BytesN, Env, Vec,
+};
+
+// Delay between a recovery being initiated and it becoming executable, giving
+// the current merchant a window to cancel a malicious recovery.
+pub const RECOVERY_TIMELOCK: u64 = 3 * 24 * 60 * 60;
+
+// Statement entries are stored in fixed-size pages; only the most recent
+// MAX_STATEMENT_PAGES pages are retained so storage stays bounded.
+pub const STATEMENT_PAGE_SIZE: u64 = 50;
+pub const MAX_STATEMENT_PAGES: u64 = 20;
 
 #[contract]
 pub struct MerchantAccount;
@@ -18,6 +41,33 @@ fn get_manager(env: &Env) -> Address {
         .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
 }
 
+fn get_merchant_address(env: &Env) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Merchant)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
+}
+
+// Once the merchant configures ed25519 signers the account authorizes itself
+// through `__check_auth`, so merchant-only calls need a quorum of those keys
+// rather than the merchant address's own signature.
+fn require_merchant_auth(env: &Env) -> Address {
+    let merchant = get_merchant_address(env);
+    if get_signer_list(env).is_empty() {
+        merchant.require_auth();
+    } else {
+        env.current_contract_address().require_auth();
+    }
+    merchant
+}
+
+fn get_signer_list(env: &Env) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Signers)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
 fn get_tracked_tokens(env: &Env) -> Vec<Address> {
     env.storage()
         .persistent()
@@ -32,6 +82,186 @@ fn is_restricted_account(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+fn is_paused_account(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+fn get_guardian_list(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Guardians)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn get_pending_recovery(env: &Env) -> RecoveryRequest {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecoveryRequest)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NoRecoveryInProgress))
+}
+
+fn assert_guardian(env: &Env, guardian: &Address) {
+    guardian.require_auth();
+    if !get_guardian_list(env).contains(guardian) {
+        panic_with_error!(env, ContractError::NotGuardian);
+    }
+}
+
+fn record_statement_entry(
+    env: &Env,
+    kind: StatementEntryKind,
+    token: &Address,
+    amount: i128,
+    counterparty: &Address,
+) {
+    let count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StatementCount)
+        .unwrap_or(0);
+    let page = count / STATEMENT_PAGE_SIZE;
+
+    let mut entries: Vec<StatementEntry> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StatementPage(page))
+        .unwrap_or_else(|| Vec::new(env));
+    entries.push_back(StatementEntry {
+        kind,
+        token: token.clone(),
+        amount,
+        counterparty: counterparty.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::StatementPage(page), &entries);
+    env.storage()
+        .persistent()
+        .set(&DataKey::StatementCount, &(count + 1));
+
+    // Drop the page that just fell out of the retention window.
+    if entries.len() == 1 && page >= MAX_STATEMENT_PAGES {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StatementPage(page - MAX_STATEMENT_PAGES));
+    }
+}
+
+// Funds deposited against an invoice stay earmarked for it until they are
+// refunded or the manager releases them. A refund for an earmarked invoice
+// is paid only from its own earmark; any other refund, and withdrawals while
+// protection is on, can only use the balance left after all earmarks.
+fn get_invoice_earmark(env: &Env, invoice_id: u64, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceFunds(invoice_id, token.clone()))
+        .unwrap_or(0)
+}
+
+fn get_earmarked_total(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EarmarkedBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+fn adjust_earmark(env: &Env, invoice_id: u64, token: &Address, delta: i128) {
+    let earmark = get_invoice_earmark(env, invoice_id, token) + delta;
+    let key = DataKey::InvoiceFunds(invoice_id, token.clone());
+    if earmark == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &earmark);
+    }
+
+    env.storage().persistent().set(
+        &DataKey::EarmarkedBalance(token.clone()),
+        &(get_earmarked_total(env, token) + delta),
+    );
+}
+
+fn unearmarked_balance(env: &Env, token: &Address) -> i128 {
+    let balance = token::TokenClient::new(env, token).balance(&env.current_contract_address());
+    balance - get_earmarked_total(env, token)
+}
+
+fn receive_deposit(
+    env: &Env,
+    kind: StatementEntryKind,
+    from: &Address,
+    token: &Address,
+    amount: i128,
+) {
+    from.require_auth();
+
+    if is_paused_account(env) {
+        panic_with_error!(env, ContractError::AccountPaused);
+    }
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let contract_address = env.current_contract_address();
+    let token_client = token::TokenClient::new(env, token);
+    token_client.transfer(from, &contract_address, &amount);
+    record_statement_entry(env, kind, token, amount, from);
+
+    publish_deposit_received_event(
+        env,
+        from.clone(),
+        token.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+}
+
+fn pay_out(env: &Env, token: &Address, amount: i128, recipient: &Address) {
+    let token_client = token::TokenClient::new(env, token);
+    let protected = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EarmarkProtection)
+        .unwrap_or(false);
+    let available = if protected {
+        unearmarked_balance(env, token)
+    } else {
+        token_client.balance(&env.current_contract_address())
+    };
+
+    if amount > available {
+        panic_with_error!(env, ContractError::InsufficientBalance);
+    }
+
+    token_client.transfer(&env.current_contract_address(), recipient, &amount);
+    record_statement_entry(
+        env,
+        StatementEntryKind::Withdrawal,
+        token,
+        amount,
+        recipient,
+    );
+
+    publish_withdrawal_to_event(
+        env,
+        token.clone(),
+        recipient.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+}
+
+fn get_withdrawal_request(env: &Env, request_id: u64) -> WithdrawalRequest {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WithdrawalRequest(request_id))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::WithdrawalRequestNotFound))
+}
+
 fn token_exists(tracked_tokens: &Vec<Address>, token: &Address) -> bool {
     for tracked_token in tracked_tokens.iter() {
         if tracked_token == token.clone() {
@@ -74,6 +304,24 @@ impl MerchantAccountTrait for MerchantAccount {
             .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized))
     }
 
+    fn get_account_info(env: Env) -> AccountDetails {
+        let account_info: AccountInfo = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountInfo)
+            .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized));
+
+        AccountDetails {
+            merchant: account_info.merchant,
+            manager: account_info.manager,
+            merchant_id: account_info.merchant_id,
+            restricted: is_restricted_account(&env),
+            paused: is_paused_account(&env),
+            verified: Self::is_verified_account(env.clone()),
+            date_created: account_info.date_created,
+        }
+    }
+
     fn add_token(env: Env, token: Address) {
         let manager = get_manager(&env);
         manager.require_auth();
@@ -90,19 +338,41 @@ impl MerchantAccountTrait for MerchantAccount {
         publish_token_added_event(&env, token, env.ledger().timestamp());
     }
 
-    fn refund(env: Env, token: Address, amount: i128, to: Address) {
-        let manager = get_manager(&env);
-        manager.require_auth();
+    fn refund(env: Env, invoice_id: u64, token: Address, amount: i128, to: Address) {
+        // Refunds may only be triggered by the managing Shade contract.
+        get_manager(&env).require_auth();
 
         if is_restricted_account(&env) {
             panic_with_error!(&env, ContractError::AccountRestricted);
         }
 
+        if amount <= 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+
+        let earmark = get_invoice_earmark(&env, invoice_id, &token);
+        if earmark > 0 {
+            if amount > earmark {
+                panic_with_error!(&env, ContractError::InsufficientInvoiceFunds);
+            }
+            adjust_earmark(&env, invoice_id, &token, -amount);
+        } else if amount > unearmarked_balance(&env, &token) {
+            panic_with_error!(&env, ContractError::InsufficientBalance);
+        }
+
         let contract_address = env.current_contract_address();
         let token_client = token::TokenClient::new(&env, &token);
         token_client.transfer(&contract_address, &to, &amount);
+        record_statement_entry(&env, StatementEntryKind::Refund, &token, amount, &to);
 
-        publish_refund_processed_event(&env, token, amount, to, env.ledger().timestamp());
+        publish_refund_processed_event(
+            &env,
+            invoice_id,
+            token,
+            amount,
+            to,
+            env.ledger().timestamp(),
+        );
     }
 
     fn has_token(env: Env, token: Address) -> bool {
@@ -145,30 +415,418 @@ impl MerchantAccountTrait for MerchantAccount {
             .get(&DataKey::Verified)
             .unwrap_or(false)
     }
+
+    fn set_restricted(env: Env, restricted: bool) {
+        get_manager(&env).require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Restricted, &restricted);
+        publish_account_restriction_set_event(&env, restricted, env.ledger().timestamp());
+    }
     fn withdraw_to(env: Env, token: Address, amount: i128, recipient: Address) {
-    // Only the merchant can initiate withdrawals to another account
-    let merchant: Address = env
-        .storage()
-        .persistent()
-        .get(&DataKey::Merchant)
-        .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized));
-    merchant.require_auth();
+        // Only the merchant can initiate withdrawals to another account
+        require_merchant_auth(&env);
 
-    let token_client = token::TokenClient::new(&env, &token);
-    let current_balance = token_client.balance(&env.current_contract_address());
+        // Restricted accounts can only move funds through a manager-approved
+        // withdrawal request.
+        if is_restricted_account(&env) {
+            panic_with_error!(&env, ContractError::AccountRestricted);
+        }
 
-    if amount > current_balance {
-        panic_with_error!(&env, ContractError::InsufficientBalance);
+        pay_out(&env, &token, amount, &recipient);
     }
 
-    token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+    fn set_guardians(env: Env, guardians: Vec<Address>, threshold: u32) {
+        require_merchant_auth(&env);
 
-    publish_withdrawal_to_event(
-        &env,
-        token,
-        recipient,
-        amount,
-        env.ledger().timestamp(),
-    );
+        // A repeated guardian only approves once, so it must not count
+        // towards the threshold.
+        for (i, guardian) in guardians.iter().enumerate() {
+            if guardians.first_index_of(&guardian) != Some(i as u32) {
+                panic_with_error!(&env, ContractError::DuplicateGuardian);
+            }
+        }
+        if threshold == 0 || threshold > guardians.len() {
+            panic_with_error!(&env, ContractError::InvalidThreshold);
+        }
+        if env.storage().persistent().has(&DataKey::RecoveryRequest) {
+            panic_with_error!(&env, ContractError::RecoveryInProgress);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Guardians, &guardians);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecoveryThreshold, &threshold);
+
+        publish_guardians_updated_event(&env, guardians, threshold, env.ledger().timestamp());
+    }
+
+    fn get_guardians(env: Env) -> Vec<Address> {
+        get_guardian_list(&env)
+    }
+
+    fn get_recovery_threshold(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecoveryThreshold)
+            .unwrap_or(0)
+    }
+
+    fn initiate_recovery(env: Env, guardian: Address, new_merchant: Address) {
+        assert_guardian(&env, &guardian);
+
+        if env.storage().persistent().has(&DataKey::RecoveryRequest) {
+            panic_with_error!(&env, ContractError::RecoveryInProgress);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(guardian.clone());
+        let request = RecoveryRequest {
+            new_merchant: new_merchant.clone(),
+            approvals,
+            initiated_at: now,
+            executable_at: now + RECOVERY_TIMELOCK,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecoveryRequest, &request);
+
+        publish_recovery_started_event(&env, guardian, new_merchant, request.executable_at, now);
+    }
+
+    fn approve_recovery(env: Env, guardian: Address) {
+        assert_guardian(&env, &guardian);
+
+        let mut request = get_pending_recovery(&env);
+        if request.approvals.contains(&guardian) {
+            return;
+        }
+        request.approvals.push_back(guardian.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::RecoveryRequest, &request);
+
+        publish_recovery_approved_event(
+            &env,
+            guardian,
+            request.approvals.len(),
+            env.ledger().timestamp(),
+        );
+    }
+
+    fn execute_recovery(env: Env) {
+        let request = get_pending_recovery(&env);
+        let threshold = Self::get_recovery_threshold(env.clone());
+
+        // Guardians may have been removed since they approved; only count
+        // approvals from the current guardian set.
+        let guardians = get_guardian_list(&env);
+        let mut valid_approvals = 0u32;
+        for approver in request.approvals.iter() {
+            if guardians.contains(&approver) {
+                valid_approvals += 1;
+            }
+        }
+        if threshold == 0 || valid_approvals < threshold {
+            panic_with_error!(&env, ContractError::RecoveryQuorumNotReached);
+        }
+        if env.ledger().timestamp() < request.executable_at {
+            panic_with_error!(&env, ContractError::RecoveryTimelockActive);
+        }
+
+        let old_merchant = get_merchant_address(&env);
+        let mut account_info: AccountInfo = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountInfo)
+            .unwrap_or_else(|| panic_with_error!(&env, ContractError::NotInitialized));
+        account_info.merchant = request.new_merchant.clone();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::AccountInfo, &account_info);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Merchant, &request.new_merchant);
+        env.storage().persistent().remove(&DataKey::RecoveryRequest);
+        // Recovery exists for lost keys, so the new merchant starts without
+        // the old signer set.
+        env.storage().persistent().remove(&DataKey::Signers);
+        env.storage().persistent().remove(&DataKey::SignerThreshold);
+
+        publish_recovery_executed_event(
+            &env,
+            old_merchant,
+            request.new_merchant,
+            env.ledger().timestamp(),
+        );
+    }
+
+    fn cancel_recovery(env: Env) {
+        require_merchant_auth(&env);
+
+        get_pending_recovery(&env);
+        env.storage().persistent().remove(&DataKey::RecoveryRequest);
+
+        publish_recovery_cancelled_event(&env, env.ledger().timestamp());
+    }
+
+    fn get_recovery_request(env: Env) -> Option<RecoveryRequest> {
+        env.storage().persistent().get(&DataKey::RecoveryRequest)
+    }
+
+    fn deposit(env: Env, from: Address, token: Address, amount: i128) {
+        receive_deposit(&env, StatementEntryKind::Deposit, &from, &token, amount);
+    }
+
+    fn get_statement(env: Env, page: u64) -> Vec<StatementEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StatementPage(page))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn get_statement_count(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::StatementCount)
+            .unwrap_or(0)
+    }
+
+    fn pause_account(env: Env) {
+        let merchant = require_merchant_auth(&env);
+
+        env.storage().persistent().set(&DataKey::Paused, &true);
+        publish_account_paused_event(&env, merchant, env.ledger().timestamp());
+    }
+
+    fn unpause_account(env: Env) {
+        let merchant = require_merchant_auth(&env);
+
+        env.storage().persistent().set(&DataKey::Paused, &false);
+        publish_account_unpaused_event(&env, merchant, env.ledger().timestamp());
+    }
+
+    fn is_account_paused(env: Env) -> bool {
+        is_paused_account(&env)
+    }
+
+    fn deposit_for_invoice(env: Env, from: Address, invoice_id: u64, token: Address, amount: i128) {
+        // Only the manager opens earmarks; otherwise anyone could pin an
+        // invoice's refunds to a token amount of their choosing.
+        get_manager(&env).require_auth();
+        receive_deposit(
+            &env,
+            StatementEntryKind::InvoicePayment,
+            &from,
+            &token,
+            amount,
+        );
+        adjust_earmark(&env, invoice_id, &token, amount);
+
+        publish_invoice_funds_credited_event(
+            &env,
+            invoice_id,
+            token,
+            amount,
+            env.ledger().timestamp(),
+        );
+    }
+
+    fn release_invoice_funds(env: Env, invoice_id: u64, token: Address) {
+        // Released once the invoice's refund window has closed.
+        get_manager(&env).require_auth();
+
+        let earmark = get_invoice_earmark(&env, invoice_id, &token);
+        if earmark > 0 {
+            adjust_earmark(&env, invoice_id, &token, -earmark);
+            publish_invoice_funds_released_event(
+                &env,
+                invoice_id,
+                token,
+                earmark,
+                env.ledger().timestamp(),
+            );
+        }
+    }
+
+    fn get_invoice_funds(env: Env, invoice_id: u64, token: Address) -> i128 {
+        get_invoice_earmark(&env, invoice_id, &token)
+    }
+
+    fn get_earmarked_balance(env: Env, token: Address) -> i128 {
+        get_earmarked_total(&env, &token)
+    }
+
+    fn set_earmark_protection(env: Env, enabled: bool) {
+        require_merchant_auth(&env);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::EarmarkProtection, &enabled);
+        publish_earmark_protection_set_event(&env, enabled, env.ledger().timestamp());
+    }
+
+    fn is_earmark_protected(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EarmarkProtection)
+            .unwrap_or(false)
+    }
+
+    fn request_withdrawal(env: Env, token: Address, amount: i128, recipient: Address) -> u64 {
+        require_merchant_auth(&env);
+
+        if !is_restricted_account(&env) {
+            panic_with_error!(&env, ContractError::AccountNotRestricted);
+        }
+        if amount <= 0 {
+            panic_with_error!(&env, ContractError::InvalidAmount);
+        }
+
+        let request_id = env
+            .storage()
+            .persistent()
+            .get(&DataKey::WithdrawalRequestCount)
+            .unwrap_or(0u64)
+            + 1;
+        let request = WithdrawalRequest {
+            id: request_id,
+            token: token.clone(),
+            amount,
+            recipient: recipient.clone(),
+            requested_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::WithdrawalRequest(request_id), &request);
+        env.storage()
+            .persistent()
+            .set(&DataKey::WithdrawalRequestCount, &request_id);
+
+        publish_withdrawal_requested_event(
+            &env,
+            request_id,
+            token,
+            amount,
+            recipient,
+            env.ledger().timestamp(),
+        );
+        request_id
+    }
+
+    fn approve_withdrawal(env: Env, caller: Address, request_id: u64) {
+        caller.require_auth();
+        if caller != get_manager(&env) {
+            panic_with_error!(&env, ContractError::NotAuthorized);
+        }
+
+        let request = get_withdrawal_request(&env, request_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::WithdrawalRequest(request_id));
+        pay_out(&env, &request.token, request.amount, &request.recipient);
+
+        publish_withdrawal_approved_event(&env, request_id, caller, env.ledger().timestamp());
+    }
+
+    fn cancel_withdrawal_request(env: Env, request_id: u64) {
+        require_merchant_auth(&env);
+
+        get_withdrawal_request(&env, request_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::WithdrawalRequest(request_id));
+
+        publish_withdrawal_cancelled_event(&env, request_id, env.ledger().timestamp());
+    }
+
+    fn get_withdrawal_request(env: Env, request_id: u64) -> Option<WithdrawalRequest> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WithdrawalRequest(request_id))
+    }
+
+    fn set_signers(env: Env, signers: Vec<BytesN<32>>, threshold: u32) {
+        require_merchant_auth(&env);
+
+        // An empty signer set hands control back to the merchant address.
+        if signers.is_empty() {
+            if threshold != 0 {
+                panic_with_error!(&env, ContractError::InvalidThreshold);
+            }
+            env.storage().persistent().remove(&DataKey::Signers);
+            env.storage().persistent().remove(&DataKey::SignerThreshold);
+        } else {
+            if threshold == 0 || threshold > signers.len() {
+                panic_with_error!(&env, ContractError::InvalidThreshold);
+            }
+            env.storage().persistent().set(&DataKey::Signers, &signers);
+            env.storage()
+                .persistent()
+                .set(&DataKey::SignerThreshold, &threshold);
+        }
+
+        publish_signers_updated_event(&env, signers, threshold, env.ledger().timestamp());
+    }
+
+    fn get_signers(env: Env) -> Vec<BytesN<32>> {
+        get_signer_list(&env)
+    }
+
+    fn get_signer_threshold(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerThreshold)
+            .unwrap_or(0)
+    }
 }
+
+#[contractimpl]
+impl CustomAccountInterface for MerchantAccount {
+    type Signature = Vec<SignerSignature>;
+    type Error = ContractError;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<SignerSignature>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), ContractError> {
+        let signers = get_signer_list(&env);
+        if signers.is_empty() {
+            return Err(ContractError::NotAuthorized);
+        }
+
+        // The signers only manage this account; they cannot spend its
+        // balances directly on the token contracts or authorize anything
+        // else on the account's behalf.
+        for context in auth_contexts.iter() {
+            match context {
+                Context::Contract(call) if call.contract == env.current_contract_address() => {}
+                _ => return Err(ContractError::NotAuthorized),
+            }
+        }
+
+        let payload: Bytes = signature_payload.to_bytes().into();
+        let mut signed_by: Vec<BytesN<32>> = Vec::new(&env);
+        for entry in signatures.iter() {
+            if !signers.contains(&entry.public_key) || signed_by.contains(&entry.public_key) {
+                return Err(ContractError::UnknownSigner);
+            }
+            env.crypto()
+                .ed25519_verify(&entry.public_key, &payload, &entry.signature);
+            signed_by.push_back(entry.public_key);
+        }
+
+        if signed_by.len() < Self::get_signer_threshold(env) {
+            return Err(ContractError::SignerThresholdNotMet);
+        }
+        Ok(())
+    }
 }
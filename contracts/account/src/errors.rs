@@ -9,4 +9,18 @@ pub enum ContractError {
     NotAuthorized = 3,
     InsufficientBalance = 4,
     AccountRestricted = 5,
-}
\ No newline at end of file
+    InvalidThreshold = 6,
+    NotGuardian = 7,
+    RecoveryInProgress = 8,
+    NoRecoveryInProgress = 9,
+    RecoveryQuorumNotReached = 10,
+    RecoveryTimelockActive = 11,
+    InvalidAmount = 12,
+    AccountPaused = 13,
+    InsufficientInvoiceFunds = 14,
+    WithdrawalRequestNotFound = 15,
+    AccountNotRestricted = 16,
+    SignerThresholdNotMet = 17,
+    UnknownSigner = 18,
+    DuplicateGuardian = 19,
+}
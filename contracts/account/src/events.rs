@@ -1,4 +1,4 @@
-use soroban_sdk::{contractevent, Address, Env};
+use soroban_sdk::{contractevent, Address, BytesN, Env, Vec};
 
 #[contractevent]
 pub struct AccountInitalizedEvent {
@@ -40,6 +40,20 @@ pub fn publish_account_verified_event(env: &Env, timestamp: u64) {
     AccountVerified { timestamp }.publish(env);
 }
 
+#[contractevent]
+pub struct AccountRestrictionSetEvent {
+    pub restricted: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_restriction_set_event(env: &Env, restricted: bool, timestamp: u64) {
+    AccountRestrictionSetEvent {
+        restricted,
+        timestamp,
+    }
+    .publish(env);
+}
+
 #[contractevent]
 pub struct WithdrawalToEvent {
     pub token: Address,
@@ -66,6 +80,7 @@ pub fn publish_withdrawal_to_event(
 
 #[contractevent]
 pub struct RefundProcessedEvent {
+    pub invoice_id: u64,
     pub token: Address,
     pub amount: i128,
     pub recipient: Address,
@@ -74,16 +89,307 @@ pub struct RefundProcessedEvent {
 
 pub fn publish_refund_processed_event(
     env: &Env,
+    invoice_id: u64,
     token: Address,
     amount: i128,
     recipient: Address,
     timestamp: u64,
 ) {
     RefundProcessedEvent {
+        invoice_id,
+        token,
+        amount,
+        recipient,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct GuardiansUpdatedEvent {
+    pub guardians: Vec<Address>,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_guardians_updated_event(
+    env: &Env,
+    guardians: Vec<Address>,
+    threshold: u32,
+    timestamp: u64,
+) {
+    GuardiansUpdatedEvent {
+        guardians,
+        threshold,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RecoveryStartedEvent {
+    pub guardian: Address,
+    pub new_merchant: Address,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_recovery_started_event(
+    env: &Env,
+    guardian: Address,
+    new_merchant: Address,
+    executable_at: u64,
+    timestamp: u64,
+) {
+    RecoveryStartedEvent {
+        guardian,
+        new_merchant,
+        executable_at,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RecoveryApprovedEvent {
+    pub guardian: Address,
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_recovery_approved_event(
+    env: &Env,
+    guardian: Address,
+    approvals: u32,
+    timestamp: u64,
+) {
+    RecoveryApprovedEvent {
+        guardian,
+        approvals,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RecoveryExecutedEvent {
+    pub old_merchant: Address,
+    pub new_merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_recovery_executed_event(
+    env: &Env,
+    old_merchant: Address,
+    new_merchant: Address,
+    timestamp: u64,
+) {
+    RecoveryExecutedEvent {
+        old_merchant,
+        new_merchant,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RecoveryCancelledEvent {
+    pub timestamp: u64,
+}
+
+pub fn publish_recovery_cancelled_event(env: &Env, timestamp: u64) {
+    RecoveryCancelledEvent { timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct DepositReceivedEvent {
+    pub from: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_deposit_received_event(
+    env: &Env,
+    from: Address,
+    token: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    DepositReceivedEvent {
+        from,
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceFundsCreditedEvent {
+    pub invoice_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_funds_credited_event(
+    env: &Env,
+    invoice_id: u64,
+    token: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    InvoiceFundsCreditedEvent {
+        invoice_id,
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceFundsReleasedEvent {
+    pub invoice_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_funds_released_event(
+    env: &Env,
+    invoice_id: u64,
+    token: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    InvoiceFundsReleasedEvent {
+        invoice_id,
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct EarmarkProtectionSetEvent {
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_earmark_protection_set_event(env: &Env, enabled: bool, timestamp: u64) {
+    EarmarkProtectionSetEvent { enabled, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct AccountPausedEvent {
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_paused_event(env: &Env, merchant: Address, timestamp: u64) {
+    AccountPausedEvent {
+        merchant,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct AccountUnpausedEvent {
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_account_unpaused_event(env: &Env, merchant: Address, timestamp: u64) {
+    AccountUnpausedEvent {
+        merchant,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalRequestedEvent {
+    pub request_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_requested_event(
+    env: &Env,
+    request_id: u64,
+    token: Address,
+    amount: i128,
+    recipient: Address,
+    timestamp: u64,
+) {
+    WithdrawalRequestedEvent {
+        request_id,
         token,
         amount,
         recipient,
         timestamp,
     }
     .publish(env);
-}
\ No newline at end of file
+}
+
+#[contractevent]
+pub struct WithdrawalApprovedEvent {
+    pub request_id: u64,
+    pub approver: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_approved_event(
+    env: &Env,
+    request_id: u64,
+    approver: Address,
+    timestamp: u64,
+) {
+    WithdrawalApprovedEvent {
+        request_id,
+        approver,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct WithdrawalCancelledEvent {
+    pub request_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_withdrawal_cancelled_event(env: &Env, request_id: u64, timestamp: u64) {
+    WithdrawalCancelledEvent {
+        request_id,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SignersUpdatedEvent {
+    pub signers: Vec<BytesN<32>>,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_signers_updated_event(
+    env: &Env,
+    signers: Vec<BytesN<32>>,
+    threshold: u32,
+    timestamp: u64,
+) {
+    SignersUpdatedEvent {
+        signers,
+        threshold,
+        timestamp,
+    }
+    .publish(env);
+}
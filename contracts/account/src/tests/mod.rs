@@ -1,2 +1,8 @@
 pub mod test;
+pub mod test_invoice_funds;
+pub mod test_pause;
+pub mod test_recovery;
+pub mod test_signers;
+pub mod test_statement;
 pub mod test_token_balance;
+pub mod test_withdrawal_requests;
@@ -2,7 +2,7 @@
 
 use crate::account::MerchantAccount;
 use crate::account::MerchantAccountClient;
-use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
 use soroban_sdk::{Address, Env};
 
 #[test]
@@ -54,15 +54,15 @@ fn test_verify_account() {
     let merchant_id = 1;
     client.initialize(&merchant, &manager, &merchant_id);
 
-    assert_eq!(client.is_verified_account(), false);
+    assert!(!client.is_verified_account());
 
     client.verify_account();
     let events = env.events().all();
 
-    assert_eq!(client.is_verified_account(), true);
+    assert!(client.is_verified_account());
 
     assert!(
-        events.len() > 0,
+        !events.is_empty(),
         "No events captured immediately after verify_account!"
     );
     let (_event_contract_id, _topics, _data) = events.get(events.len() - 1).unwrap();
@@ -84,3 +84,43 @@ fn test_verify_account_unauthorized() {
     // This should fail because we're not authenticated as manager
     client.verify_account();
 }
+
+#[test]
+fn test_get_account_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+
+    let merchant = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let merchant_id = 7;
+    client.initialize(&merchant, &manager, &merchant_id);
+
+    let info = client.get_account_info();
+    assert_eq!(info.merchant, merchant);
+    assert_eq!(info.manager, manager);
+    assert_eq!(info.merchant_id, merchant_id);
+    assert!(!info.restricted);
+    assert!(!info.paused);
+    assert!(!info.verified);
+    assert_eq!(info.date_created, 1_700_000_000);
+
+    client.verify_account();
+    client.set_restricted(&true);
+
+    let info = client.get_account_info();
+    assert!(info.restricted);
+    assert!(info.verified);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #2)")]
+#[test]
+fn test_get_account_info_not_initialized() {
+    let env = Env::default();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+
+    client.get_account_info();
+}
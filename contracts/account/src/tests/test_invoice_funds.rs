@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::errors::ContractError;
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{token, Address, Env, IntoVal};
+
+struct InvoiceFundsTest<'a> {
+    env: Env,
+    client: MerchantAccountClient<'a>,
+    manager: Address,
+    payer: Address,
+    token: Address,
+}
+
+fn setup_test<'a>() -> InvoiceFundsTest<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+    let manager = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &manager, &1);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+
+    InvoiceFundsTest {
+        env,
+        client,
+        manager,
+        payer,
+        token,
+    }
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_refund_is_paid_from_invoice_earmark() {
+    let t = setup_test();
+    t.client.deposit_for_invoice(&t.payer, &1, &t.token, &600);
+    t.client.deposit_for_invoice(&t.payer, &2, &t.token, &400);
+    assert_eq!(t.client.get_invoice_funds(&1, &t.token), 600);
+    assert_eq!(t.client.get_earmarked_balance(&t.token), 1_000);
+
+    assert_contract_error(
+        t.client.try_refund(&1, &t.token, &700, &t.payer),
+        ContractError::InsufficientInvoiceFunds,
+    );
+
+    t.client.refund(&1, &t.token, &600, &t.payer);
+    assert_eq!(t.client.get_invoice_funds(&1, &t.token), 0);
+    assert_eq!(t.client.get_earmarked_balance(&t.token), 400);
+
+    // Invoice 3 has nothing earmarked and may not dip into invoice 2's funds.
+    assert_contract_error(
+        t.client.try_refund(&3, &t.token, &100, &t.payer),
+        ContractError::InsufficientBalance,
+    );
+}
+
+#[test]
+fn test_protected_withdrawals_leave_earmarks_untouched() {
+    let t = setup_test();
+    let recipient = Address::generate(&t.env);
+    t.client.deposit_for_invoice(&t.payer, &1, &t.token, &600);
+    t.client.deposit(&t.payer, &t.token, &400);
+
+    t.client.set_earmark_protection(&true);
+    assert!(t.client.is_earmark_protected());
+    assert_contract_error(
+        t.client.try_withdraw_to(&t.token, &500, &recipient),
+        ContractError::InsufficientBalance,
+    );
+    t.client.withdraw_to(&t.token, &400, &recipient);
+
+    t.client.release_invoice_funds(&1, &t.token);
+    assert_eq!(t.client.get_earmarked_balance(&t.token), 0);
+    t.client.withdraw_to(&t.token, &600, &recipient);
+    assert_eq!(
+        token::Client::new(&t.env, &t.token).balance(&recipient),
+        1_000
+    );
+}
+
+#[test]
+fn test_only_manager_releases_invoice_funds() {
+    let t = setup_test();
+    t.client.deposit_for_invoice(&t.payer, &1, &t.token, &600);
+
+    let result = t
+        .client
+        .mock_auths(&[MockAuth {
+            address: &t.payer,
+            invoke: &MockAuthInvoke {
+                contract: &t.client.address,
+                fn_name: "release_invoice_funds",
+                args: (1_u64, &t.token).into_val(&t.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_release_invoice_funds(&1, &t.token);
+    assert!(result.is_err());
+    assert_eq!(t.client.get_invoice_funds(&1, &t.token), 600);
+
+    t.client
+        .mock_auths(&[MockAuth {
+            address: &t.manager,
+            invoke: &MockAuthInvoke {
+                contract: &t.client.address,
+                fn_name: "release_invoice_funds",
+                args: (1_u64, &t.token).into_val(&t.env),
+                sub_invokes: &[],
+            },
+        }])
+        .release_invoice_funds(&1, &t.token);
+    assert_eq!(t.client.get_invoice_funds(&1, &t.token), 0);
+}
+
+#[test]
+fn test_strangers_cannot_earmark_invoice_funds() {
+    let t = setup_test();
+    let attacker = Address::generate(&t.env);
+    token::StellarAssetClient::new(&t.env, &t.token).mint(&attacker, &1);
+
+    // A one-stroop earmark would leave invoice 1 refundable only up to that
+    // stroop, so the deposit needs the manager's signature too.
+    let result = t
+        .client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &t.client.address,
+                fn_name: "deposit_for_invoice",
+                args: (&attacker, 1_u64, &t.token, 1_i128).into_val(&t.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &t.token,
+                    fn_name: "transfer",
+                    args: (&attacker, &t.client.address, 1_i128).into_val(&t.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .try_deposit_for_invoice(&attacker, &1, &t.token, &1);
+    assert!(result.is_err());
+    assert_eq!(t.client.get_invoice_funds(&1, &t.token), 0);
+}
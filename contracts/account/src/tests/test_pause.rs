@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{token, Address, Env, IntoVal};
+
+fn setup_funded_account(env: &Env) -> (Address, MerchantAccountClient<'_>, Address, Address) {
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    let manager = Address::generate(env);
+    client.initialize(&merchant, &manager, &1);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(env, &token)
+        .mock_all_auths()
+        .mint(&contract_id, &1_000);
+
+    (contract_id, client, merchant, token)
+}
+
+#[test]
+fn test_pause_and_unpause_account() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, _) = setup_funded_account(&env);
+
+    assert!(!client.is_account_paused());
+
+    client.pause_account();
+    assert!(client.is_account_paused());
+    assert!(client.get_account_info().paused);
+
+    client.unpause_account();
+    assert!(!client.is_account_paused());
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+#[test]
+fn test_deposit_rejected_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client, _, token) = setup_funded_account(&env);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &100);
+
+    client.pause_account();
+    client.deposit(&payer, &token, &100);
+}
+
+#[test]
+fn test_withdrawal_allowed_while_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    client.pause_account();
+    client.withdraw_to(&token, &400, &merchant);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&merchant), 400);
+    assert_eq!(token_client.balance(&contract_id), 600);
+}
+
+#[test]
+#[should_panic]
+fn test_pause_account_requires_merchant() {
+    let env = Env::default();
+    let (contract_id, client, _, _) = setup_funded_account(&env);
+
+    let manager = client.get_account_info().manager;
+    client
+        .mock_auths(&[MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "pause_account",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .pause_account();
+}
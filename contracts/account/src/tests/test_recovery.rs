@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient, RECOVERY_TIMELOCK};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env};
+
+fn setup_account_with_guardians(
+    env: &Env,
+) -> (
+    Address,
+    MerchantAccountClient<'_>,
+    Address,
+    Address,
+    Address,
+    Address,
+) {
+    env.mock_all_auths();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    let manager = Address::generate(env);
+    client.initialize(&merchant, &manager, &1);
+
+    let guardian_a = Address::generate(env);
+    let guardian_b = Address::generate(env);
+    let guardian_c = Address::generate(env);
+    client.set_guardians(
+        &vec![
+            env,
+            guardian_a.clone(),
+            guardian_b.clone(),
+            guardian_c.clone(),
+        ],
+        &2,
+    );
+
+    (
+        contract_id,
+        client,
+        merchant,
+        guardian_a,
+        guardian_b,
+        guardian_c,
+    )
+}
+
+fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+}
+
+#[test]
+fn test_set_guardians() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, guardian_b, guardian_c) = setup_account_with_guardians(&env);
+
+    assert_eq!(
+        client.get_guardians(),
+        vec![&env, guardian_a, guardian_b, guardian_c]
+    );
+    assert_eq!(client.get_recovery_threshold(), 2);
+    assert_eq!(client.get_recovery_request(), None);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+#[test]
+fn test_set_guardians_threshold_above_guardian_count() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, _, _) = setup_account_with_guardians(&env);
+
+    client.set_guardians(&vec![&env, guardian_a], &2);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #19)")]
+#[test]
+fn test_set_guardians_duplicate_guardian() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, _, _) = setup_account_with_guardians(&env);
+
+    client.set_guardians(&vec![&env, guardian_a.clone(), guardian_a], &2);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #6)")]
+#[test]
+fn test_set_guardians_zero_threshold() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, _, _) = setup_account_with_guardians(&env);
+
+    client.set_guardians(&vec![&env, guardian_a], &0);
+}
+
+#[test]
+fn test_recovery_rotates_merchant_after_quorum_and_timelock() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, guardian_b, _) = setup_account_with_guardians(&env);
+
+    let new_merchant = Address::generate(&env);
+    client.initiate_recovery(&guardian_a, &new_merchant);
+    client.approve_recovery(&guardian_b);
+
+    let request = client.get_recovery_request().unwrap();
+    assert_eq!(request.new_merchant, new_merchant);
+    assert_eq!(request.approvals.len(), 2);
+
+    advance_time(&env, RECOVERY_TIMELOCK);
+    client.execute_recovery();
+
+    assert_eq!(client.get_merchant(), new_merchant);
+    assert_eq!(client.get_recovery_request(), None);
+    assert_eq!(client.get_account_info().merchant, new_merchant);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #10)")]
+#[test]
+fn test_execute_recovery_without_quorum() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, _, _) = setup_account_with_guardians(&env);
+
+    client.initiate_recovery(&guardian_a, &Address::generate(&env));
+    advance_time(&env, RECOVERY_TIMELOCK);
+    client.execute_recovery();
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #11)")]
+#[test]
+fn test_execute_recovery_before_timelock() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, guardian_b, _) = setup_account_with_guardians(&env);
+
+    client.initiate_recovery(&guardian_a, &Address::generate(&env));
+    client.approve_recovery(&guardian_b);
+    advance_time(&env, RECOVERY_TIMELOCK - 1);
+    client.execute_recovery();
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #7)")]
+#[test]
+fn test_initiate_recovery_non_guardian() {
+    let env = Env::default();
+    let (_, client, _, _, _, _) = setup_account_with_guardians(&env);
+
+    let stranger = Address::generate(&env);
+    client.initiate_recovery(&stranger, &stranger);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_initiate_recovery_twice() {
+    let env = Env::default();
+    let (_, client, _, guardian_a, guardian_b, _) = setup_account_with_guardians(&env);
+
+    client.initiate_recovery(&guardian_a, &Address::generate(&env));
+    client.initiate_recovery(&guardian_b, &Address::generate(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #9)")]
+#[test]
+fn test_merchant_can_cancel_recovery() {
+    let env = Env::default();
+    let (_, client, merchant, guardian_a, guardian_b, _) = setup_account_with_guardians(&env);
+
+    client.initiate_recovery(&guardian_a, &Address::generate(&env));
+    client.approve_recovery(&guardian_b);
+    client.cancel_recovery();
+
+    assert_eq!(client.get_recovery_request(), None);
+    assert_eq!(client.get_merchant(), merchant);
+
+    advance_time(&env, RECOVERY_TIMELOCK);
+    client.execute_recovery();
+}
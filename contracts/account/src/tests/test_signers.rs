@@ -0,0 +1,158 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::errors::ContractError;
+use crate::types::SignerSignature;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+fn setup_test(env: &Env) -> (MerchantAccountClient<'_>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+    let merchant = Address::generate(env);
+    client.initialize(&merchant, &Address::generate(env), &1);
+    (client, merchant)
+}
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> BytesN<32> {
+    BytesN::from_array(env, &key.verifying_key().to_bytes())
+}
+
+fn sign(env: &Env, key: &SigningKey, payload: &BytesN<32>) -> SignerSignature {
+    SignerSignature {
+        public_key: public_key(env, key),
+        signature: BytesN::from_array(env, &key.sign(&payload.to_array()).to_bytes()),
+    }
+}
+
+fn account_context(env: &Env, contract: &Address) -> Vec<Context> {
+    vec![
+        env,
+        Context::Contract(ContractContext {
+            contract: contract.clone(),
+            fn_name: Symbol::new(env, "pause_account"),
+            args: vec![env],
+        }),
+    ]
+}
+
+#[test]
+fn test_signers_replace_merchant_auth() {
+    let env = Env::default();
+    let (client, merchant) = setup_test(&env);
+    let keys = [signing_key(1), signing_key(2), signing_key(3)];
+    let signers = vec![
+        &env,
+        public_key(&env, &keys[0]),
+        public_key(&env, &keys[1]),
+        public_key(&env, &keys[2]),
+    ];
+
+    client.pause_account();
+    assert_eq!(env.auths()[0].0, merchant);
+
+    client.set_signers(&signers, &2);
+    assert_eq!(client.get_signers(), signers);
+    assert_eq!(client.get_signer_threshold(), 2);
+
+    client.unpause_account();
+    assert_eq!(env.auths()[0].0, client.address);
+
+    client.set_signers(&Vec::new(&env), &0);
+    assert_eq!(client.get_signer_threshold(), 0);
+    client.pause_account();
+    assert_eq!(env.auths()[0].0, merchant);
+}
+
+#[test]
+fn test_check_auth_requires_signer_quorum() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+    let keys = [signing_key(1), signing_key(2), signing_key(3)];
+    client.set_signers(
+        &vec![
+            &env,
+            public_key(&env, &keys[0]),
+            public_key(&env, &keys[1]),
+            public_key(&env, &keys[2]),
+        ],
+        &2,
+    );
+
+    let payload = BytesN::from_array(&env, &[7; 32]);
+    let contexts = account_context(&env, &client.address);
+    let check = |signatures: Vec<SignerSignature>, contexts: &Vec<Context>| {
+        env.try_invoke_contract_check_auth::<ContractError>(
+            &client.address,
+            &payload,
+            signatures.into_val(&env),
+            contexts,
+        )
+    };
+
+    let quorum = vec![
+        &env,
+        sign(&env, &keys[0], &payload),
+        sign(&env, &keys[2], &payload),
+    ];
+    assert_eq!(check(quorum.clone(), &contexts), Ok(()));
+
+    assert_eq!(
+        check(vec![&env, sign(&env, &keys[0], &payload)], &contexts),
+        Err(Ok(ContractError::SignerThresholdNotMet))
+    );
+    assert_eq!(
+        check(
+            vec![
+                &env,
+                sign(&env, &keys[0], &payload),
+                sign(&env, &keys[0], &payload)
+            ],
+            &contexts
+        ),
+        Err(Ok(ContractError::UnknownSigner))
+    );
+    assert_eq!(
+        check(
+            vec![
+                &env,
+                sign(&env, &keys[0], &payload),
+                sign(&env, &signing_key(9), &payload)
+            ],
+            &contexts
+        ),
+        Err(Ok(ContractError::UnknownSigner))
+    );
+
+    // The signers cannot authorize calls to other contracts, such as a
+    // token transfer out of the account.
+    let token_context = account_context(&env, &Address::generate(&env));
+    assert_eq!(
+        check(quorum, &token_context),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_set_signers_validates_threshold() {
+    let env = Env::default();
+    let (client, _) = setup_test(&env);
+    let signers = vec![&env, public_key(&env, &signing_key(1))];
+
+    assert_eq!(
+        client.try_set_signers(&signers, &2),
+        Err(Ok(ContractError::InvalidThreshold.into()))
+    );
+    assert_eq!(
+        client.try_set_signers(&signers, &0),
+        Err(Ok(ContractError::InvalidThreshold.into()))
+    );
+    assert!(client.get_signers().is_empty());
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::account::{
+    MerchantAccount, MerchantAccountClient, MAX_STATEMENT_PAGES, STATEMENT_PAGE_SIZE,
+};
+use crate::types::StatementEntryKind;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, Address, Env};
+
+fn setup_funded_account(env: &Env) -> (Address, MerchantAccountClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(env, &contract_id);
+
+    let merchant = Address::generate(env);
+    let manager = Address::generate(env);
+    client.initialize(&merchant, &manager, &1);
+
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(env, &token).mint(&contract_id, &10_000);
+
+    (contract_id, client, merchant, token)
+}
+
+#[test]
+fn test_statement_records_deposits_withdrawals_and_refunds() {
+    let env = Env::default();
+    let (contract_id, client, merchant, token) = setup_funded_account(&env);
+
+    let payer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&payer, &500);
+
+    client.deposit(&payer, &token, &500);
+    client.withdraw_to(&token, &300, &merchant);
+    client.refund(&1, &token, &100, &payer);
+
+    assert_eq!(client.get_statement_count(), 3);
+
+    let statement = client.get_statement(&0);
+    assert_eq!(statement.len(), 3);
+
+    let deposit = statement.get(0).unwrap();
+    assert_eq!(deposit.kind, StatementEntryKind::Deposit);
+    assert_eq!(deposit.amount, 500);
+    assert_eq!(deposit.counterparty, payer);
+
+    let withdrawal = statement.get(1).unwrap();
+    assert_eq!(withdrawal.kind, StatementEntryKind::Withdrawal);
+    assert_eq!(withdrawal.amount, 300);
+    assert_eq!(withdrawal.counterparty, merchant);
+
+    let refund = statement.get(2).unwrap();
+    assert_eq!(refund.kind, StatementEntryKind::Refund);
+    assert_eq!(refund.amount, 100);
+    assert_eq!(refund.counterparty, payer);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&contract_id), 10_000 + 500 - 300 - 100);
+}
+
+#[test]
+fn test_statement_paginates_and_drops_oldest_pages() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let (_, client, merchant, token) = setup_funded_account(&env);
+
+    let total_entries = STATEMENT_PAGE_SIZE * MAX_STATEMENT_PAGES + 1;
+    for _ in 0..total_entries {
+        client.withdraw_to(&token, &1, &merchant);
+    }
+
+    assert_eq!(client.get_statement_count(), total_entries);
+    assert_eq!(client.get_statement(&0).len(), 0);
+    assert_eq!(client.get_statement(&1).len() as u64, STATEMENT_PAGE_SIZE);
+    assert_eq!(client.get_statement(&MAX_STATEMENT_PAGES).len(), 1);
+}
+
+#[test]
+fn test_get_statement_empty_page() {
+    let env = Env::default();
+    let (_, client, _, _) = setup_funded_account(&env);
+
+    assert_eq!(client.get_statement_count(), 0);
+    assert_eq!(client.get_statement(&0).len(), 0);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #12)")]
+#[test]
+fn test_deposit_invalid_amount() {
+    let env = Env::default();
+    let (_, client, _, token) = setup_funded_account(&env);
+
+    let payer = Address::generate(&env);
+    client.deposit(&payer, &token, &0);
+}
@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+use crate::account::{MerchantAccount, MerchantAccountClient};
+use crate::errors::ContractError;
+use soroban_sdk::testutils::{Address as _, Events as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{token, Address, Env, IntoVal, Symbol, TryIntoVal};
+
+struct WithdrawalRequestTest<'a> {
+    env: Env,
+    client: MerchantAccountClient<'a>,
+    manager: Address,
+    token: Address,
+}
+
+fn setup_test<'a>() -> WithdrawalRequestTest<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(MerchantAccount, ());
+    let client = MerchantAccountClient::new(&env, &contract_id);
+    let manager = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &manager, &1);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(&env, &token).mint(&contract_id, &1_000);
+
+    WithdrawalRequestTest {
+        env,
+        client,
+        manager,
+        token,
+    }
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_restricted_account_withdraws_after_manager_approval() {
+    let t = setup_test();
+    let recipient = Address::generate(&t.env);
+    t.client.set_restricted(&true);
+
+    assert_contract_error(
+        t.client.try_withdraw_to(&t.token, &400, &recipient),
+        ContractError::AccountRestricted,
+    );
+
+    let request_id = t.client.request_withdrawal(&t.token, &400, &recipient);
+    let request = t.client.get_withdrawal_request(&request_id).unwrap();
+    assert_eq!(request.amount, 400);
+    assert_eq!(request.recipient, recipient);
+
+    t.client.approve_withdrawal(&t.manager, &request_id);
+    assert_eq!(t.client.get_withdrawal_request(&request_id), None);
+    assert_eq!(
+        token::Client::new(&t.env, &t.token).balance(&recipient),
+        400
+    );
+    assert_eq!(t.client.get_statement_count(), 1);
+
+    assert_contract_error(
+        t.client.try_approve_withdrawal(&t.manager, &request_id),
+        ContractError::WithdrawalRequestNotFound,
+    );
+}
+
+#[test]
+fn test_only_manager_approves_withdrawals() {
+    let t = setup_test();
+    let recipient = Address::generate(&t.env);
+    t.client.set_restricted(&true);
+
+    let request_id = t.client.request_withdrawal(&t.token, &400, &recipient);
+    assert_contract_error(
+        t.client.try_approve_withdrawal(&recipient, &request_id),
+        ContractError::NotAuthorized,
+    );
+
+    t.client.cancel_withdrawal_request(&request_id);
+    assert_eq!(t.client.get_withdrawal_request(&request_id), None);
+    assert_eq!(t.client.get_balance(&t.token), 1_000);
+}
+
+#[test]
+fn test_withdrawal_requests_require_restriction() {
+    let t = setup_test();
+    let recipient = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_request_withdrawal(&t.token, &400, &recipient),
+        ContractError::AccountNotRestricted,
+    );
+}
+
+#[test]
+fn test_only_manager_sets_restriction() {
+    let t = setup_test();
+    let merchant = t.client.get_merchant();
+
+    let result = t
+        .client
+        .mock_auths(&[MockAuth {
+            address: &merchant,
+            invoke: &MockAuthInvoke {
+                contract: &t.client.address,
+                fn_name: "set_restricted",
+                args: (true,).into_val(&t.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_restricted(&true);
+    assert!(result.is_err());
+    assert!(!t.client.get_account_info().restricted);
+
+    t.client.set_restricted(&true);
+    let event = t.env.events().all().last().unwrap();
+    let name: Symbol = event.1.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(name, Symbol::new(&t.env, "account_restriction_set_event"));
+    assert!(t.client.get_account_info().restricted);
+
+    t.client.set_restricted(&false);
+    let recipient = Address::generate(&t.env);
+    t.client.withdraw_to(&t.token, &400, &recipient);
+    assert_eq!(t.client.get_balance(&t.token), 600);
+}
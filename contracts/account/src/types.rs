@@ -1,5 +1,10 @@
 use soroban_sdk::{contracttype, Address};
 
+pub use shared::account::{
+    AccountDetails, RecoveryRequest, SignerSignature, StatementEntry, StatementEntryKind,
+    TokenBalance, WithdrawalRequest,
+};
+
 #[contracttype]
 pub enum DataKey {
     Manager,
@@ -8,6 +13,19 @@ pub enum DataKey {
     Restricted,
     AccountInfo,
     TrackedTokens,
+    Guardians,
+    RecoveryThreshold,
+    RecoveryRequest,
+    StatementCount,
+    StatementPage(u64),
+    Paused,
+    InvoiceFunds(u64, Address),
+    EarmarkedBalance(Address),
+    EarmarkProtection,
+    WithdrawalRequestCount,
+    WithdrawalRequest(u64),
+    Signers,
+    SignerThreshold,
 }
 
 #[contracttype]
@@ -18,10 +36,3 @@ pub struct AccountInfo {
     pub merchant: Address,
     pub date_created: u64,
 }
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct TokenBalance {
-    pub token: Address,
-    pub balance: i128,
-}
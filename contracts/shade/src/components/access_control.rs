@@ -1,26 +1,37 @@
-use crate::components::core;
+use crate::components::{core, pagination};
+use crate::errors::ContractError;
 use crate::events;
 use crate::types::{DataKey, Role};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
 
 pub fn grant_role(env: &Env, admin: &Address, user: &Address, role: Role) {
     core::assert_admin(env, admin);
-
-    env.storage()
-        .persistent()
-        .set(&DataKey::Role(user.clone(), role.clone()), &true);
-
-    events::publish_role_granted_event(env, user.clone(), role, env.ledger().timestamp());
+    store_role(env, user, role);
 }
 
 pub fn revoke_role(env: &Env, admin: &Address, user: &Address, role: Role) {
     core::assert_admin(env, admin);
+    remove_role(env, user, role);
+}
 
-    env.storage()
-        .persistent()
-        .remove(&DataKey::Role(user.clone(), role.clone()));
+// Batch variants for bootstrapping a deployment or rotating an ops team in
+// one admin-authorized call.
+pub fn grant_roles(env: &Env, admin: &Address, grants: &Vec<(Address, Role)>) {
+    core::assert_admin(env, admin);
+    assert_batch_size(env, grants);
 
-    events::publish_role_revoked_event(env, user.clone(), role, env.ledger().timestamp());
+    for (user, role) in grants.iter() {
+        store_role(env, &user, role);
+    }
+}
+
+pub fn revoke_roles(env: &Env, admin: &Address, revocations: &Vec<(Address, Role)>) {
+    core::assert_admin(env, admin);
+    assert_batch_size(env, revocations);
+
+    for (user, role) in revocations.iter() {
+        remove_role(env, &user, role);
+    }
 }
 
 pub fn has_role(env: &Env, user: &Address, role: Role) -> bool {
@@ -37,8 +48,28 @@ pub fn has_role(env: &Env, user: &Address, role: Role) -> bool {
 pub fn assert_has_role(env: &Env, user: &Address, role: Role) {
     user.require_auth();
     if !has_role(env, user, role) {
-        use crate::errors::ContractError;
-        use soroban_sdk::panic_with_error;
         panic_with_error!(env, ContractError::NotAuthorized);
     }
 }
+
+fn assert_batch_size(env: &Env, entries: &Vec<(Address, Role)>) {
+    if entries.len() > pagination::MAX_PAGE_LIMIT {
+        panic_with_error!(env, ContractError::BatchTooLarge);
+    }
+}
+
+fn store_role(env: &Env, user: &Address, role: Role) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Role(user.clone(), role.clone()), &true);
+
+    events::publish_role_granted_event(env, user.clone(), role, env.ledger().timestamp());
+}
+
+fn remove_role(env: &Env, user: &Address, role: Role) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Role(user.clone(), role.clone()));
+
+    events::publish_role_revoked_event(env, user.clone(), role, env.ledger().timestamp());
+}
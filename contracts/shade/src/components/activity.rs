@@ -0,0 +1,81 @@
+use crate::components::{pagination, ttl};
+use crate::types::{ActivityKind, ActivityRecord, DataKey};
+use soroban_sdk::{Address, Env, Vec};
+
+// Ring buffer of the most recent money movements so lightweight clients can
+// show live activity without ingesting events. Records live in a fixed set of
+// pages whose slots are overwritten once the buffer wraps, so neither storage
+// nor the footprint of a read grows past ACTIVITY_CAPACITY entries.
+pub const ACTIVITY_PAGE_SIZE: u64 = 20;
+pub const ACTIVITY_CAPACITY: u64 = 100;
+
+pub fn record_activity(
+    env: &Env,
+    kind: ActivityKind,
+    reference_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    let count = get_activity_count(env);
+    let slot = count % ACTIVITY_CAPACITY;
+    let key = DataKey::ActivityPage(slot / ACTIVITY_PAGE_SIZE);
+    let record = ActivityRecord {
+        kind,
+        reference_id,
+        token: token.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let mut page: Vec<ActivityRecord> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    let index = (slot % ACTIVITY_PAGE_SIZE) as u32;
+    if index < page.len() {
+        page.set(index, record);
+    } else {
+        page.push_back(record);
+    }
+    env.storage().persistent().set(&key, &page);
+    ttl::extend_persistent(env, &key);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActivityCount, &(count + 1));
+}
+
+// Newest first.
+pub fn get_recent_activity(env: &Env, limit: u32) -> Vec<ActivityRecord> {
+    let count = get_activity_count(env);
+    let limit = (pagination::clamp_limit(limit) as u64).min(count.min(ACTIVITY_CAPACITY));
+
+    let mut records = Vec::new(env);
+    let mut loaded: Option<(u64, Vec<ActivityRecord>)> = None;
+    for offset in 1..=limit {
+        let slot = (count - offset) % ACTIVITY_CAPACITY;
+        let page_number = slot / ACTIVITY_PAGE_SIZE;
+        if loaded.as_ref().map(|(number, _)| *number) != Some(page_number) {
+            let page = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ActivityPage(page_number))
+                .unwrap_or_else(|| Vec::new(env));
+            loaded = Some((page_number, page));
+        }
+        if let Some(record) = loaded
+            .as_ref()
+            .and_then(|(_, page)| page.get((slot % ACTIVITY_PAGE_SIZE) as u32))
+        {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+fn get_activity_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActivityCount)
+        .unwrap_or(0)
+}
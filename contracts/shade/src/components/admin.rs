@@ -1,26 +1,120 @@
-use crate::components::{core, reentrancy};
-use crate::errors::ContractError;
+use crate::components::{core, distribution, reentrancy, ttl};
+use crate::errors::{ComplianceError, ContractError, InvoiceError};
 use crate::events;
-use crate::types::DataKey;
+use crate::types::{AmountBounds, DataKey, TokenMetadata};
 use soroban_sdk::{panic_with_error, token, Address, Env, Vec};
 
 pub fn add_accepted_token(env: &Env, admin: &Address, token: &Address) {
     reentrancy::enter(env);
     core::assert_admin(env, admin);
 
-    let _ = token::Client::new(env, token).symbol();
+    if rejects_clawback_assets(env) && is_clawback_asset(env, token) {
+        panic_with_error!(env, ComplianceError::ClawbackAssetRejected);
+    }
+    probe_token(env, token);
 
-    let mut accepted_tokens = get_accepted_tokens(env);
-    if !contains_token(&accepted_tokens, token) {
-        accepted_tokens.push_back(token.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::AcceptedTokens, &accepted_tokens);
-        events::publish_token_added_event(env, token.clone(), env.ledger().timestamp());
+    insert_accepted_token(env, token);
+    reentrancy::exit(env);
+}
+
+// Stellar Asset Contracts do not expose the issuer's clawback flag, so the
+// admin records which assets are clawback-enabled. With rejection turned on
+// those assets can no longer be added as accepted tokens; assets already
+// accepted stay until removed.
+pub fn set_clawback_asset(env: &Env, admin: &Address, token: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::ClawbackAsset(token.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
     }
+
+    events::publish_clawback_asset_set_event(env, token.clone(), enabled, env.ledger().timestamp());
+}
+
+pub fn is_clawback_asset(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ClawbackAsset(token.clone()))
+}
+
+pub fn set_reject_clawback_assets(env: &Env, admin: &Address, reject: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RejectClawbackAssets, &reject);
+    ttl::extend_persistent(env, &DataKey::RejectClawbackAssets);
+
+    events::publish_clawback_policy_set_event(env, reject, env.ledger().timestamp());
+}
+
+pub fn rejects_clawback_assets(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RejectClawbackAssets)
+        .unwrap_or(false)
+}
+
+pub fn set_refund_protocol_fees(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundProtocolFees, &enabled);
+    ttl::extend_persistent(env, &DataKey::RefundProtocolFees);
+
+    events::publish_fee_refund_policy_set_event(env, enabled, env.ledger().timestamp());
+}
+
+pub fn refunds_protocol_fees(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundProtocolFees)
+        .unwrap_or(false)
+}
+
+pub fn set_refund_subsidy(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundSubsidy, &enabled);
+    ttl::extend_persistent(env, &DataKey::RefundSubsidy);
+
+    events::publish_refund_subsidy_policy_set_event(env, enabled, env.ledger().timestamp());
+}
+
+pub fn subsidizes_refunds(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundSubsidy)
+        .unwrap_or(false)
+}
+
+// The native XLM Stellar Asset Contract address differs per network, so the
+// admin configures it once per deployment. It is also accepted for payment.
+pub fn set_native_token(env: &Env, admin: &Address, token: &Address) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+
+    probe_token(env, token);
+
+    env.storage().persistent().set(&DataKey::NativeToken, token);
+    ttl::extend_persistent(env, &DataKey::NativeToken);
+    insert_accepted_token(env, token);
+
+    events::publish_native_token_set_event(env, token.clone(), env.ledger().timestamp());
     reentrancy::exit(env);
 }
 
+pub fn get_native_token(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::NativeToken)
+}
+
 pub fn remove_accepted_token(env: &Env, admin: &Address, token: &Address) {
     reentrancy::enter(env);
     core::assert_admin(env, admin);
@@ -53,27 +147,190 @@ pub fn is_accepted_token(env: &Env, token: &Address) -> bool {
 pub fn set_fee(env: &Env, admin: &Address, token: &Address, fee: i128) {
     reentrancy::enter(env);
     core::assert_admin(env, admin);
+    store_fee(env, token, fee);
+    reentrancy::exit(env);
+}
 
+// Shared by set_fee and governance proposals; callers handle authorization.
+pub fn store_fee(env: &Env, token: &Address, fee: i128) {
     if !is_accepted_token(env, token) {
         panic_with_error!(env, ContractError::TokenNotAccepted);
     }
+    if !(0..=FEE_DENOMINATOR).contains(&fee) {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
 
     env.storage()
         .persistent()
         .set(&DataKey::TokenFee(token.clone()), &fee);
+    ttl::extend_persistent(env, &DataKey::TokenFee(token.clone()));
 
     events::publish_fee_set_event(env, token.clone(), fee, env.ledger().timestamp());
-    reentrancy::exit(env);
 }
 
 pub fn get_fee(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::TokenFee(token.clone());
+    let fee = env.storage().persistent().get(&key).unwrap_or(0);
+    ttl::extend_persistent(env, &key);
+    fee
+}
+
+// Fees are configured per token in basis points of the gross amount.
+pub const FEE_DENOMINATOR: i128 = 10_000;
+
+// `parties` are the payer and/or recipient of the payment; if any of them
+// is fee-exempt the fee is waived and a fee_waived event records it.
+pub fn calculate_fee(env: &Env, token: &Address, amount: i128, parties: &[&Address]) -> i128 {
+    let fee = amount * get_fee(env, token) / FEE_DENOMINATOR;
+    if fee == 0 {
+        return 0;
+    }
+
+    for party in parties {
+        if is_fee_exempt(env, party) {
+            events::publish_fee_waived_event(env, token.clone(), (*party).clone(), fee);
+            return 0;
+        }
+    }
+    fee
+}
+
+// Same as `calculate_fee` without recording a waiver, for read-only quotes.
+pub fn quote_fee(env: &Env, token: &Address, amount: i128, parties: &[&Address]) -> i128 {
+    if parties.iter().any(|party| is_fee_exempt(env, party)) {
+        return 0;
+    }
+    amount * get_fee(env, token) / FEE_DENOMINATOR
+}
+
+pub fn set_fee_exempt(env: &Env, admin: &Address, address: &Address, exempt: bool) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::FeeExempt(address.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    events::publish_fee_exemption_set_event(env, address.clone(), exempt, env.ledger().timestamp());
+}
+
+pub fn is_fee_exempt(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FeeExempt(address.clone()))
+}
+
+pub fn collect_fee(env: &Env, token: &Address, fee: i128) {
+    if fee == 0 {
+        return;
+    }
+
+    let key = DataKey::CollectedFees(token.clone());
+    let collected = get_collected_fees(env, token);
+    env.storage().persistent().set(&key, &(collected + fee));
+    ttl::extend_persistent(env, &key);
+}
+
+// Pays collected fees back out, e.g. when a refund returns the protocol fee.
+// Fees already credited to distribution recipients are owed to them, so only
+// the undistributed part can be released.
+pub fn release_collected_fee(env: &Env, token: &Address, amount: i128) {
+    if amount > distribution::get_undistributed_fees(env, token) {
+        panic_with_error!(env, InvoiceError::InsufficientCollectedFees);
+    }
+    let collected = get_collected_fees(env, token);
+
+    let key = DataKey::CollectedFees(token.clone());
+    env.storage().persistent().set(&key, &(collected - amount));
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn get_collected_fees(env: &Env, token: &Address) -> i128 {
     env.storage()
         .persistent()
-        .get(&DataKey::TokenFee(token.clone()))
+        .get(&DataKey::CollectedFees(token.clone()))
         .unwrap_or(0)
 }
 
-fn get_accepted_tokens(env: &Env) -> Vec<Address> {
+// Per-token sanity bounds on invoice amounts. `unverified_max` is an extra,
+// lower ceiling for merchants the admin has not verified yet.
+pub fn set_amount_bounds(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    bounds: &Option<AmountBounds>,
+) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::AmountBounds(token.clone());
+    match bounds {
+        Some(bounds) => {
+            let unverified_ok = match bounds.unverified_max {
+                Some(unverified_max) => {
+                    unverified_max >= bounds.min && unverified_max <= bounds.max
+                }
+                None => true,
+            };
+            if bounds.min <= 0 || bounds.max < bounds.min || !unverified_ok {
+                panic_with_error!(env, ContractError::InvalidAmount);
+            }
+            env.storage().persistent().set(&key, bounds);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    events::publish_amount_bounds_set_event(
+        env,
+        token.clone(),
+        bounds.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_amount_bounds(env: &Env, token: &Address) -> Option<AmountBounds> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AmountBounds(token.clone()))
+}
+
+pub fn assert_amount_within_bounds(env: &Env, token: &Address, amount: i128, verified: bool) {
+    let Some(bounds) = get_amount_bounds(env, token) else {
+        return;
+    };
+
+    let max = match bounds.unverified_max {
+        Some(unverified_max) if !verified => unverified_max,
+        _ => bounds.max,
+    };
+    if amount < bounds.min || amount > max {
+        panic_with_error!(env, ComplianceError::AmountOutOfBounds);
+    }
+}
+
+// While the legacy format flag is set, events keyed by token, merchant or
+// invoice are published with every field in the data map (the pre-topic
+// layout). It is on until the admin turns it off, so indexers built against
+// the old layout keep working through an upgrade.
+pub fn set_legacy_event_format(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::LegacyEventFormat, &enabled);
+    ttl::extend_persistent(env, &DataKey::LegacyEventFormat);
+
+    events::publish_legacy_event_format_set_event(env, enabled, env.ledger().timestamp());
+}
+
+pub fn is_legacy_event_format(env: &Env) -> bool {
+    ttl::get_persistent(env, &DataKey::LegacyEventFormat).unwrap_or(true)
+}
+
+pub fn get_accepted_tokens(env: &Env) -> Vec<Address> {
     env.storage()
         .persistent()
         .get(&DataKey::AcceptedTokens)
@@ -88,3 +345,47 @@ fn contains_token(accepted_tokens: &Vec<Address>, token: &Address) -> bool {
     }
     false
 }
+
+pub fn get_token_metadata(env: &Env, token: &Address) -> Option<TokenMetadata> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenMetadata(token.clone()))
+}
+
+// Cross-calls the SEP-41 read methods so a mistyped or non-token address is
+// rejected up front rather than failing at payment time. The probed
+// metadata is kept for clients that need decimals to format amounts.
+fn probe_token(env: &Env, token: &Address) {
+    let client = token::TokenClient::new(env, token);
+    let (Ok(Ok(name)), Ok(Ok(symbol)), Ok(Ok(decimals)), Ok(Ok(_))) = (
+        client.try_name(),
+        client.try_symbol(),
+        client.try_decimals(),
+        client.try_balance(&env.current_contract_address()),
+    ) else {
+        panic_with_error!(env, ContractError::InvalidToken);
+    };
+
+    let key = DataKey::TokenMetadata(token.clone());
+    env.storage().persistent().set(
+        &key,
+        &TokenMetadata {
+            name,
+            symbol,
+            decimals,
+        },
+    );
+    ttl::extend_persistent(env, &key);
+}
+
+fn insert_accepted_token(env: &Env, token: &Address) {
+    let mut accepted_tokens = get_accepted_tokens(env);
+    if !contains_token(&accepted_tokens, token) {
+        accepted_tokens.push_back(token.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AcceptedTokens, &accepted_tokens);
+        ttl::extend_persistent(env, &DataKey::AcceptedTokens);
+        events::publish_token_added_event(env, token.clone(), env.ledger().timestamp());
+    }
+}
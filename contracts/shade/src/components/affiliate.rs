@@ -0,0 +1,110 @@
+use crate::components::{custody, reentrancy, ttl};
+use crate::errors::{ContractError, GovernanceError};
+use crate::events;
+use crate::types::{AffiliateEarnings, DataKey, Invoice};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Payments can credit an affiliate. The merchant sets a commission in basis
+// points of its net proceeds; when a payment names an affiliate, that much
+// of the merchant's share stays in Shade for the affiliate to claim later.
+// Commissions are not clawed back when the invoice is refunded.
+const MAX_COMMISSION_BPS: u32 = 10_000;
+
+pub fn set_affiliate_commission(env: &Env, merchant: &Address, commission_bps: u32) {
+    merchant.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+    if commission_bps > MAX_COMMISSION_BPS {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let key = DataKey::AffiliateCommission(merchant_id);
+    if commission_bps == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &commission_bps);
+        ttl::extend_persistent(env, &key);
+    }
+
+    events::publish_affiliate_commission_set_event(
+        env,
+        merchant_id,
+        commission_bps,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_affiliate_commission(env: &Env, merchant_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AffiliateCommission(merchant_id))
+        .unwrap_or(0)
+}
+
+pub fn get_affiliate_earnings(
+    env: &Env,
+    affiliate: &Address,
+    token: &Address,
+) -> AffiliateEarnings {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AffiliateEarnings(
+            affiliate.clone(),
+            token.clone(),
+        ))
+        .unwrap_or(AffiliateEarnings {
+            accrued: 0,
+            claimed: 0,
+        })
+}
+
+// Called on settlement with the merchant's net proceeds. Returns the
+// commission withheld from them.
+pub fn accrue_commission(env: &Env, invoice: &Invoice, affiliate: &Address, net: i128) -> i128 {
+    let commission = net * get_affiliate_commission(env, invoice.merchant_id) as i128
+        / MAX_COMMISSION_BPS as i128;
+    if commission == 0 {
+        return 0;
+    }
+
+    let mut earnings = get_affiliate_earnings(env, affiliate, &invoice.token);
+    earnings.accrued += commission;
+    save_earnings(env, affiliate, &invoice.token, &earnings);
+
+    events::publish_commission_accrued_event(
+        env,
+        invoice.id,
+        affiliate.clone(),
+        invoice.token.clone(),
+        commission,
+    );
+    commission
+}
+
+pub fn claim_affiliate_commission(env: &Env, affiliate: &Address, token: &Address) -> i128 {
+    reentrancy::enter(env);
+    affiliate.require_auth();
+
+    let mut earnings = get_affiliate_earnings(env, affiliate, token);
+    let amount = earnings.accrued - earnings.claimed;
+    if amount == 0 {
+        panic_with_error!(env, GovernanceError::NothingToClaim);
+    }
+
+    earnings.claimed = earnings.accrued;
+    save_earnings(env, affiliate, token, &earnings);
+    custody::send(env, token, affiliate, amount);
+
+    events::publish_commission_claimed_event(env, affiliate.clone(), token.clone(), amount);
+    reentrancy::exit(env);
+    amount
+}
+
+fn save_earnings(env: &Env, affiliate: &Address, token: &Address, earnings: &AffiliateEarnings) {
+    let key = DataKey::AffiliateEarnings(affiliate.clone(), token.clone());
+    env.storage().persistent().set(&key, earnings);
+    ttl::extend_persistent(env, &key);
+}
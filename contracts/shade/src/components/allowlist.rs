@@ -0,0 +1,48 @@
+use crate::components::core;
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::DataKey;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// External contracts Shade calls into (oracles, payment hooks) must be
+// vetted by the admin first. Removal takes effect on the next call.
+
+pub fn add_trusted_contract(env: &Env, admin: &Address, contract: &Address) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::TrustedContract(contract.clone());
+    if !env.storage().persistent().has(&key) {
+        env.storage().persistent().set(&key, &true);
+        events::publish_trusted_contract_added_event(
+            env,
+            contract.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+pub fn remove_trusted_contract(env: &Env, admin: &Address, contract: &Address) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::TrustedContract(contract.clone());
+    if env.storage().persistent().has(&key) {
+        env.storage().persistent().remove(&key);
+        events::publish_trusted_contract_removed_event(
+            env,
+            contract.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+pub fn is_trusted_contract(env: &Env, contract: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TrustedContract(contract.clone()))
+}
+
+pub fn assert_trusted_contract(env: &Env, contract: &Address) {
+    if !is_trusted_contract(env, contract) {
+        panic_with_error!(env, ContractError::ContractNotTrusted);
+    }
+}
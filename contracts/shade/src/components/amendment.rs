@@ -0,0 +1,140 @@
+use crate::components::{admin, approval, contribution, expiry, invoice, merchant, milestone, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, InvoiceAmendment, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+
+// Every amendment is appended to the invoice's history so a payer or auditor
+// can see what the invoice said before. The history is never rewritten.
+pub const MAX_INVOICE_AMENDMENTS: u32 = 20;
+
+// Only pending invoices can be amended. The amount is fixed once someone has
+// contributed or the invoice is split into line items or milestones, since
+// those are checked against it; the token is fixed once anyone contributed.
+pub fn amend_invoice(
+    env: &Env,
+    caller: &Address,
+    invoice_id: u64,
+    new_amount: Option<i128>,
+    new_description: Option<String>,
+    new_expires_at: Option<u64>,
+    new_token: Option<Address>,
+) {
+    caller.require_auth();
+
+    let mut invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+    if new_amount.is_none()
+        && new_description.is_none()
+        && new_expires_at.is_none()
+        && new_token.is_none()
+    {
+        panic_with_error!(env, InvoiceError::InvalidAmendment);
+    }
+
+    let mut history = get_invoice_history(env, invoice_id);
+    if history.len() >= MAX_INVOICE_AMENDMENTS {
+        panic_with_error!(env, InvoiceError::InvalidAmendment);
+    }
+
+    let old_amount = invoice.amount;
+    let old_description = invoice.description.clone();
+    let old_expires_at = expiry::get_invoice_expiry(env, invoice_id);
+    let old_token = invoice.token.clone();
+
+    if let Some(amount) = new_amount {
+        if amount <= 0 {
+            panic_with_error!(env, ContractError::InvalidAmount);
+        }
+        if contribution::total_contributed(env, invoice_id) > 0 {
+            panic_with_error!(env, InvoiceError::InvoiceHasContributions);
+        }
+        if !invoice::get_invoice_line_items(env, invoice_id).is_empty() {
+            panic_with_error!(env, InvoiceError::InvalidLineItems);
+        }
+        if milestone::has_milestones(env, invoice_id) {
+            panic_with_error!(env, InvoiceError::InvalidMilestones);
+        }
+        invoice.amount = amount;
+    }
+
+    if let Some(token) = new_token {
+        if !admin::is_accepted_token(env, &token) {
+            panic_with_error!(env, ContractError::TokenNotAccepted);
+        }
+        if contribution::total_contributed(env, invoice_id) > 0 {
+            panic_with_error!(env, InvoiceError::InvoiceHasContributions);
+        }
+        invoice.token = token;
+    }
+
+    let repriced = invoice.amount != old_amount || invoice.token != old_token;
+    if repriced {
+        admin::assert_amount_within_bounds(
+            env,
+            &invoice.token,
+            invoice.amount,
+            merchant::is_merchant_verified(env, invoice.merchant_id),
+        );
+    }
+
+    if let Some(description) = new_description {
+        // Private invoices commit to a description hash instead.
+        if invoice::get_invoice_description_hash(env, invoice_id).is_some() {
+            panic_with_error!(env, InvoiceError::InvalidAmendment);
+        }
+        invoice.description = description;
+    }
+
+    if let Some(expires_at) = new_expires_at {
+        expiry::update_invoice_expiry(env, invoice_id, expires_at);
+    }
+
+    invoice::save_invoice(env, &invoice);
+    if repriced {
+        approval::hold_for_approval(
+            env,
+            invoice_id,
+            invoice.merchant_id,
+            &invoice.token,
+            invoice.amount,
+            caller,
+        );
+    }
+
+    let timestamp = env.ledger().timestamp();
+    history.push_back(InvoiceAmendment {
+        actor: caller.clone(),
+        timestamp,
+        old_amount,
+        new_amount: invoice.amount,
+        old_description,
+        new_description: invoice.description.clone(),
+        old_expires_at,
+        new_expires_at: expiry::get_invoice_expiry(env, invoice_id),
+        old_token,
+        new_token: invoice.token.clone(),
+    });
+    let key = DataKey::InvoiceAmendments(invoice_id);
+    env.storage().persistent().set(&key, &history);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_invoice_amended_event(
+        env,
+        invoice_id,
+        caller.clone(),
+        old_amount,
+        invoice.amount,
+        timestamp,
+    );
+}
+
+pub fn get_invoice_history(env: &Env, invoice_id: u64) -> Vec<InvoiceAmendment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceAmendments(invoice_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
@@ -0,0 +1,121 @@
+use crate::components::{invoice, merchant, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::DataKey;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Maker-checker control for high-value billing. Above a merchant's
+// per-token threshold, a new invoice can't be paid until someone other than
+// its creator approves it: a delegate when the merchant created it, or the
+// merchant (or another delegate) when a delegate did.
+
+pub fn set_invoice_approval_threshold(
+    env: &Env,
+    merchant_address: &Address,
+    token: &Address,
+    threshold: Option<i128>,
+) {
+    merchant_address.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let key = DataKey::InvoiceApprovalThreshold(merchant_id, token.clone());
+    match threshold {
+        Some(threshold) if threshold <= 0 => {
+            panic_with_error!(env, ContractError::InvalidAmount)
+        }
+        Some(threshold) => {
+            env.storage().persistent().set(&key, &threshold);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    events::publish_approval_threshold_set_event(
+        env,
+        merchant_id,
+        token.clone(),
+        threshold,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_invoice_approval_threshold(
+    env: &Env,
+    merchant_id: u64,
+    token: &Address,
+) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceApprovalThreshold(
+            merchant_id,
+            token.clone(),
+        ))
+}
+
+// Called on invoice creation; holds the invoice for approval when its
+// amount exceeds the threshold.
+pub fn hold_for_approval(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    token: &Address,
+    amount: i128,
+    creator: &Address,
+) {
+    let Some(threshold) = get_invoice_approval_threshold(env, merchant_id, token) else {
+        return;
+    };
+    if amount <= threshold {
+        return;
+    }
+
+    let key = DataKey::InvoicePendingApproval(invoice_id);
+    env.storage().persistent().set(&key, creator);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_invoice_approval_required_event(env, invoice_id, creator.clone(), amount);
+}
+
+pub fn approve_invoice(env: &Env, approver: &Address, invoice_id: u64) {
+    approver.require_auth();
+
+    let creator: Address = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvoicePendingApproval(invoice_id))
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvoiceNotAwaitingApproval));
+
+    let merchant_id = invoice::get_invoice(env, invoice_id).merchant_id;
+    let is_operator = *approver == merchant::get_merchant(env, merchant_id).address
+        || merchant::is_invoice_delegate(env, merchant_id, approver);
+    if !is_operator || *approver == creator {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InvoicePendingApproval(invoice_id));
+
+    events::publish_invoice_approved_event(
+        env,
+        invoice_id,
+        approver.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn is_invoice_awaiting_approval(env: &Env, invoice_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::InvoicePendingApproval(invoice_id))
+}
+
+pub fn assert_invoice_approved(env: &Env, invoice_id: u64) {
+    if is_invoice_awaiting_approval(env, invoice_id) {
+        panic_with_error!(env, InvoiceError::InvoiceAwaitingApproval);
+    }
+}
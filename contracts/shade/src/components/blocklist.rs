@@ -0,0 +1,50 @@
+use crate::components::access_control;
+use crate::errors::ComplianceError;
+use crate::events;
+use crate::types::{DataKey, Role};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Compliance blocklist of payer addresses, managed by the admin or an
+// operator. Blocked addresses cannot fund anything that pays a merchant.
+
+pub fn block_payer(env: &Env, caller: &Address, payer: &Address) {
+    access_control::assert_has_role(env, caller, Role::Operator);
+
+    let key = DataKey::BlockedPayer(payer.clone());
+    if !env.storage().persistent().has(&key) {
+        env.storage().persistent().set(&key, &true);
+        events::publish_payer_blocked_event(
+            env,
+            payer.clone(),
+            caller.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+pub fn unblock_payer(env: &Env, caller: &Address, payer: &Address) {
+    access_control::assert_has_role(env, caller, Role::Operator);
+
+    let key = DataKey::BlockedPayer(payer.clone());
+    if env.storage().persistent().has(&key) {
+        env.storage().persistent().remove(&key);
+        events::publish_payer_unblocked_event(
+            env,
+            payer.clone(),
+            caller.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+pub fn is_payer_blocked(env: &Env, payer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::BlockedPayer(payer.clone()))
+}
+
+pub fn assert_not_blocked(env: &Env, payer: &Address) {
+    if is_payer_blocked(env, payer) {
+        panic_with_error!(env, ComplianceError::PayerBlocked);
+    }
+}
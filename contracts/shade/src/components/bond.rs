@@ -0,0 +1,148 @@
+use crate::components::{admin, core, custody, merchant, reentrancy, refund, ttl};
+use crate::errors::{ComplianceError, ContractError};
+use crate::events;
+use crate::types::{DataKey, MerchantBond};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Merchants stake the configured bond when they register. It comes back to
+// them through `withdraw_bond` once a cooling-off period after a clean
+// offboarding has passed. Until then the admin can slash it to make payers
+// whole after fraud, whether or not the merchant is still active.
+pub const BOND_COOLING_OFF: u64 = refund::REFUND_WINDOW;
+
+pub fn set_registration_bond(env: &Env, admin: &Address, bond: &Option<MerchantBond>) {
+    core::assert_admin(env, admin);
+
+    match bond {
+        Some(bond) => {
+            if bond.amount <= 0 {
+                panic_with_error!(env, ContractError::InvalidAmount);
+            }
+            if !admin::is_accepted_token(env, &bond.token) {
+                panic_with_error!(env, ContractError::TokenNotAccepted);
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::RegistrationBond, bond);
+        }
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::RegistrationBond),
+    }
+
+    events::publish_registration_bond_set_event(env, bond.clone(), env.ledger().timestamp());
+}
+
+pub fn get_registration_bond(env: &Env) -> Option<MerchantBond> {
+    env.storage().persistent().get(&DataKey::RegistrationBond)
+}
+
+pub fn get_merchant_bond(env: &Env, merchant_id: u64) -> Option<MerchantBond> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantBond(merchant_id))
+}
+
+// Called by register_merchant. The bond terms are copied onto the merchant
+// so later changes to the configured bond don't affect existing stakes.
+pub fn stake_registration_bond(env: &Env, merchant_address: &Address, merchant_id: u64) {
+    let Some(bond) = get_registration_bond(env) else {
+        return;
+    };
+
+    reentrancy::enter(env);
+    custody::receive(env, &bond.token, merchant_address, bond.amount);
+
+    let key = DataKey::MerchantBond(merchant_id);
+    env.storage().persistent().set(&key, &bond);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_bond_staked_event(env, merchant_id, bond.token, bond.amount);
+    reentrancy::exit(env);
+}
+
+// Called by offboard_merchant once the merchant has been deactivated. The
+// bond stays slashable for BOND_COOLING_OFF, long enough for refund claims
+// on the merchant's last invoices to surface, before it can be withdrawn.
+pub fn start_cooling_off(env: &Env, merchant_id: u64) {
+    if get_merchant_bond(env, merchant_id).is_none() {
+        return;
+    }
+
+    let release_at = env.ledger().timestamp() + BOND_COOLING_OFF;
+    let key = DataKey::MerchantBondReleaseAt(merchant_id);
+    env.storage().persistent().set(&key, &release_at);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_bond_cooling_off_started_event(env, merchant_id, release_at);
+}
+
+pub fn get_bond_release_at(env: &Env, merchant_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantBondReleaseAt(merchant_id))
+}
+
+pub fn withdraw_bond(env: &Env, merchant_address: &Address) {
+    reentrancy::enter(env);
+    merchant_address.require_auth();
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+    let bond = get_merchant_bond(env, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, ComplianceError::BondNotFound));
+    match get_bond_release_at(env, merchant_id) {
+        Some(release_at) if env.ledger().timestamp() >= release_at => {}
+        _ => panic_with_error!(env, ComplianceError::BondLocked),
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MerchantBond(merchant_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MerchantBondReleaseAt(merchant_id));
+    custody::send(env, &bond.token, merchant_address, bond.amount);
+
+    events::publish_bond_released_event(env, merchant_id, bond.token, bond.amount);
+    reentrancy::exit(env);
+}
+
+pub fn slash_bond(env: &Env, admin: &Address, merchant_id: u64, amount: i128, recipient: &Address) {
+    reentrancy::enter(env);
+    core::assert_admin(env, admin);
+    merchant::get_merchant(env, merchant_id);
+
+    let key = DataKey::MerchantBond(merchant_id);
+    let mut bond = get_merchant_bond(env, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, ComplianceError::BondNotFound));
+    if amount <= 0 || amount > bond.amount {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    bond.amount -= amount;
+    if bond.amount == 0 {
+        env.storage().persistent().remove(&key);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::MerchantBondReleaseAt(merchant_id));
+    } else {
+        env.storage().persistent().set(&key, &bond);
+        ttl::extend_persistent(env, &key);
+    }
+
+    custody::send(env, &bond.token, recipient, amount);
+
+    events::publish_bond_slashed_event(
+        env,
+        merchant_id,
+        recipient.clone(),
+        amount,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
@@ -0,0 +1,230 @@
+use crate::components::{
+    activity, admin, blocklist, core, custody, customer, merchant, reentrancy, stats, ttl, velocity,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ActivityKind, Campaign, CampaignStatus, DataKey};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Campaigns pool contributions until the deadline. If the goal is reached
+// the merchant can collect the pool (net of the protocol fee); otherwise
+// every contributor reclaims exactly what they put in.
+
+pub fn create_campaign(
+    env: &Env,
+    merchant_address: &Address,
+    token: &Address,
+    goal: i128,
+    deadline: u64,
+) -> u64 {
+    merchant_address.require_auth();
+
+    if goal <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if deadline <= env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::InvalidCampaignDeadline);
+    }
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    if !merchant::is_merchant(env, merchant_address) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap();
+    merchant::assert_merchant_approved(env, merchant_id);
+
+    let campaign_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CampaignCount)
+        .unwrap_or(0);
+    let campaign_id = campaign_count + 1;
+
+    let campaign = Campaign {
+        id: campaign_id,
+        merchant_id,
+        token: token.clone(),
+        goal,
+        raised: 0,
+        deadline,
+        status: CampaignStatus::Active,
+    };
+    save_campaign(env, &campaign);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CampaignCount, &campaign_id);
+
+    events::publish_campaign_created_event(
+        env,
+        campaign_id,
+        merchant_id,
+        token.clone(),
+        goal,
+        deadline,
+    );
+    campaign_id
+}
+
+pub fn contribute(env: &Env, contributor: &Address, campaign_id: u64, amount: i128) {
+    reentrancy::enter(env);
+    let mut campaign = get_campaign(env, campaign_id);
+    core::require_payment_auth(env, contributor, campaign_id, &campaign.token, amount);
+    blocklist::assert_not_blocked(env, contributor);
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if campaign.status != CampaignStatus::Active || env.ledger().timestamp() >= campaign.deadline {
+        panic_with_error!(env, ContractError::CampaignClosed);
+    }
+
+    velocity::record_payment(env, contributor, &campaign.token, amount);
+    custody::receive(env, &campaign.token, contributor, amount);
+
+    campaign.raised += amount;
+    save_campaign(env, &campaign);
+
+    let key = DataKey::CampaignContribution(campaign_id, contributor.clone());
+    let contributed = get_contribution(env, campaign_id, contributor);
+    env.storage()
+        .persistent()
+        .set(&key, &(contributed + amount));
+    ttl::extend_persistent(env, &key);
+
+    events::publish_campaign_contribution_event(
+        env,
+        campaign_id,
+        contributor.clone(),
+        amount,
+        campaign.raised,
+    );
+    reentrancy::exit(env);
+}
+
+// Permissionless once the deadline passes; decides the campaign outcome
+// and pays the merchant if the goal was met.
+pub fn finalize_campaign(env: &Env, campaign_id: u64) -> CampaignStatus {
+    reentrancy::enter(env);
+
+    let mut campaign = get_campaign(env, campaign_id);
+    if campaign.status != CampaignStatus::Active {
+        panic_with_error!(env, ContractError::CampaignClosed);
+    }
+    if env.ledger().timestamp() < campaign.deadline {
+        panic_with_error!(env, ContractError::CampaignStillActive);
+    }
+
+    if campaign.raised >= campaign.goal {
+        campaign.status = CampaignStatus::Succeeded;
+        save_campaign(env, &campaign);
+
+        let merchant_address = merchant::get_merchant(env, campaign.merchant_id).address;
+        let fee = admin::calculate_fee(env, &campaign.token, campaign.raised, &[&merchant_address]);
+        admin::collect_fee(env, &campaign.token, fee);
+        stats::record_volume(env, &campaign.token, campaign.raised);
+        activity::record_activity(
+            env,
+            ActivityKind::CampaignPayout,
+            campaign_id,
+            &campaign.token,
+            campaign.raised,
+        );
+        custody::send(
+            env,
+            &campaign.token,
+            &merchant_address,
+            campaign.raised - fee,
+        );
+    } else {
+        campaign.status = CampaignStatus::Failed;
+        save_campaign(env, &campaign);
+    }
+
+    events::publish_campaign_finalized_event(
+        env,
+        campaign_id,
+        campaign.status,
+        campaign.raised,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+    campaign.status
+}
+
+pub fn claim_refund(env: &Env, contributor: &Address, campaign_id: u64) -> i128 {
+    reentrancy::enter(env);
+    contributor.require_auth();
+
+    let mut campaign = get_campaign(env, campaign_id);
+    if campaign.status == CampaignStatus::Active
+        && env.ledger().timestamp() >= campaign.deadline
+        && campaign.raised < campaign.goal
+    {
+        campaign.status = CampaignStatus::Failed;
+        save_campaign(env, &campaign);
+    }
+    if campaign.status != CampaignStatus::Failed {
+        panic_with_error!(env, ContractError::CampaignNotRefundable);
+    }
+
+    let amount = get_contribution(env, campaign_id, contributor);
+    if amount == 0 {
+        panic_with_error!(env, ContractError::NothingToRefund);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CampaignContribution(
+            campaign_id,
+            contributor.clone(),
+        ));
+
+    custody::send(
+        env,
+        &campaign.token,
+        &customer::refund_address(env, contributor),
+        amount,
+    );
+    activity::record_activity(
+        env,
+        ActivityKind::CampaignRefund,
+        campaign_id,
+        &campaign.token,
+        amount,
+    );
+
+    events::publish_campaign_refunded_event(env, campaign_id, contributor.clone(), amount);
+    reentrancy::exit(env);
+    amount
+}
+
+pub fn get_campaign(env: &Env, campaign_id: u64) -> Campaign {
+    let key = DataKey::Campaign(campaign_id);
+    let campaign = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::CampaignNotFound));
+    ttl::extend_persistent(env, &key);
+    campaign
+}
+
+pub fn get_contribution(env: &Env, campaign_id: u64, contributor: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CampaignContribution(
+            campaign_id,
+            contributor.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+fn save_campaign(env: &Env, campaign: &Campaign) {
+    let key = DataKey::Campaign(campaign.id);
+    env.storage().persistent().set(&key, campaign);
+    ttl::extend_persistent(env, &key);
+}
@@ -0,0 +1,133 @@
+use crate::components::{
+    contribution, core, custody, distribution, expiry, gift_card, invoice, payment_link,
+    reentrancy, ttl,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Anyone can close out expired gift cards and payment links in batches.
+// When the admin sets a bounty for a token, the caller earns it for each
+// entry processed in that token, paid from the token's undistributed
+// protocol fees, so cleanup doesn't depend on the admin running
+// maintenance. Entries that can't be processed are skipped, and the batch
+// size caps what a single call can earn.
+pub const MAX_SWEEP_BATCH: u32 = 20;
+
+pub fn set_cleanup_bounty(env: &Env, admin: &Address, token: &Address, amount: i128) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::CleanupBounty(token.clone());
+    if amount < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    } else if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+        ttl::extend_persistent(env, &key);
+    }
+
+    events::publish_cleanup_bounty_set_event(env, token.clone(), amount, env.ledger().timestamp());
+}
+
+pub fn get_cleanup_bounty(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CleanupBounty(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn sweep_expired(
+    env: &Env,
+    caller: &Address,
+    gift_card_ids: &Vec<u64>,
+    payment_link_ids: &Vec<u64>,
+) -> u32 {
+    reentrancy::enter(env);
+    caller.require_auth();
+
+    if gift_card_ids.len() + payment_link_ids.len() > MAX_SWEEP_BATCH {
+        panic_with_error!(env, ContractError::BatchTooLarge);
+    }
+
+    let mut processed = 0;
+    for card_id in gift_card_ids.iter() {
+        if let Some(token) = gift_card::try_expire_gift_card(env, card_id) {
+            processed += 1;
+            pay_bounty(env, caller, &token);
+        }
+    }
+    for link_id in payment_link_ids.iter() {
+        if let Some(token) = payment_link::try_refund_payment_link(env, link_id) {
+            processed += 1;
+            pay_bounty(env, caller, &token);
+        }
+    }
+
+    events::publish_expired_entries_swept_event(
+        env,
+        caller.clone(),
+        processed,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+    processed
+}
+
+// Cancels expired invoices that are still awaiting payment and returns
+// any escrowed contributions to their payers. Each one publishes an
+// InvoiceExpired event, so merchants can close out the order without
+// polling. A pay attempt on an expired invoice fails and its events roll
+// back with it, so this sweep is where expiry is announced. Ids that don't
+// exist, aren't payable or haven't expired are skipped.
+pub fn cancel_expired_invoices(env: &Env, invoice_ids: &Vec<u64>) -> u32 {
+    reentrancy::enter(env);
+    if invoice_ids.len() > MAX_SWEEP_BATCH {
+        panic_with_error!(env, ContractError::BatchTooLarge);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut cancelled = 0;
+    for invoice_id in invoice_ids.iter() {
+        let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        else {
+            continue;
+        };
+        let Some(expires_at) = expiry::get_invoice_expiry(env, invoice_id) else {
+            continue;
+        };
+        if now < expires_at || !invoice::is_payable_status(invoice.status) {
+            continue;
+        }
+
+        events::publish_invoice_expired_event(
+            env,
+            invoice_id,
+            invoice.merchant_id,
+            expires_at,
+            now,
+        );
+        invoice::set_invoice_status(env, invoice_id, InvoiceStatus::Cancelled);
+        let refunded = contribution::refund_contributions(env, &invoice);
+        events::publish_expired_invoice_cancelled_event(env, invoice_id, refunded, now);
+        cancelled += 1;
+    }
+    reentrancy::exit(env);
+    cancelled
+}
+
+fn pay_bounty(env: &Env, caller: &Address, token: &Address) {
+    let bounty =
+        get_cleanup_bounty(env, token).min(distribution::get_undistributed_fees(env, token));
+    if bounty <= 0 {
+        return;
+    }
+
+    distribution::spend_undistributed_fees(env, token, bounty);
+    custody::send(env, token, caller, bounty);
+    events::publish_cleanup_bounty_paid_event(env, caller.clone(), token.clone(), bounty);
+}
@@ -0,0 +1,184 @@
+use crate::components::{
+    approval, blocklist, core, custody, customer, expiry, invoice, merchant, reentrancy, ttl,
+    velocity,
+};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env, Map};
+
+// Partial payments are escrowed per payer until the invoice is covered,
+// then the invoice settles in one go. By default the first contributor
+// owns the invoice; multi-payer invoices (split bills) accept
+// contributions from any address. Contributors can take their share back
+// if the invoice can no longer be settled. Once it settles, the final
+// shares are kept as the invoice's payer shares so refunds can be split
+// the same way.
+pub const MAX_CONTRIBUTORS: u32 = 20;
+
+pub fn set_invoice_multi_payer(env: &Env, caller: &Address, invoice_id: u64, multi_payer: bool) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+    if !get_contributions(env, invoice_id).is_empty() {
+        panic_with_error!(env, InvoiceError::InvoiceHasContributions);
+    }
+
+    let key = DataKey::InvoiceMultiPayer(invoice_id);
+    if multi_payer {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_multi_payer(env: &Env, invoice_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceMultiPayer(invoice_id))
+        .unwrap_or(false)
+}
+
+pub fn get_contributions(env: &Env, invoice_id: u64) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceContributions(invoice_id))
+        .unwrap_or(Map::new(env))
+}
+
+pub fn get_contribution(env: &Env, invoice_id: u64, payer: &Address) -> i128 {
+    get_contributions(env, invoice_id)
+        .get(payer.clone())
+        .unwrap_or(0)
+}
+
+pub fn total_contributed(env: &Env, invoice_id: u64) -> i128 {
+    get_contributions(env, invoice_id).values().iter().sum()
+}
+
+pub fn get_payer_shares(env: &Env, invoice_id: u64) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoicePayerShares(invoice_id))
+        .unwrap_or(Map::new(env))
+}
+
+pub fn contribute_to_invoice(env: &Env, payer: &Address, invoice_id: u64, amount: i128) -> Invoice {
+    reentrancy::enter(env);
+    let invoice = invoice::get_invoice(env, invoice_id);
+    core::require_payment_auth(env, payer, invoice_id, &invoice.token, amount);
+    blocklist::assert_not_blocked(env, payer);
+    invoice::assert_payable(env, &invoice);
+    invoice::assert_allowed_payer(env, invoice_id, payer);
+    expiry::assert_invoice_not_expired(env, invoice_id);
+    approval::assert_invoice_approved(env, invoice_id);
+
+    let mut contributions = get_contributions(env, invoice_id);
+    let existing = contributions.get(payer.clone());
+    if existing.is_none() {
+        let locked = !contributions.is_empty() && !is_multi_payer(env, invoice_id);
+        if locked || contributions.len() >= MAX_CONTRIBUTORS {
+            panic_with_error!(env, ContractError::NotAuthorized);
+        }
+    }
+
+    let contributed: i128 = contributions.values().iter().sum();
+    let remaining = invoice::amount_due(env, &invoice) - contributed;
+    if amount <= 0 || amount > remaining {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    velocity::record_payment(env, payer, &invoice.token, amount);
+    custody::receive(env, &invoice.token, payer, amount);
+
+    let key = DataKey::InvoiceContributions(invoice_id);
+    events::publish_invoice_contribution_event(
+        env,
+        invoice_id,
+        payer.clone(),
+        amount,
+        contributed + amount,
+    );
+
+    contributions.set(payer.clone(), existing.unwrap_or(0) + amount);
+    let invoice = if amount == remaining {
+        env.storage().persistent().remove(&key);
+        let shares_key = DataKey::InvoicePayerShares(invoice_id);
+        env.storage().persistent().set(&shares_key, &contributions);
+        ttl::extend_persistent(env, &shares_key);
+        invoice::settle_from_escrow(env, &invoice, payer)
+    } else {
+        env.storage().persistent().set(&key, &contributions);
+        ttl::extend_persistent(env, &key);
+        invoice
+    };
+    reentrancy::exit(env);
+    invoice
+}
+
+// Returns every escrowed contribution to its payer, e.g. when an expired
+// invoice is cancelled. Returns the total refunded.
+pub fn refund_contributions(env: &Env, invoice: &Invoice) -> i128 {
+    let key = DataKey::InvoiceContributions(invoice.id);
+    let contributions = get_contributions(env, invoice.id);
+    if contributions.is_empty() {
+        return 0;
+    }
+    env.storage().persistent().remove(&key);
+
+    let mut refunded = 0;
+    for (payer, amount) in contributions.iter() {
+        custody::send(
+            env,
+            &invoice.token,
+            &customer::refund_address(env, &payer),
+            amount,
+        );
+        events::publish_contribution_withdrawn_event(env, invoice.id, payer, amount);
+        refunded += amount;
+    }
+    refunded
+}
+
+// Returns a contributor's escrowed share once the invoice can no longer be
+// settled through contributions: it expired, was cancelled or was paid by
+// other means.
+pub fn withdraw_contribution(env: &Env, payer: &Address, invoice_id: u64) -> i128 {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let expired = expiry::get_invoice_expiry(env, invoice_id)
+        .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at);
+    if invoice::is_payable_status(invoice.status) && !expired {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceContributions(invoice_id);
+    let mut contributions = get_contributions(env, invoice_id);
+    let amount = contributions
+        .get(payer.clone())
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::NoContribution));
+    contributions.remove(payer.clone());
+    if contributions.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &contributions);
+        ttl::extend_persistent(env, &key);
+    }
+
+    custody::send(
+        env,
+        &invoice.token,
+        &customer::refund_address(env, payer),
+        amount,
+    );
+    events::publish_contribution_withdrawn_event(env, invoice_id, payer.clone(), amount);
+    reentrancy::exit(env);
+    amount
+}
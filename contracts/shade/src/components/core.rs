@@ -1,11 +1,14 @@
+use crate::components::{admin, expiry, migration, pausable, ttl};
 use crate::errors::ContractError;
-use crate::types::DataKey;
-use soroban_sdk::{panic_with_error, Address, Env};
+use crate::types::{ContractInfo, DataKey, EntityCounts, ProtocolConfig, TokenFee};
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Vec};
+
+// (major, minor, patch) of the code in this build. Bump on every release so
+// clients can feature-detect entrypoints on a deployment.
+pub const CONTRACT_VERSION: (u32, u32, u32) = (0, 3, 0);
 
 pub fn get_admin(env: &Env) -> Address {
-    env.storage()
-        .persistent()
-        .get(&DataKey::Admin)
+    ttl::get_persistent(env, &DataKey::Admin)
         .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized))
 }
 
@@ -15,3 +18,60 @@ pub fn assert_admin(env: &Env, admin: &Address) {
         panic_with_error!(env, ContractError::NotAuthorized);
     }
 }
+
+// Payer authorization for entrypoints whose own arguments don't carry the
+// amount being spent. Contract wallets (policy signers, session keys) see
+// `(reference_id, token, amount)` in their auth context instead of bare ids,
+// so they can enforce spend limits without reading Shade's storage.
+pub fn require_payment_auth(
+    env: &Env,
+    payer: &Address,
+    reference_id: u64,
+    token: &Address,
+    amount: i128,
+) {
+    payer.require_auth_for_args((reference_id, token.clone(), amount).into_val(env));
+}
+
+pub fn get_counts(env: &Env) -> EntityCounts {
+    EntityCounts {
+        merchants: ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0),
+        invoices: ttl::get_persistent(env, &DataKey::InvoiceCount).unwrap_or(0),
+    }
+}
+
+pub fn get_version() -> (u32, u32, u32) {
+    CONTRACT_VERSION
+}
+
+pub fn get_contract_info(env: &Env) -> ContractInfo {
+    let mut info: ContractInfo = ttl::get_persistent(env, &DataKey::ContractInfo)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotInitialized));
+    // The stored copy reflects the release that last wrote it; report the
+    // version of the code actually running.
+    info.version = CONTRACT_VERSION;
+    info
+}
+
+// Snapshot of protocol-wide settings so SDKs and explorers can read the
+// configuration in one call instead of one getter per field.
+pub fn get_config(env: &Env) -> ProtocolConfig {
+    let mut token_fees = Vec::new(env);
+    for token in admin::get_accepted_tokens(env).iter() {
+        token_fees.push_back(TokenFee {
+            fee: admin::get_fee(env, &token),
+            token,
+        });
+    }
+
+    ProtocolConfig {
+        admin: get_admin(env),
+        token_fees,
+        native_token: admin::get_native_token(env),
+        default_invoice_expiry: expiry::get_default_invoice_expiry(env),
+        paused: pausable::is_paused(env),
+        legacy_event_format: admin::is_legacy_event_format(env),
+        schema_version: migration::get_schema_version(env),
+        version: CONTRACT_VERSION,
+    }
+}
@@ -0,0 +1,113 @@
+use crate::components::{core, ttl};
+use crate::errors::{ComplianceError, ContractError, GovernanceError};
+use crate::events;
+use crate::types::DataKey;
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::{
+    panic_with_error, token, vec, Address, Env, Error, IntoVal, InvokeError, Symbol,
+};
+
+// Every token movement in or out of the contract goes through `receive` and
+// `send`, which keep a per-token count of what Shade knows it holds (escrow,
+// bonds, campaign funds, fees awaiting distribution). Anything above that
+// count was transferred straight to the contract address and is the only
+// balance `recover_tokens` may sweep.
+
+pub fn receive(env: &Env, token: &Address, from: &Address, amount: i128) {
+    transfer(env, token, from, &env.current_contract_address(), amount);
+    set_custody_balance(env, token, get_custody_balance(env, token) + amount);
+}
+
+pub fn send(env: &Env, token: &Address, to: &Address, amount: i128) {
+    transfer(env, token, &env.current_contract_address(), to, amount);
+    set_custody_balance(env, token, get_custody_balance(env, token) - amount);
+}
+
+// Lets `to` pull `amount` out of custody during its next call, for contracts
+// such as merchant accounts that take deposits by transferring from the
+// depositor themselves.
+pub fn authorize_send(env: &Env, token: &Address, to: &Address, amount: i128) {
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (env.current_contract_address(), to.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    set_custody_balance(env, token, get_custody_balance(env, token) - amount);
+}
+
+// Stellar classic assets wrapped as SACs fail transfers when the issuer has
+// frozen a trustline or the recipient has none. Those failures surface as
+// `AssetFrozen` when the sending side is frozen and `TransferBlocked` when
+// the recipient cannot hold the asset; any other token error, including a
+// trap inside the token contract, is re-raised unchanged.
+const SAC_BALANCE_DEAUTHORIZED: u32 = 11;
+const SAC_TRUSTLINE_MISSING: u32 = 13;
+
+fn transfer(env: &Env, token: &Address, from: &Address, to: &Address, amount: i128) {
+    let error = match token::Client::new(env, token).try_transfer(from, to, &amount) {
+        Ok(_) => return,
+        Err(Ok(error)) => error,
+        Err(Err(InvokeError::Contract(code))) => Error::from_contract_error(code),
+        Err(Err(InvokeError::Abort)) => {
+            Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+        }
+    };
+
+    if error == Error::from_contract_error(SAC_TRUSTLINE_MISSING) {
+        panic_with_error!(env, ComplianceError::TransferBlocked);
+    }
+    if error == Error::from_contract_error(SAC_BALANCE_DEAUTHORIZED) {
+        match token::StellarAssetClient::new(env, token).try_authorized(from) {
+            Ok(Ok(false)) => panic_with_error!(env, ComplianceError::AssetFrozen),
+            Ok(Ok(true)) => panic_with_error!(env, ComplianceError::TransferBlocked),
+            _ => {}
+        }
+    }
+    panic_with_error!(env, error);
+}
+
+pub fn get_custody_balance(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CustodyBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_custody_balance(env: &Env, token: &Address, balance: i128) {
+    let key = DataKey::CustodyBalance(token.clone());
+    env.storage().persistent().set(&key, &balance);
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn get_recoverable_balance(env: &Env, token: &Address) -> i128 {
+    let balance = token::Client::new(env, token).balance(&env.current_contract_address());
+    (balance - get_custody_balance(env, token)).max(0)
+}
+
+pub fn recover_tokens(env: &Env, admin: &Address, token: &Address, amount: i128, to: &Address) {
+    core::assert_admin(env, admin);
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if amount > get_recoverable_balance(env, token) {
+        panic_with_error!(env, GovernanceError::InsufficientRecoverableBalance);
+    }
+
+    transfer(env, token, &env.current_contract_address(), to, amount);
+
+    events::publish_tokens_recovered_event(
+        env,
+        token.clone(),
+        amount,
+        to.clone(),
+        env.ledger().timestamp(),
+    );
+}
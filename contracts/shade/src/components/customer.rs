@@ -0,0 +1,69 @@
+use crate::components::{admin, ttl};
+use crate::errors::{AccountError, ContractError};
+use crate::events;
+use crate::types::{CustomerProfile, DataKey};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env};
+
+// Optional registry where a payer records how it wants to be paid back.
+// Refunds and returned contributions go to the registered refund address
+// instead of the wallet that paid. The preferred token is advisory: Shade
+// does not convert between tokens, so merchants read it when billing.
+// The contact hash lets off-chain systems match a customer without the
+// contact details being stored on-chain.
+
+pub fn register_customer(
+    env: &Env,
+    customer: &Address,
+    preferred_token: Option<Address>,
+    refund_address: Option<Address>,
+    contact_hash: Option<BytesN<32>>,
+) {
+    customer.require_auth();
+
+    if let Some(token) = &preferred_token {
+        if !admin::is_accepted_token(env, token) {
+            panic_with_error!(env, ContractError::TokenNotAccepted);
+        }
+    }
+
+    let registered_at = get_customer(env, customer)
+        .map(|profile| profile.registered_at)
+        .unwrap_or_else(|| env.ledger().timestamp());
+    let profile = CustomerProfile {
+        preferred_token,
+        refund_address,
+        contact_hash,
+        registered_at,
+    };
+
+    let key = DataKey::CustomerProfile(customer.clone());
+    env.storage().persistent().set(&key, &profile);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_customer_registered_event(env, customer.clone(), env.ledger().timestamp());
+}
+
+pub fn remove_customer(env: &Env, customer: &Address) {
+    customer.require_auth();
+
+    let key = DataKey::CustomerProfile(customer.clone());
+    if !env.storage().persistent().has(&key) {
+        panic_with_error!(env, AccountError::CustomerNotFound);
+    }
+    env.storage().persistent().remove(&key);
+
+    events::publish_customer_removed_event(env, customer.clone(), env.ledger().timestamp());
+}
+
+pub fn get_customer(env: &Env, customer: &Address) -> Option<CustomerProfile> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CustomerProfile(customer.clone()))
+}
+
+// Where money owed back to `payer` should be sent.
+pub fn refund_address(env: &Env, payer: &Address) -> Address {
+    get_customer(env, payer)
+        .and_then(|profile| profile.refund_address)
+        .unwrap_or_else(|| payer.clone())
+}
@@ -0,0 +1,131 @@
+use crate::components::{admin, core, custody, reentrancy, ttl};
+use crate::errors::GovernanceError;
+use crate::events;
+use crate::types::{DataKey, DistributionShare};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Collected protocol fees are shared out between weighted recipients
+// (stakers, partners). `distribute_fees` can be called by anyone; it only
+// credits fees collected since the previous run, and rounding dust stays
+// undistributed for the next one. Recipients pull their share with
+// `claim_distribution`.
+
+pub fn set_distribution_shares(env: &Env, admin: &Address, shares: &Vec<DistributionShare>) {
+    core::assert_admin(env, admin);
+
+    if shares.is_empty() || total_weight(shares) == 0 {
+        panic_with_error!(env, GovernanceError::InvalidDistributionWeights);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::DistributionShares, shares);
+    ttl::extend_persistent(env, &DataKey::DistributionShares);
+
+    events::publish_distribution_shares_set_event(env, shares.len(), env.ledger().timestamp());
+}
+
+pub fn get_distribution_shares(env: &Env) -> Vec<DistributionShare> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DistributionShares)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn get_undistributed_fees(env: &Env, token: &Address) -> i128 {
+    admin::get_collected_fees(env, token) - get_distributed_fees(env, token)
+}
+
+pub fn distribute_fees(env: &Env, token: &Address) -> i128 {
+    let shares = get_distribution_shares(env);
+    let weight = total_weight(&shares);
+    if weight == 0 {
+        panic_with_error!(env, GovernanceError::InvalidDistributionWeights);
+    }
+
+    let available = get_undistributed_fees(env, token);
+    let mut distributed = 0;
+    for share in shares.iter() {
+        let amount = available * share.weight as i128 / weight as i128;
+        if amount == 0 {
+            continue;
+        }
+
+        let key = DataKey::DistributionClaimable(share.recipient.clone(), token.clone());
+        let claimable = get_claimable_distribution(env, &share.recipient, token);
+        env.storage().persistent().set(&key, &(claimable + amount));
+        ttl::extend_persistent(env, &key);
+        distributed += amount;
+    }
+
+    let key = DataKey::DistributedFees(token.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(get_distributed_fees(env, token) + distributed));
+    ttl::extend_persistent(env, &key);
+
+    events::publish_fees_distributed_event(
+        env,
+        token.clone(),
+        distributed,
+        env.ledger().timestamp(),
+    );
+    distributed
+}
+
+pub fn get_claimable_distribution(env: &Env, recipient: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DistributionClaimable(
+            recipient.clone(),
+            token.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+pub fn claim_distribution(env: &Env, recipient: &Address, token: &Address) -> i128 {
+    reentrancy::enter(env);
+    recipient.require_auth();
+
+    let amount = get_claimable_distribution(env, recipient, token);
+    if amount == 0 {
+        panic_with_error!(env, GovernanceError::NothingToClaim);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DistributionClaimable(
+            recipient.clone(),
+            token.clone(),
+        ));
+    custody::send(env, token, recipient, amount);
+
+    events::publish_distribution_claimed_event(env, recipient.clone(), token.clone(), amount);
+    reentrancy::exit(env);
+    amount
+}
+
+// Pays protocol work (e.g. cleanup bounties) out of undistributed fees by
+// counting it as already distributed.
+pub fn spend_undistributed_fees(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::DistributedFees(token.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(get_distributed_fees(env, token) + amount));
+    ttl::extend_persistent(env, &key);
+}
+
+fn get_distributed_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DistributedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+fn total_weight(shares: &Vec<DistributionShare>) -> u64 {
+    let mut total = 0;
+    for share in shares.iter() {
+        total += share.weight as u64;
+    }
+    total
+}
@@ -0,0 +1,53 @@
+use crate::components::{invoice, merchant, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// A due date doesn't stop an invoice from being paid, unlike an expiry.
+// Once it has passed anyone may call `mark_overdue`, which moves the
+// invoice to `Overdue` so indexers can send reminders; overdue invoices
+// remain payable.
+
+// `None` removes the due date.
+pub fn set_invoice_due_date(env: &Env, caller: &Address, invoice_id: u64, due_at: Option<u64>) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceDueDate(invoice_id);
+    match due_at {
+        Some(due_at) => {
+            if due_at <= env.ledger().timestamp() {
+                panic_with_error!(env, InvoiceError::InvalidDueDate);
+            }
+            env.storage().persistent().set(&key, &due_at);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_invoice_due_date(env: &Env, invoice_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceDueDate(invoice_id))
+}
+
+pub fn mark_overdue(env: &Env, invoice_id: u64) {
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let due_at = get_invoice_due_date(env, invoice_id)
+        .filter(|due_at| env.ledger().timestamp() >= *due_at)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvoiceNotOverdue));
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    invoice::set_invoice_status(env, invoice_id, InvoiceStatus::Overdue);
+
+    events::publish_invoice_overdue_event(env, invoice_id, due_at, env.ledger().timestamp());
+}
@@ -0,0 +1,138 @@
+use crate::components::{invoice, merchant, milestone, reentrancy, routing, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, EscrowHold, Invoice, InvoiceStatus};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Escrow invoices keep the merchant's net proceeds in Shade after payment.
+// The payer releases them with `confirm_delivery`; otherwise anyone can
+// release them once the escrow period has run out. Refunds issued while
+// funds are held are paid from the hold first. Milestone invoices are
+// always held and release in parts as the payer approves each milestone;
+// without an escrow period they never release on their own.
+
+pub fn set_invoice_escrow(env: &Env, caller: &Address, invoice_id: u64, period: Option<u64>) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceEscrowPeriod(invoice_id);
+    match period {
+        Some(0) => panic_with_error!(env, InvoiceError::InvalidEscrowPeriod),
+        Some(period) => {
+            env.storage().persistent().set(&key, &period);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_invoice_escrow_period(env: &Env, invoice_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceEscrowPeriod(invoice_id))
+}
+
+pub fn get_escrow_hold(env: &Env, invoice_id: u64) -> Option<EscrowHold> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceEscrowHold(invoice_id))
+}
+
+// Called on settlement in place of paying the merchant directly.
+pub fn pay_or_hold(env: &Env, invoice: &Invoice, merchant_address: &Address, net: i128) {
+    let period = get_invoice_escrow_period(env, invoice.id);
+    let has_milestones = milestone::has_milestones(env, invoice.id);
+    if period.is_none() && !has_milestones {
+        routing::pay_merchant(
+            env,
+            invoice.id,
+            invoice.merchant_id,
+            merchant_address,
+            &invoice.token,
+            net,
+        );
+        return;
+    }
+    if has_milestones {
+        milestone::assign_release_amounts(env, invoice, net);
+    }
+
+    let hold = EscrowHold {
+        amount: net,
+        release_at: period.map_or(u64::MAX, |period| env.ledger().timestamp() + period),
+    };
+    save_hold(env, invoice.id, &hold);
+    events::publish_escrow_held_event(env, invoice.id, net, hold.release_at);
+}
+
+pub fn confirm_delivery(env: &Env, payer: &Address, invoice_id: u64) {
+    reentrancy::enter(env);
+    payer.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.payer != Some(payer.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    release(env, &invoice, true);
+    reentrancy::exit(env);
+}
+
+pub fn release_escrow(env: &Env, invoice_id: u64) {
+    reentrancy::enter(env);
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let hold = get_escrow_hold(env, invoice_id)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::EscrowNotHeld));
+    if env.ledger().timestamp() < hold.release_at {
+        panic_with_error!(env, InvoiceError::EscrowLocked);
+    }
+    release(env, &invoice, false);
+    reentrancy::exit(env);
+}
+
+// Takes up to `amount` out of the hold and returns how much was covered.
+pub fn take_from_hold(env: &Env, invoice_id: u64, amount: i128) -> i128 {
+    let Some(mut hold) = get_escrow_hold(env, invoice_id) else {
+        return 0;
+    };
+
+    let taken = amount.min(hold.amount);
+    hold.amount -= taken;
+    if hold.amount == 0 {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::InvoiceEscrowHold(invoice_id));
+    } else {
+        save_hold(env, invoice_id, &hold);
+    }
+    taken
+}
+
+fn release(env: &Env, invoice: &Invoice, confirmed: bool) {
+    let hold = get_escrow_hold(env, invoice.id)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::EscrowNotHeld));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InvoiceEscrowHold(invoice.id));
+
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+    routing::pay_merchant(
+        env,
+        invoice.id,
+        invoice.merchant_id,
+        &merchant_address,
+        &invoice.token,
+        hold.amount,
+    );
+    events::publish_escrow_released_event(env, invoice.id, hold.amount, confirmed);
+}
+
+fn save_hold(env: &Env, invoice_id: u64, hold: &EscrowHold) {
+    let key = DataKey::InvoiceEscrowHold(invoice_id);
+    env.storage().persistent().set(&key, hold);
+    ttl::extend_persistent(env, &key);
+}
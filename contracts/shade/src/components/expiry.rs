@@ -0,0 +1,220 @@
+use crate::components::{core, invoice, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceExpiryPolicy};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Invoices created without an explicit `expires_at` take the merchant's
+// expiry policy, falling back to the admin default, so stale invoices don't
+// stay payable forever. A merchant can opt out with `Never`.
+//
+// Pending invoices that expire are also indexed by expiry day, each bucket
+// sorted by `(expires_at, id)`, so listing what expires in the next few
+// hours reads a handful of buckets instead of scanning every invoice.
+pub const EXPIRY_BUCKET_SECS: u64 = 24 * 60 * 60;
+pub const MAX_EXPIRY_WINDOW: u64 = 30 * EXPIRY_BUCKET_SECS;
+pub const EXPIRING_INVOICES_PAGE_SIZE: u32 = 20;
+
+pub fn set_default_invoice_expiry(env: &Env, admin: &Address, duration: Option<u64>) {
+    core::assert_admin(env, admin);
+    store_default_invoice_expiry(env, duration);
+}
+
+// Shared with governance proposals; callers handle authorization.
+pub fn store_default_invoice_expiry(env: &Env, duration: Option<u64>) {
+    match duration {
+        Some(0) => panic_with_error!(env, ContractError::InvalidAmount),
+        Some(duration) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::DefaultInvoiceExpiry, &duration),
+        None => env
+            .storage()
+            .persistent()
+            .remove(&DataKey::DefaultInvoiceExpiry),
+    }
+
+    events::publish_default_invoice_expiry_set_event(env, duration, env.ledger().timestamp());
+}
+
+pub fn get_default_invoice_expiry(env: &Env) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DefaultInvoiceExpiry)
+}
+
+pub fn set_merchant_invoice_expiry(
+    env: &Env,
+    merchant_address: &Address,
+    policy: &InvoiceExpiryPolicy,
+) {
+    merchant_address.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NotAuthorized));
+
+    let key = DataKey::MerchantInvoiceExpiry(merchant_id);
+    match policy {
+        InvoiceExpiryPolicy::After(0) => panic_with_error!(env, ContractError::InvalidAmount),
+        InvoiceExpiryPolicy::Default => env.storage().persistent().remove(&key),
+        _ => {
+            env.storage().persistent().set(&key, policy);
+            ttl::extend_persistent(env, &key);
+        }
+    }
+}
+
+pub fn get_merchant_invoice_expiry(env: &Env, merchant_id: u64) -> InvoiceExpiryPolicy {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantInvoiceExpiry(merchant_id))
+        .unwrap_or(InvoiceExpiryPolicy::Default)
+}
+
+// Resolves and stores the expiry for a newly created invoice.
+pub fn apply_invoice_expiry(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    expires_at: Option<u64>,
+) -> Option<u64> {
+    let now = env.ledger().timestamp();
+    let expires_at = match expires_at {
+        Some(expires_at) => {
+            if expires_at <= now {
+                panic_with_error!(env, InvoiceError::InvoiceExpired);
+            }
+            Some(expires_at)
+        }
+        None => match get_merchant_invoice_expiry(env, merchant_id) {
+            InvoiceExpiryPolicy::Never => None,
+            InvoiceExpiryPolicy::After(duration) => Some(now + duration),
+            InvoiceExpiryPolicy::Default => {
+                get_default_invoice_expiry(env).map(|duration| now + duration)
+            }
+        },
+    };
+
+    if let Some(expires_at) = expires_at {
+        let key = DataKey::InvoiceExpiry(invoice_id);
+        env.storage().persistent().set(&key, &expires_at);
+        ttl::extend_persistent(env, &key);
+        add_to_expiry_index(env, invoice_id, expires_at);
+    }
+    expires_at
+}
+
+// Moves a pending invoice's expiry, keeping the expiry index in step.
+pub fn update_invoice_expiry(env: &Env, invoice_id: u64, expires_at: u64) {
+    if expires_at <= env.ledger().timestamp() {
+        panic_with_error!(env, InvoiceError::InvoiceExpired);
+    }
+
+    remove_from_expiry_index(env, invoice_id);
+    let key = DataKey::InvoiceExpiry(invoice_id);
+    env.storage().persistent().set(&key, &expires_at);
+    ttl::extend_persistent(env, &key);
+    add_to_expiry_index(env, invoice_id, expires_at);
+}
+
+pub fn get_invoice_expiry(env: &Env, invoice_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceExpiry(invoice_id))
+}
+
+pub fn assert_invoice_not_expired(env: &Env, invoice_id: u64) {
+    if let Some(expires_at) = get_invoice_expiry(env, invoice_id) {
+        if env.ledger().timestamp() >= expires_at {
+            panic_with_error!(env, InvoiceError::InvoiceExpired);
+        }
+    }
+}
+
+fn expiry_bucket_key(expires_at: u64) -> DataKey {
+    DataKey::InvoicesByExpiry(expires_at / EXPIRY_BUCKET_SECS)
+}
+
+fn add_to_expiry_index(env: &Env, invoice_id: u64, expires_at: u64) {
+    let key = expiry_bucket_key(expires_at);
+    let bucket: Vec<(u64, u64)> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let entry = (expires_at, invoice_id);
+    let mut updated = Vec::new(env);
+    let mut inserted = false;
+    for existing in bucket.iter() {
+        if !inserted && existing > entry {
+            updated.push_back(entry);
+            inserted = true;
+        }
+        updated.push_back(existing);
+    }
+    if !inserted {
+        updated.push_back(entry);
+    }
+
+    env.storage().persistent().set(&key, &updated);
+    ttl::extend_persistent(env, &key);
+}
+
+// Called when an invoice leaves `Pending`; it no longer needs a reminder.
+pub fn remove_from_expiry_index(env: &Env, invoice_id: u64) {
+    let Some(expires_at) = get_invoice_expiry(env, invoice_id) else {
+        return;
+    };
+    let key = expiry_bucket_key(expires_at);
+    let Some(bucket) = env.storage().persistent().get::<_, Vec<(u64, u64)>>(&key) else {
+        return;
+    };
+
+    let mut updated = Vec::new(env);
+    for entry in bucket.iter() {
+        if entry.1 != invoice_id {
+            updated.push_back(entry);
+        }
+    }
+
+    if updated.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &updated);
+    }
+}
+
+// Pending invoices expiring within `window_secs` from now, soonest first.
+// The window is capped at MAX_EXPIRY_WINDOW; pages hold
+// EXPIRING_INVOICES_PAGE_SIZE invoices and start at 0.
+pub fn list_expiring_invoices(env: &Env, window_secs: u64, page: u32) -> Vec<Invoice> {
+    let now = env.ledger().timestamp();
+    let end = now.saturating_add(window_secs.min(MAX_EXPIRY_WINDOW));
+    let mut skip = page as u64 * EXPIRING_INVOICES_PAGE_SIZE as u64;
+
+    let mut invoices = Vec::new(env);
+    for bucket in (now / EXPIRY_BUCKET_SECS)..=(end / EXPIRY_BUCKET_SECS) {
+        let entries: Vec<(u64, u64)> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::InvoicesByExpiry(bucket))
+            .unwrap_or_else(|| Vec::new(env));
+        for (expires_at, invoice_id) in entries.iter() {
+            if expires_at <= now || expires_at > end {
+                continue;
+            }
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            invoices.push_back(invoice::get_invoice(env, invoice_id));
+            if invoices.len() == EXPIRING_INVOICES_PAGE_SIZE {
+                return invoices;
+            }
+        }
+    }
+    invoices
+}
@@ -0,0 +1,214 @@
+use crate::components::{
+    admin, blocklist, core, custody, invoice, merchant, reentrancy, ttl, velocity,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, GiftCard, GiftCardStatus};
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+
+// A gift card escrows prepaid credit for a single merchant. Cards issued
+// with a claim hash have no holder until someone presents the preimage,
+// which lets a buyer hand the secret to a recipient off-chain.
+
+pub fn issue_gift_card(
+    env: &Env,
+    buyer: &Address,
+    merchant_id: u64,
+    token: &Address,
+    amount: i128,
+    expires_at: u64,
+    claim_hash: Option<BytesN<32>>,
+) -> u64 {
+    reentrancy::enter(env);
+    buyer.require_auth();
+    blocklist::assert_not_blocked(env, buyer);
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::GiftCardExpired);
+    }
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    merchant::get_merchant(env, merchant_id);
+
+    velocity::record_payment(env, buyer, token, amount);
+    custody::receive(env, token, buyer, amount);
+
+    let card_count: u64 = ttl::get_persistent(env, &DataKey::GiftCardCount).unwrap_or(0);
+    let card_id = card_count + 1;
+
+    let holder = match claim_hash {
+        Some(_) => None,
+        None => Some(buyer.clone()),
+    };
+    let card = GiftCard {
+        id: card_id,
+        merchant_id,
+        token: token.clone(),
+        buyer: buyer.clone(),
+        holder,
+        claim_hash,
+        balance: amount,
+        expires_at,
+        status: GiftCardStatus::Active,
+    };
+    save_gift_card(env, &card);
+    env.storage()
+        .persistent()
+        .set(&DataKey::GiftCardCount, &card_id);
+    ttl::extend_persistent(env, &DataKey::GiftCardCount);
+
+    events::publish_gift_card_issued_event(
+        env,
+        card_id,
+        merchant_id,
+        buyer.clone(),
+        token.clone(),
+        amount,
+        expires_at,
+    );
+    reentrancy::exit(env);
+    card_id
+}
+
+pub fn claim_gift_card(env: &Env, claimant: &Address, card_id: u64, secret: &Bytes) {
+    claimant.require_auth();
+
+    let mut card = get_gift_card(env, card_id);
+    assert_active(env, &card);
+    if card.holder.is_some() {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    let hash: BytesN<32> = env.crypto().sha256(secret).into();
+    if card.claim_hash != Some(hash) {
+        panic_with_error!(env, ContractError::InvalidClaimSecret);
+    }
+
+    card.holder = Some(claimant.clone());
+    save_gift_card(env, &card);
+
+    events::publish_gift_card_claimed_event(
+        env,
+        card_id,
+        claimant.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+// Pays a pending invoice of the issuing merchant in full from the card
+// balance. The protocol fee comes out of the merchant's proceeds.
+pub fn redeem_gift_card(env: &Env, holder: &Address, card_id: u64, invoice_id: u64) {
+    reentrancy::enter(env);
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let amount = invoice::amount_due(env, &invoice);
+    core::require_payment_auth(env, holder, invoice_id, &invoice.token, amount);
+
+    let mut card = get_gift_card(env, card_id);
+    assert_active(env, &card);
+    if card.holder != Some(holder.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    invoice::assert_payable(env, &invoice);
+    if invoice.merchant_id != card.merchant_id || invoice.token != card.token {
+        panic_with_error!(env, ContractError::GiftCardNotApplicable);
+    }
+    if amount > card.balance {
+        panic_with_error!(env, ContractError::InsufficientGiftCardBalance);
+    }
+
+    card.balance -= amount;
+    if card.balance == 0 {
+        card.status = GiftCardStatus::Depleted;
+    }
+    save_gift_card(env, &card);
+
+    invoice::settle_from_escrow(env, &invoice, holder);
+
+    events::publish_gift_card_redeemed_event(
+        env,
+        card_id,
+        invoice_id,
+        amount,
+        card.balance,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+// Once a card has expired anyone may close it out, returning the unspent
+// balance to the buyer.
+pub fn expire_gift_card(env: &Env, card_id: u64) {
+    reentrancy::enter(env);
+
+    let card = get_gift_card(env, card_id);
+    if card.status != GiftCardStatus::Active {
+        panic_with_error!(env, ContractError::GiftCardNotActive);
+    }
+    if env.ledger().timestamp() < card.expires_at {
+        panic_with_error!(env, ContractError::GiftCardNotExpired);
+    }
+
+    close_expired_gift_card(env, card);
+    reentrancy::exit(env);
+}
+
+// Sweep variant: closes the card only if it exists and has expired, and
+// returns its token when it did.
+pub fn try_expire_gift_card(env: &Env, card_id: u64) -> Option<Address> {
+    let card: GiftCard = env
+        .storage()
+        .persistent()
+        .get(&DataKey::GiftCard(card_id))?;
+    if card.status != GiftCardStatus::Active || env.ledger().timestamp() < card.expires_at {
+        return None;
+    }
+
+    let token = card.token.clone();
+    close_expired_gift_card(env, card);
+    Some(token)
+}
+
+fn close_expired_gift_card(env: &Env, mut card: GiftCard) {
+    let card_id = card.id;
+    let refund = card.balance;
+    card.balance = 0;
+    card.status = GiftCardStatus::Expired;
+    save_gift_card(env, &card);
+
+    if refund > 0 {
+        custody::send(env, &card.token, &card.buyer, refund);
+    }
+
+    events::publish_gift_card_expired_event(env, card_id, refund, env.ledger().timestamp());
+}
+
+pub fn get_gift_card(env: &Env, card_id: u64) -> GiftCard {
+    let key = DataKey::GiftCard(card_id);
+    let card = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::GiftCardNotFound));
+    ttl::extend_persistent(env, &key);
+    card
+}
+
+fn assert_active(env: &Env, card: &GiftCard) {
+    if card.status != GiftCardStatus::Active {
+        panic_with_error!(env, ContractError::GiftCardNotActive);
+    }
+    if env.ledger().timestamp() >= card.expires_at {
+        panic_with_error!(env, ContractError::GiftCardExpired);
+    }
+}
+
+fn save_gift_card(env: &Env, card: &GiftCard) {
+    let key = DataKey::GiftCard(card.id);
+    env.storage().persistent().set(&key, card);
+    ttl::extend_persistent(env, &key);
+}
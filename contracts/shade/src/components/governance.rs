@@ -0,0 +1,179 @@
+use crate::components::{admin, core, expiry, ttl};
+use crate::errors::{ContractError, GovernanceError};
+use crate::events;
+use crate::types::{Council, DataKey, ParameterChange, Proposal, ProposalStatus};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Council members propose and approve parameter changes instead of the admin
+// setting them directly. Once a proposal reaches the approval threshold it is
+// queued behind the same timelock as upgrades and can then be executed by
+// anyone. Approvals are recounted against the current council on execution,
+// so removing a member also withdraws their votes.
+
+pub const GOVERNANCE_TIMELOCK: u64 = 2 * 24 * 60 * 60;
+
+pub fn set_council(env: &Env, admin: &Address, members: &Vec<Address>, threshold: u32) {
+    core::assert_admin(env, admin);
+
+    if threshold == 0 || threshold > members.len() {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let council = Council {
+        members: members.clone(),
+        threshold,
+    };
+    env.storage().persistent().set(&DataKey::Council, &council);
+    ttl::extend_persistent(env, &DataKey::Council);
+
+    events::publish_council_set_event(env, members.len(), threshold, env.ledger().timestamp());
+}
+
+pub fn get_council(env: &Env) -> Option<Council> {
+    env.storage().persistent().get(&DataKey::Council)
+}
+
+pub fn propose_change(env: &Env, proposer: &Address, change: &ParameterChange) -> u64 {
+    proposer.require_auth();
+    let council = assert_council_member(env, proposer);
+
+    let proposal_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProposalCount)
+        .unwrap_or(0);
+    let proposal_id = proposal_count + 1;
+
+    let now = env.ledger().timestamp();
+    let mut proposal = Proposal {
+        id: proposal_id,
+        proposer: proposer.clone(),
+        change: change.clone(),
+        approvals: Vec::from_array(env, [proposer.clone()]),
+        created_at: now,
+        executable_at: None,
+        status: ProposalStatus::Pending,
+    };
+    queue_if_approved(env, &council, &mut proposal);
+    save_proposal(env, &proposal);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ProposalCount, &proposal_id);
+
+    events::publish_proposal_created_event(env, proposal_id, proposer.clone(), change.clone());
+    proposal_id
+}
+
+pub fn vote_on_proposal(env: &Env, member: &Address, proposal_id: u64) {
+    member.require_auth();
+    let council = assert_council_member(env, member);
+
+    let mut proposal = get_proposal(env, proposal_id);
+    if proposal.status != ProposalStatus::Pending {
+        panic_with_error!(env, GovernanceError::ProposalNotExecutable);
+    }
+    if proposal.approvals.contains(member) {
+        panic_with_error!(env, GovernanceError::AlreadyVoted);
+    }
+
+    proposal.approvals.push_back(member.clone());
+    queue_if_approved(env, &council, &mut proposal);
+    save_proposal(env, &proposal);
+
+    events::publish_proposal_voted_event(
+        env,
+        proposal_id,
+        member.clone(),
+        proposal.approvals.len(),
+        proposal.executable_at,
+    );
+}
+
+pub fn execute_proposal(env: &Env, proposal_id: u64) {
+    let mut proposal = get_proposal(env, proposal_id);
+    if proposal.status != ProposalStatus::Pending {
+        panic_with_error!(env, GovernanceError::ProposalNotExecutable);
+    }
+
+    let now = env.ledger().timestamp();
+    match proposal.executable_at {
+        Some(executable_at) if now >= executable_at => {}
+        _ => panic_with_error!(env, GovernanceError::ProposalNotExecutable),
+    }
+
+    let council = get_council(env)
+        .unwrap_or_else(|| panic_with_error!(env, GovernanceError::NotCouncilMember));
+    if current_approvals(&council, &proposal) < council.threshold {
+        panic_with_error!(env, GovernanceError::ProposalNotExecutable);
+    }
+
+    match &proposal.change {
+        ParameterChange::Fee(token, fee) => admin::store_fee(env, token, *fee),
+        ParameterChange::DefaultInvoiceExpiry(duration) => {
+            expiry::store_default_invoice_expiry(env, *duration)
+        }
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    save_proposal(env, &proposal);
+
+    events::publish_proposal_status_event(env, proposal_id, ProposalStatus::Executed, now);
+}
+
+pub fn cancel_proposal(env: &Env, admin: &Address, proposal_id: u64) {
+    core::assert_admin(env, admin);
+
+    let mut proposal = get_proposal(env, proposal_id);
+    if proposal.status != ProposalStatus::Pending {
+        panic_with_error!(env, GovernanceError::ProposalNotExecutable);
+    }
+
+    proposal.status = ProposalStatus::Cancelled;
+    save_proposal(env, &proposal);
+
+    events::publish_proposal_status_event(
+        env,
+        proposal_id,
+        ProposalStatus::Cancelled,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Proposal {
+    let key = DataKey::Proposal(proposal_id);
+    let proposal = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, GovernanceError::ProposalNotFound));
+    ttl::extend_persistent(env, &key);
+    proposal
+}
+
+fn assert_council_member(env: &Env, member: &Address) -> Council {
+    match get_council(env) {
+        Some(council) if council.members.contains(member) => council,
+        _ => panic_with_error!(env, GovernanceError::NotCouncilMember),
+    }
+}
+
+fn current_approvals(council: &Council, proposal: &Proposal) -> u32 {
+    proposal
+        .approvals
+        .iter()
+        .filter(|member| council.members.contains(member))
+        .count() as u32
+}
+
+fn queue_if_approved(env: &Env, council: &Council, proposal: &mut Proposal) {
+    if proposal.executable_at.is_none() && current_approvals(council, proposal) >= council.threshold
+    {
+        proposal.executable_at = Some(env.ledger().timestamp() + GOVERNANCE_TIMELOCK);
+    }
+}
+
+fn save_proposal(env: &Env, proposal: &Proposal) {
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    ttl::extend_persistent(env, &key);
+}
@@ -1,17 +1,28 @@
-use crate::components::merchant;
-use crate::errors::ContractError;
+use crate::components::{
+    activity, admin, affiliate, approval, blocklist, core, custody, escrow, expiry, late_fee,
+    merchant, pagination, pausable, payment_hook, rate_limit, reentrancy, routing, stats, tax, ttl,
+    velocity,
+};
+use crate::errors::{ContractError, InvoiceError};
 use crate::events;
-use crate::types::{DataKey, Invoice, InvoiceFilter, InvoiceStatus};
-use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+use crate::types::{
+    ActivityKind, DataKey, Invoice, InvoiceBalance, InvoiceFilter, InvoiceStatus, LineItem,
+    PaymentBlocker, PaymentPreview,
+};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Map, String, Symbol, Vec};
 
+// `caller` is either the merchant itself or a backend address the merchant
+// has delegated invoice creation to.
 pub fn create_invoice(
     env: &Env,
+    caller: &Address,
     merchant_address: &Address,
     description: &String,
     amount: i128,
     token: &Address,
+    expires_at: Option<u64>,
 ) -> u64 {
-    merchant_address.require_auth();
+    caller.require_auth();
 
     if amount <= 0 {
         panic_with_error!(env, ContractError::InvalidAmount);
@@ -26,12 +37,43 @@ pub fn create_invoice(
         .persistent()
         .get(&DataKey::MerchantId(merchant_address.clone()))
         .unwrap();
+    if caller != merchant_address && !merchant::is_invoice_delegate(env, merchant_id, caller) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
 
-    let invoice_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::InvoiceCount)
-        .unwrap_or(0);
+    issue_invoice(
+        env,
+        caller,
+        merchant_id,
+        description,
+        amount,
+        token,
+        expires_at,
+    )
+}
+
+// Creates the invoice once the caller has been authorized, either directly
+// by `create_invoice` or ahead of time (e.g. a recurring schedule the
+// merchant set up). `creator` is who approvals are attributed to.
+pub fn issue_invoice(
+    env: &Env,
+    creator: &Address,
+    merchant_id: u64,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    expires_at: Option<u64>,
+) -> u64 {
+    merchant::assert_merchant_approved(env, merchant_id);
+    admin::assert_amount_within_bounds(
+        env,
+        token,
+        amount,
+        merchant::is_merchant_verified(env, merchant_id),
+    );
+    rate_limit::record_invoice_created(env, merchant_id);
+
+    let invoice_count: u64 = ttl::get_persistent(env, &DataKey::InvoiceCount).unwrap_or(0);
 
     let new_invoice_id = invoice_count + 1;
 
@@ -53,11 +95,24 @@ pub fn create_invoice(
     env.storage()
         .persistent()
         .set(&DataKey::InvoiceCount, &new_invoice_id);
+    ttl::extend_persistent(env, &DataKey::InvoiceCount);
+    pagination::index_push(
+        env,
+        &DataKey::MerchantInvoiceCount(merchant_id),
+        |bucket| DataKey::MerchantInvoices(merchant_id, bucket),
+        new_invoice_id,
+    );
+    add_to_status_index(env, new_invoice_id, InvoiceStatus::Pending);
+    stats::record_invoice_status(env, None, InvoiceStatus::Pending);
+    ttl::extend_persistent(env, &DataKey::Invoice(new_invoice_id));
+    save_invoice_balance(env, &invoice);
+    expiry::apply_invoice_expiry(env, new_invoice_id, merchant_id, expires_at);
+    approval::hold_for_approval(env, new_invoice_id, merchant_id, token, amount, creator);
 
     events::publish_invoice_created_event(
         env,
         new_invoice_id,
-        merchant_address.clone(),
+        merchant::get_merchant(env, merchant_id).address,
         amount,
         token.clone(),
     );
@@ -65,67 +120,776 @@ pub fn create_invoice(
     new_invoice_id
 }
 
-pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+// Private invoices keep only `sha256(description)` on-chain; the merchant
+// shares the plaintext with the payer off-chain, who can check it against
+// the stored hash with `verify_invoice_description`.
+pub fn create_private_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    description_hash: &BytesN<32>,
+    amount: i128,
+    token: &Address,
+) -> u64 {
+    let invoice_id = create_invoice(
+        env,
+        merchant_address,
+        merchant_address,
+        &String::from_str(env, ""),
+        amount,
+        token,
+        None,
+    );
+
+    let key = DataKey::InvoiceDescriptionHash(invoice_id);
+    env.storage().persistent().set(&key, description_hash);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_invoice_description_hash_event(env, invoice_id, description_hash.clone());
+    invoice_id
+}
+
+// Itemized invoices keep their line items next to the invoice record. The
+// invoice amount must equal the sum of quantity * unit_price over the items.
+pub const MAX_LINE_ITEMS: u32 = 50;
+
+pub fn create_itemized_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    line_items: &Vec<LineItem>,
+    amount: i128,
+    token: &Address,
+) -> u64 {
+    if line_items.is_empty() || line_items.len() > MAX_LINE_ITEMS {
+        panic_with_error!(env, InvoiceError::InvalidLineItems);
+    }
+    let mut total: i128 = 0;
+    for item in line_items.iter() {
+        if item.quantity == 0 || item.unit_price <= 0 {
+            panic_with_error!(env, InvoiceError::InvalidLineItems);
+        }
+        total = item
+            .unit_price
+            .checked_mul(item.quantity as i128)
+            .and_then(|line_total| total.checked_add(line_total))
+            .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvalidLineItems));
+    }
+    if total != amount {
+        panic_with_error!(env, InvoiceError::InvalidLineItems);
+    }
+
+    let invoice_id = create_invoice(
+        env,
+        merchant_address,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+    );
+
+    let key = DataKey::InvoiceLineItems(invoice_id);
+    env.storage().persistent().set(&key, line_items);
+    ttl::extend_persistent(env, &key);
+
+    invoice_id
+}
+
+pub fn get_invoice_line_items(env: &Env, invoice_id: u64) -> Vec<LineItem> {
     env.storage()
         .persistent()
-        .get(&DataKey::Invoice(invoice_id))
-        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound))
+        .get(&DataKey::InvoiceLineItems(invoice_id))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
-pub fn get_invoices(env: &Env, filter: InvoiceFilter) -> Vec<Invoice> {
-    let invoice_count: u64 = env
+// Addressed invoices can only be settled for one of the listed customers,
+// e.g. a B2B counterparty. Every payment path checks the allowlist.
+pub const MAX_ALLOWED_PAYERS: u32 = 10;
+
+pub fn create_addressed_invoice(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    payer_allowlist: &Vec<Address>,
+) -> u64 {
+    if payer_allowlist.is_empty() || payer_allowlist.len() > MAX_ALLOWED_PAYERS {
+        panic_with_error!(env, InvoiceError::InvalidPayerAllowlist);
+    }
+
+    let invoice_id = create_invoice(
+        env,
+        merchant_address,
+        merchant_address,
+        description,
+        amount,
+        token,
+        None,
+    );
+
+    let key = DataKey::InvoicePayerAllowlist(invoice_id);
+    env.storage().persistent().set(&key, payer_allowlist);
+    ttl::extend_persistent(env, &key);
+
+    invoice_id
+}
+
+pub fn get_invoice_payer_allowlist(env: &Env, invoice_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoicePayerAllowlist(invoice_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn assert_allowed_payer(env: &Env, invoice_id: u64, payer: &Address) {
+    let allowlist = get_invoice_payer_allowlist(env, invoice_id);
+    if !allowlist.is_empty() && !allowlist.contains(payer) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
+// Free-form references (order ids, customer references, SKUs) a merchant or
+// its invoice delegates attach to an invoice. Setting an empty map clears it.
+pub const MAX_METADATA_ENTRIES: u32 = 20;
+pub const MAX_METADATA_VALUE_LEN: u32 = 256;
+
+pub fn set_invoice_metadata(
+    env: &Env,
+    caller: &Address,
+    invoice_id: u64,
+    metadata: &Map<Symbol, String>,
+) {
+    caller.require_auth();
+
+    let merchant_id = get_invoice(env, invoice_id).merchant_id;
+    merchant::assert_invoice_operator(env, merchant_id, caller);
+
+    if metadata.len() > MAX_METADATA_ENTRIES {
+        panic_with_error!(env, InvoiceError::InvalidInvoiceMetadata);
+    }
+    for value in metadata.values().iter() {
+        if value.len() > MAX_METADATA_VALUE_LEN {
+            panic_with_error!(env, InvoiceError::InvalidInvoiceMetadata);
+        }
+    }
+
+    let key = DataKey::InvoiceMetadata(invoice_id);
+    if metadata.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, metadata);
+        ttl::extend_persistent(env, &key);
+    }
+
+    events::publish_invoice_metadata_set_event(
+        env,
+        invoice_id,
+        metadata.len(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_invoice_metadata(env: &Env, invoice_id: u64) -> Map<Symbol, String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceMetadata(invoice_id))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn get_invoice_description_hash(env: &Env, invoice_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceDescriptionHash(invoice_id))
+}
+
+pub fn verify_invoice_description(env: &Env, invoice_id: u64, description: &String) -> bool {
+    let invoice = get_invoice(env, invoice_id);
+    match get_invoice_description_hash(env, invoice_id) {
+        Some(description_hash) => {
+            let hash: BytesN<32> = env.crypto().sha256(&description.to_bytes()).into();
+            hash == description_hash
+        }
+        None => invoice.description == *description,
+    }
+}
+
+pub fn get_invoice(env: &Env, invoice_id: u64) -> Invoice {
+    let key = DataKey::Invoice(invoice_id);
+    let invoice = env
         .storage()
         .persistent()
-        .get(&DataKey::InvoiceCount)
-        .unwrap_or(0);
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound));
+    ttl::extend_persistent(env, &key);
+    invoice
+}
 
-    let mut invoices: Vec<Invoice> = Vec::new(env);
+// Each merchant's invoices are numbered 1, 2, 3, ... in creation order for
+// accounting systems that need gapless, merchant-local numbering. The
+// merchant index is append-only, so an invoice's position in it already is
+// that number and nothing extra is stored.
+pub fn get_invoice_by_merchant_seq(env: &Env, merchant_id: u64, seq: u64) -> Invoice {
+    let invoice_id = seq
+        .checked_sub(1)
+        .and_then(|position| {
+            pagination::index_get(
+                env,
+                |bucket| DataKey::MerchantInvoices(merchant_id, bucket),
+                position,
+            )
+        })
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::InvoiceNotFound));
+    get_invoice(env, invoice_id)
+}
+
+pub fn get_invoice_merchant_seq(env: &Env, invoice_id: u64) -> u64 {
+    let merchant_id = get_invoice(env, invoice_id).merchant_id;
+    let position = pagination::index_lower_bound(
+        env,
+        &DataKey::MerchantInvoiceCount(merchant_id),
+        |bucket| DataKey::MerchantInvoices(merchant_id, bucket),
+        invoice_id - 1,
+    );
+    position + 1
+}
+
+// Reads a merchant's invoices straight from its index, oldest first, so the
+// cost depends only on `limit` and not on how many invoices exist overall.
+pub fn get_merchant_invoices(env: &Env, merchant_id: u64, offset: u64, limit: u32) -> Vec<Invoice> {
+    let limit = pagination::clamp_limit(limit);
+    let len = pagination::index_len(env, &DataKey::MerchantInvoiceCount(merchant_id));
+
+    let mut invoices = Vec::new(env);
+    let mut position = offset;
+    while position < len && invoices.len() < limit {
+        if let Some(invoice_id) = pagination::index_get(
+            env,
+            |bucket| DataKey::MerchantInvoices(merchant_id, bucket),
+            position,
+        ) {
+            invoices.push_back(get_invoice(env, invoice_id));
+        }
+        position += 1;
+    }
+    invoices
+}
+
+// A payer's invoices in the order they were paid. The payer index is
+// appended on payment, so unlike the merchant index it is not in id order.
+pub fn get_payer_invoices(env: &Env, payer: &Address, offset: u64, limit: u32) -> Vec<Invoice> {
+    let limit = pagination::clamp_limit(limit);
+    let len = pagination::index_len(env, &DataKey::PayerInvoiceCount(payer.clone()));
+
+    let mut invoices = Vec::new(env);
+    let mut position = offset;
+    while position < len && invoices.len() < limit {
+        if let Some(invoice_id) = pagination::index_get(
+            env,
+            |bucket| DataKey::PayerInvoices(payer.clone(), bucket),
+            position,
+        ) {
+            invoices.push_back(get_invoice(env, invoice_id));
+        }
+        position += 1;
+    }
+    invoices
+}
+
+pub fn get_invoices_by_ids(env: &Env, invoice_ids: Vec<u64>) -> Vec<Invoice> {
+    if invoice_ids.len() > pagination::MAX_PAGE_LIMIT {
+        panic_with_error!(env, ContractError::BatchTooLarge);
+    }
 
-    for i in 1..=invoice_count {
+    // Unknown ids are skipped so one stale id doesn't fail the whole batch.
+    let mut invoices: Vec<Invoice> = Vec::new(env);
+    for invoice_id in invoice_ids.iter() {
         if let Some(invoice) = env
             .storage()
             .persistent()
-            .get::<_, Invoice>(&DataKey::Invoice(i))
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
         {
-            let mut matches = true;
+            invoices.push_back(invoice);
+        }
+    }
 
-            if let Some(status) = filter.status {
-                if invoice.status as u32 != status {
-                    matches = false;
-                }
-            }
+    invoices
+}
 
-            if let Some(merchant) = &filter.merchant {
-                if let Some(merchant_id) = env
-                    .storage()
-                    .persistent()
-                    .get::<_, u64>(&DataKey::MerchantId(merchant.clone()))
-                {
-                    if invoice.merchant_id != merchant_id {
-                        matches = false;
-                    }
-                } else {
-                    matches = false;
-                }
-            }
+pub fn set_invoice_status(env: &Env, invoice_id: u64, status: InvoiceStatus) -> Invoice {
+    let mut invoice = get_invoice(env, invoice_id);
+    if invoice.status == status {
+        return invoice;
+    }
+
+    remove_from_status_index(env, invoice_id, invoice.status);
+    add_to_status_index(env, invoice_id, status);
+    if is_payable_status(invoice.status) && !is_payable_status(status) {
+        expiry::remove_from_expiry_index(env, invoice_id);
+    }
+    stats::record_invoice_status(env, Some(invoice.status), status);
+
+    invoice.status = status;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+    save_invoice_balance(env, &invoice);
+    invoice
+}
 
-            if let Some(min_amount) = filter.min_amount {
-                if invoice.amount < min_amount as i128 {
-                    matches = false;
-                }
+// Overdue invoices can still be paid; only the status differs.
+pub fn is_payable_status(status: InvoiceStatus) -> bool {
+    matches!(status, InvoiceStatus::Pending | InvoiceStatus::Overdue)
+}
+
+pub fn assert_payable(env: &Env, invoice: &Invoice) {
+    if !is_payable_status(invoice.status) {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+}
+
+pub fn get_invoice_status(env: &Env, invoice_id: u64) -> InvoiceStatus {
+    get_invoice_balance(env, invoice_id).status
+}
+
+pub fn get_invoice_balance(env: &Env, invoice_id: u64) -> InvoiceBalance {
+    let key = DataKey::InvoiceBalance(invoice_id);
+    match env.storage().persistent().get(&key) {
+        Some(balance) => {
+            ttl::extend_persistent(env, &key);
+            balance
+        }
+        // Invoices created before balances were tracked only have the full record.
+        None => invoice_balance(&get_invoice(env, invoice_id)),
+    }
+}
+
+fn invoice_balance(invoice: &Invoice) -> InvoiceBalance {
+    let paid = match invoice.status {
+        InvoiceStatus::Paid | InvoiceStatus::Refunded => invoice.amount,
+        InvoiceStatus::Pending | InvoiceStatus::Overdue | InvoiceStatus::Cancelled => 0,
+    };
+    let refunded = match invoice.status {
+        InvoiceStatus::Refunded => invoice.amount,
+        _ => 0,
+    };
+
+    InvoiceBalance {
+        status: invoice.status,
+        amount: invoice.amount,
+        paid,
+        refunded,
+    }
+}
+
+// Persists an edited invoice record along with its balance view.
+pub fn save_invoice(env: &Env, invoice: &Invoice) {
+    let key = DataKey::Invoice(invoice.id);
+    env.storage().persistent().set(&key, invoice);
+    ttl::extend_persistent(env, &key);
+    save_invoice_balance(env, invoice);
+}
+
+fn save_invoice_balance(env: &Env, invoice: &Invoice) {
+    let key = DataKey::InvoiceBalance(invoice.id);
+    env.storage()
+        .persistent()
+        .set(&key, &invoice_balance(invoice));
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn mark_invoice_paid(env: &Env, invoice_id: u64, payer: &Address) -> Invoice {
+    set_invoice_status(env, invoice_id, InvoiceStatus::Paid);
+
+    let mut invoice = get_invoice(env, invoice_id);
+    invoice.payer = Some(payer.clone());
+    invoice.date_paid = Some(env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .set(&DataKey::Invoice(invoice_id), &invoice);
+    pagination::index_push(
+        env,
+        &DataKey::PayerInvoiceCount(payer.clone()),
+        |bucket| DataKey::PayerInvoices(payer.clone(), bucket),
+        invoice_id,
+    );
+    invoice
+}
+
+// Protocol fee charged when the invoice settled.
+pub fn get_invoice_fee(env: &Env, invoice_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceFee(invoice_id))
+        .unwrap_or(0)
+}
+
+// What a payer has to put up right now: the principal plus any accrued
+// late fee.
+pub fn amount_due(env: &Env, invoice: &Invoice) -> i128 {
+    invoice.amount + late_fee::accrued_late_fee(env, invoice)
+}
+
+// Settles a pending invoice with funds the contract already escrows (gift
+// card balances, payment links). The escrow must cover `amount_due`; the
+// merchant receives it net of any tax line and the protocol fee.
+pub fn settle_from_escrow(env: &Env, invoice: &Invoice, payer: &Address) -> Invoice {
+    settle(env, invoice, payer, None)
+}
+
+fn settle(env: &Env, invoice: &Invoice, payer: &Address, affiliate: Option<&Address>) -> Invoice {
+    assert_allowed_payer(env, invoice.id, payer);
+    expiry::assert_invoice_not_expired(env, invoice.id);
+    approval::assert_invoice_approved(env, invoice.id);
+
+    let late_fee = late_fee::accrued_late_fee(env, invoice);
+    let gross = invoice.amount + late_fee;
+    let tax = tax::tax_amount(env, invoice);
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+    let fee = admin::calculate_fee(
+        env,
+        &invoice.token,
+        gross - tax,
+        &[payer, &merchant_address],
+    );
+    admin::collect_fee(env, &invoice.token, fee);
+    if fee > 0 {
+        let fee_key = DataKey::InvoiceFee(invoice.id);
+        env.storage().persistent().set(&fee_key, &fee);
+        ttl::extend_persistent(env, &fee_key);
+    }
+    stats::record_volume(env, &invoice.token, gross);
+    activity::record_activity(
+        env,
+        ActivityKind::InvoicePayment,
+        invoice.id,
+        &invoice.token,
+        gross,
+    );
+    late_fee::record_late_fee_collected(env, invoice.id, late_fee);
+    tax::pay_tax(env, invoice, tax);
+    let net = gross - tax - fee;
+    let commission = match affiliate {
+        Some(affiliate) => affiliate::accrue_commission(env, invoice, affiliate, net),
+        None => 0,
+    };
+    escrow::pay_or_hold(env, invoice, &merchant_address, net - commission);
+
+    let paid = mark_invoice_paid(env, invoice.id, payer);
+    payment_hook::notify_payment(env, &paid, payer, gross);
+    paid
+}
+
+// Dry run of `settle_from_escrow` for checkout UIs. The payer isn't known
+// yet, so only the merchant's fee exemption is taken into account.
+pub fn preview_payment(env: &Env, invoice_id: u64, amount: i128) -> PaymentPreview {
+    let invoice = get_invoice(env, invoice_id);
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+
+    let mut blockers = Vec::new(env);
+    if pausable::is_paused(env) {
+        blockers.push_back(PaymentBlocker::ContractPaused);
+    }
+    if !is_payable_status(invoice.status) {
+        blockers.push_back(PaymentBlocker::InvoiceNotPending);
+    }
+    if expiry::get_invoice_expiry(env, invoice_id)
+        .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at)
+    {
+        blockers.push_back(PaymentBlocker::InvoiceExpired);
+    }
+    if approval::is_invoice_awaiting_approval(env, invoice_id) {
+        blockers.push_back(PaymentBlocker::AwaitingApproval);
+    }
+    if !admin::is_accepted_token(env, &invoice.token) {
+        blockers.push_back(PaymentBlocker::TokenNotAccepted);
+    }
+    let late_fee = late_fee::accrued_late_fee(env, &invoice);
+    let gross = invoice.amount + late_fee;
+    if amount != gross {
+        blockers.push_back(PaymentBlocker::AmountMismatch);
+    }
+
+    let tax = tax::tax_amount(env, &invoice);
+    let fee = admin::quote_fee(env, &invoice.token, gross - tax, &[&merchant_address]);
+    let merchant_net = gross - tax - fee;
+    let splits = routing::preview_payout(
+        env,
+        invoice.merchant_id,
+        &merchant_address,
+        &invoice.token,
+        merchant_net,
+    );
+
+    PaymentPreview {
+        invoice_id,
+        token: invoice.token,
+        amount: gross,
+        late_fee,
+        tax,
+        fee,
+        merchant_net,
+        splits,
+        blockers,
+    }
+}
+
+pub fn pay_invoice(env: &Env, payer: &Address, invoice_id: u64) -> Invoice {
+    reentrancy::enter(env);
+    let (invoice, _) = pay(env, payer, payer, invoice_id, None);
+    reentrancy::exit(env);
+    invoice
+}
+
+// Same as `pay_invoice`, crediting `affiliate` with the merchant's
+// commission for the referral.
+pub fn pay_invoice_with_affiliate(
+    env: &Env,
+    payer: &Address,
+    invoice_id: u64,
+    affiliate: &Address,
+) -> Invoice {
+    reentrancy::enter(env);
+    let (invoice, _) = pay(env, payer, payer, invoice_id, Some(affiliate));
+    reentrancy::exit(env);
+    invoice
+}
+
+// Someone other than the payer of record (an employer, a parent, a
+// corporate card) funds the invoice. The beneficiary is recorded as the
+// payer, so refunds and receipts go to them rather than to the funder.
+pub fn pay_invoice_on_behalf(
+    env: &Env,
+    funder: &Address,
+    beneficiary: &Address,
+    invoice_id: u64,
+) -> Invoice {
+    reentrancy::enter(env);
+    let (invoice, amount) = pay(env, funder, beneficiary, invoice_id, None);
+
+    events::publish_invoice_paid_on_behalf_event(
+        env,
+        invoice_id,
+        funder.clone(),
+        beneficiary.clone(),
+        amount,
+    );
+    reentrancy::exit(env);
+    invoice
+}
+
+// Charges `funder` the amount due, late fees included, and settles the
+// invoice for `beneficiary`. Returns the paid invoice and the amount charged.
+fn pay(
+    env: &Env,
+    funder: &Address,
+    beneficiary: &Address,
+    invoice_id: u64,
+    affiliate: Option<&Address>,
+) -> (Invoice, i128) {
+    let invoice = get_invoice(env, invoice_id);
+    let amount = amount_due(env, &invoice);
+    core::require_payment_auth(env, funder, invoice_id, &invoice.token, amount);
+    blocklist::assert_not_blocked(env, funder);
+    blocklist::assert_not_blocked(env, beneficiary);
+    assert_payable(env, &invoice);
+
+    velocity::record_payment(env, funder, &invoice.token, amount);
+    custody::receive(env, &invoice.token, funder, amount);
+    (settle(env, &invoice, beneficiary, affiliate), amount)
+}
+
+const INVOICE_STATUSES: [InvoiceStatus; 5] = [
+    InvoiceStatus::Pending,
+    InvoiceStatus::Paid,
+    InvoiceStatus::Cancelled,
+    InvoiceStatus::Refunded,
+    InvoiceStatus::Overdue,
+];
+
+// The status index groups ids by status and by id range, keeping every
+// bucket sorted and bounded so transitions and id-cursor reads stay cheap.
+fn status_bucket_key(status: InvoiceStatus, invoice_id: u64) -> DataKey {
+    DataKey::InvoicesByStatus(status as u32, invoice_id / pagination::INDEX_BUCKET_SIZE)
+}
+
+fn add_to_status_index(env: &Env, invoice_id: u64, status: InvoiceStatus) {
+    let key = status_bucket_key(status, invoice_id);
+    let bucket: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let mut updated = Vec::new(env);
+    let mut inserted = false;
+    for id in bucket.iter() {
+        if !inserted && id > invoice_id {
+            updated.push_back(invoice_id);
+            inserted = true;
+        }
+        if id != invoice_id {
+            updated.push_back(id);
+        }
+    }
+    if !inserted {
+        updated.push_back(invoice_id);
+    }
+
+    env.storage().persistent().set(&key, &updated);
+    ttl::extend_persistent(env, &key);
+}
+
+fn remove_from_status_index(env: &Env, invoice_id: u64, status: InvoiceStatus) {
+    let key = status_bucket_key(status, invoice_id);
+    let Some(bucket) = env.storage().persistent().get::<_, Vec<u64>>(&key) else {
+        return;
+    };
+
+    let mut updated = Vec::new(env);
+    for id in bucket.iter() {
+        if id != invoice_id {
+            updated.push_back(id);
+        }
+    }
+
+    if updated.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &updated);
+        ttl::extend_persistent(env, &key);
+    }
+}
+
+// Examines at most `limit` candidate invoices from the narrowest index the
+// filter allows, so the cost of a call never depends on how selective the
+// filter is. A page can come back short or empty; clients keep following the
+// returned cursor until it is `None`.
+pub fn get_invoices(
+    env: &Env,
+    filter: InvoiceFilter,
+    cursor: u64,
+    limit: u32,
+) -> (Vec<Invoice>, Option<u64>) {
+    let budget = pagination::clamp_limit(limit);
+    let (candidates, next_cursor) = page_candidates(env, &filter, cursor, budget);
+
+    let mut invoices = Vec::new(env);
+    for invoice_id in candidates.iter() {
+        if let Some(invoice) = env
+            .storage()
+            .persistent()
+            .get::<_, Invoice>(&DataKey::Invoice(invoice_id))
+        {
+            if matches_filter(&invoice, &filter) {
+                invoices.push_back(invoice);
             }
+        }
+    }
+
+    (invoices, next_cursor)
+}
 
-            if let Some(max_amount) = filter.max_amount {
-                if invoice.amount > max_amount as i128 {
-                    matches = false;
-                }
+// Up to `budget` invoice ids after `cursor`, taken from the narrowest index
+// the filter allows, and the cursor to resume from if the index goes on.
+fn page_candidates(
+    env: &Env,
+    filter: &InvoiceFilter,
+    cursor: u64,
+    budget: u32,
+) -> (Vec<u64>, Option<u64>) {
+    let mut ids = Vec::new(env);
+
+    if let Some(merchant) = &filter.merchant {
+        let Some(merchant_id) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::MerchantId(merchant.clone()))
+        else {
+            return (ids, None);
+        };
+
+        let len_key = DataKey::MerchantInvoiceCount(merchant_id);
+        let bucket_key = |bucket| DataKey::MerchantInvoices(merchant_id, bucket);
+        let len = pagination::index_len(env, &len_key);
+        let mut position = pagination::index_lower_bound(env, &len_key, bucket_key, cursor);
+
+        while position < len && ids.len() < budget {
+            if let Some(invoice_id) = pagination::index_get(env, bucket_key, position) {
+                ids.push_back(invoice_id);
             }
+            position += 1;
+        }
 
-            if matches {
-                invoices.push_back(invoice);
+        let next_cursor = if position < len { ids.last() } else { None };
+        return (ids, next_cursor);
+    }
+
+    // Without a merchant, walk the status buckets: just the filtered status,
+    // or every status merged in id order. Empty buckets count against the
+    // budget too, so a page never reads more than `budget` buckets.
+    let statuses = match filter.status {
+        Some(status) => Vec::from_array(env, [status]),
+        None => Vec::from_array(env, INVOICE_STATUSES.map(|status| status as u32)),
+    };
+    let invoice_count: u64 = ttl::get_persistent(env, &DataKey::InvoiceCount).unwrap_or(0);
+    let mut bucket = (cursor + 1) / pagination::INDEX_BUCKET_SIZE;
+    let last_bucket = invoice_count / pagination::INDEX_BUCKET_SIZE;
+    let mut buckets_read = 0;
+
+    while bucket <= last_bucket && ids.len() < budget && buckets_read < budget {
+        for invoice_id in status_bucket_ids(env, &statuses, bucket).iter() {
+            if invoice_id > cursor && ids.len() < budget {
+                ids.push_back(invoice_id);
             }
         }
+        bucket += 1;
+        buckets_read += 1;
     }
 
-    invoices
+    let next_cursor = if ids.len() >= budget {
+        ids.last()
+    } else if bucket <= last_bucket {
+        Some(bucket * pagination::INDEX_BUCKET_SIZE - 1)
+    } else {
+        None
+    };
+    (ids, next_cursor)
+}
+
+// Ids in one bucket across the given statuses, in ascending order. Each
+// invoice sits in exactly one status, so the merge has no duplicates.
+fn status_bucket_ids(env: &Env, statuses: &Vec<u32>, bucket: u64) -> Vec<u64> {
+    let mut ids: Map<u64, ()> = Map::new(env);
+    for status in statuses.iter() {
+        let bucket_ids: Vec<u64> =
+            ttl::get_persistent(env, &DataKey::InvoicesByStatus(status, bucket))
+                .unwrap_or_else(|| Vec::new(env));
+        for invoice_id in bucket_ids.iter() {
+            ids.set(invoice_id, ());
+        }
+    }
+    ids.keys()
+}
+
+fn matches_filter(invoice: &Invoice, filter: &InvoiceFilter) -> bool {
+    if let Some(status) = filter.status {
+        if invoice.status as u32 != status {
+            return false;
+        }
+    }
+
+    if let Some(min_amount) = filter.min_amount {
+        if invoice.amount < min_amount as i128 {
+            return false;
+        }
+    }
+
+    if let Some(max_amount) = filter.max_amount {
+        if invoice.amount > max_amount as i128 {
+            return false;
+        }
+    }
+
+    true
 }
@@ -0,0 +1,82 @@
+use crate::components::{admin, due_date, invoice, merchant, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus, LateFeePolicy};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// A late fee accrues once an invoice's due date has passed and is charged
+// on top of the principal when it is paid. Per-day fees count every started
+// day. The accrued fee never exceeds the principal.
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// The policy is part of the invoice's terms, so it can only be set or
+// changed while the invoice is pending and its due date is still ahead.
+pub fn set_invoice_late_fee(
+    env: &Env,
+    caller: &Address,
+    invoice_id: u64,
+    policy: Option<LateFeePolicy>,
+) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+    let due_at = due_date::get_invoice_due_date(env, invoice_id)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvalidLateFeePolicy));
+    if env.ledger().timestamp() >= due_at {
+        panic_with_error!(env, InvoiceError::InvalidLateFeePolicy);
+    }
+
+    let key = DataKey::InvoiceLateFee(invoice_id);
+    match policy {
+        Some(policy) => {
+            let valid = match policy {
+                LateFeePolicy::Flat(amount) => amount > 0,
+                LateFeePolicy::BpsPerDay(bps) => bps > 0 && bps as i128 <= admin::FEE_DENOMINATOR,
+            };
+            if !valid {
+                panic_with_error!(env, InvoiceError::InvalidLateFeePolicy);
+            }
+            env.storage().persistent().set(&key, &policy);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_invoice_late_fee(env: &Env, invoice_id: u64) -> Option<LateFeePolicy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceLateFee(invoice_id))
+}
+
+pub fn accrued_late_fee(env: &Env, invoice: &Invoice) -> i128 {
+    let Some(policy) = get_invoice_late_fee(env, invoice.id) else {
+        return 0;
+    };
+    let Some(due_at) = due_date::get_invoice_due_date(env, invoice.id) else {
+        return 0;
+    };
+    let now = env.ledger().timestamp();
+    if now <= due_at {
+        return 0;
+    }
+
+    let late_fee = match policy {
+        LateFeePolicy::Flat(amount) => amount,
+        LateFeePolicy::BpsPerDay(bps) => {
+            let days_late = (now - due_at).div_ceil(SECONDS_PER_DAY) as i128;
+            invoice.amount * bps as i128 * days_late / admin::FEE_DENOMINATOR
+        }
+    };
+    late_fee.min(invoice.amount)
+}
+
+pub fn record_late_fee_collected(env: &Env, invoice_id: u64, late_fee: i128) {
+    if late_fee > 0 {
+        events::publish_late_fee_collected_event(env, invoice_id, late_fee);
+    }
+}
@@ -1,10 +1,10 @@
-use crate::components::core;
-use crate::errors::ContractError;
+use crate::components::{access_control, bond, core, pagination, ttl};
+use crate::errors::{ComplianceError, ContractError};
 use crate::events;
-use crate::types::{DataKey, Merchant, MerchantFilter};
+use crate::types::{DataKey, Merchant, MerchantFilter, Role};
 use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
 
-pub fn register_merchant(env: &Env, merchant: &Address) {
+pub fn register_merchant(env: &Env, merchant: &Address) -> u64 {
     merchant.require_auth();
 
     if env
@@ -15,11 +15,7 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
         panic_with_error!(env, ContractError::MerchantAlreadyRegistered);
     }
 
-    let merchant_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::MerchantCount)
-        .unwrap_or(0);
+    let merchant_count: u64 = ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0);
 
     let new_id = merchant_count + 1;
 
@@ -40,6 +36,15 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
     env.storage()
         .persistent()
         .set(&DataKey::MerchantCount, &new_id);
+    ttl::extend_persistent(env, &DataKey::MerchantCount);
+    ttl::extend_persistent(env, &DataKey::Merchant(new_id));
+    ttl::extend_persistent(env, &DataKey::MerchantId(merchant.clone()));
+
+    if !is_permissionless_registration(env) {
+        let key = DataKey::MerchantPendingApproval(new_id);
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    }
 
     events::publish_merchant_registered_event(
         env,
@@ -47,6 +52,10 @@ pub fn register_merchant(env: &Env, merchant: &Address) {
         new_id,
         env.ledger().timestamp(),
     );
+
+    bond::stake_registration_bond(env, merchant, new_id);
+
+    new_id
 }
 
 pub fn get_merchant(env: &Env, merchant_id: u64) -> Merchant {
@@ -54,20 +63,19 @@ pub fn get_merchant(env: &Env, merchant_id: u64) -> Merchant {
         panic_with_error!(env, ContractError::MerchantNotFound);
     }
 
-    let merchant_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::MerchantCount)
-        .unwrap_or(0);
+    let merchant_count: u64 = ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0);
 
     if merchant_id > merchant_count {
         panic_with_error!(env, ContractError::MerchantNotFound);
     }
 
-    env.storage()
+    let merchant: Merchant = env
+        .storage()
         .persistent()
         .get(&DataKey::Merchant(merchant_id))
-        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+    ttl::extend_persistent(env, &DataKey::Merchant(merchant_id));
+    merchant
 }
 
 pub fn is_merchant(env: &Env, merchant: &Address) -> bool {
@@ -83,11 +91,7 @@ pub fn set_merchant_status(env: &Env, admin: &Address, merchant_id: u64, status:
         panic_with_error!(env, ContractError::MerchantNotFound);
     }
 
-    let merchant_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::MerchantCount)
-        .unwrap_or(0);
+    let merchant_count: u64 = ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0);
 
     if merchant_id > merchant_count {
         panic_with_error!(env, ContractError::MerchantNotFound);
@@ -113,16 +117,43 @@ pub fn set_merchant_status(env: &Env, admin: &Address, merchant_id: u64, status:
     );
 }
 
+// A merchant leaving in good standing deactivates itself and can withdraw
+// its registration bond after the cooling-off period. Merchants the admin
+// has already deactivated cannot offboard, so their bond stays available for
+// slashing.
+pub fn offboard_merchant(env: &Env, merchant: &Address) {
+    merchant.require_auth();
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+    let mut merchant_data = get_merchant(env, merchant_id);
+    if !merchant_data.active {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    merchant_data.active = false;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Merchant(merchant_id), &merchant_data);
+    events::publish_merchant_status_changed_event(
+        env,
+        merchant_id,
+        false,
+        env.ledger().timestamp(),
+    );
+
+    bond::start_cooling_off(env, merchant_id);
+}
+
 pub fn is_merchant_active(env: &Env, merchant_id: u64) -> bool {
     if merchant_id == 0 {
         panic_with_error!(env, ContractError::MerchantNotFound);
     }
 
-    let merchant_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::MerchantCount)
-        .unwrap_or(0);
+    let merchant_count: u64 = ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0);
 
     if merchant_id > merchant_count {
         panic_with_error!(env, ContractError::MerchantNotFound);
@@ -155,6 +186,99 @@ pub fn is_merchant_verified(env: &Env, merchant_id: u64) -> bool {
     merchant_data.verified
 }
 
+// Unless registration is permissionless, new merchants wait for an admin or
+// manager to approve them before they can bill anyone. Merchants registered
+// before the approval workflow existed carry no pending flag and count as
+// approved.
+pub fn approve_merchant(env: &Env, caller: &Address, merchant_id: u64) {
+    access_control::assert_has_role(env, caller, Role::Manager);
+    get_merchant(env, merchant_id);
+
+    let key = DataKey::MerchantPendingApproval(merchant_id);
+    if env.storage().persistent().has(&key) {
+        env.storage().persistent().remove(&key);
+        events::publish_merchant_approved_event(
+            env,
+            merchant_id,
+            caller.clone(),
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+pub fn is_merchant_approved(env: &Env, merchant_id: u64) -> bool {
+    get_merchant(env, merchant_id);
+    !env.storage()
+        .persistent()
+        .has(&DataKey::MerchantPendingApproval(merchant_id))
+}
+
+pub fn assert_merchant_approved(env: &Env, merchant_id: u64) {
+    if !is_merchant_approved(env, merchant_id) {
+        panic_with_error!(env, ComplianceError::MerchantNotApproved);
+    }
+}
+
+pub fn set_permissionless_registration(env: &Env, admin: &Address, enabled: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::PermissionlessRegistration, &enabled);
+
+    events::publish_permissionless_registration_set_event(env, enabled, env.ledger().timestamp());
+}
+
+pub fn is_permissionless_registration(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PermissionlessRegistration)
+        .unwrap_or(false)
+}
+
+// A merchant can let a backend address create invoices on its behalf
+// without granting it a protocol-wide role.
+pub fn delegate_invoice_creation(env: &Env, merchant: &Address, delegate: &Address, enabled: bool) {
+    merchant.require_auth();
+
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let key = DataKey::InvoiceDelegate(merchant_id, delegate.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    events::publish_invoice_delegate_set_event(
+        env,
+        merchant_id,
+        delegate.clone(),
+        enabled,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn is_invoice_delegate(env: &Env, merchant_id: u64, delegate: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::InvoiceDelegate(merchant_id, delegate.clone()))
+}
+
+// The merchant itself or one of its invoice delegates.
+pub fn assert_invoice_operator(env: &Env, merchant_id: u64, caller: &Address) {
+    if *caller != get_merchant(env, merchant_id).address
+        && !is_invoice_delegate(env, merchant_id, caller)
+    {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
 pub fn set_merchant_key(env: &Env, merchant: &Address, key: &BytesN<32>) {
     merchant.require_auth();
 
@@ -181,40 +305,44 @@ pub fn get_merchant_key(env: &Env, merchant: &Address) -> BytesN<32> {
         .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantKeyNotFound))
 }
 
-pub fn get_merchants(env: &Env, filter: MerchantFilter) -> Vec<Merchant> {
-    let merchant_count: u64 = env
-        .storage()
-        .persistent()
-        .get(&DataKey::MerchantCount)
-        .unwrap_or(0);
+// Examines at most `limit` merchant ids after `cursor`, so a selective
+// filter can return a short page. Returns the cursor to resume from, or
+// `None` once every merchant has been examined.
+pub fn get_merchants(
+    env: &Env,
+    filter: MerchantFilter,
+    cursor: u64,
+    limit: u32,
+) -> (Vec<Merchant>, Option<u64>) {
+    let budget = pagination::clamp_limit(limit) as u64;
+    let merchant_count: u64 = ttl::get_persistent(env, &DataKey::MerchantCount).unwrap_or(0);
+    let last = merchant_count.min(cursor.saturating_add(budget));
 
     let mut merchants: Vec<Merchant> = Vec::new(env);
-
-    for i in 1..=merchant_count {
-        if let Some(merchant) = env
+    for merchant_id in cursor.saturating_add(1)..=last {
+        let Some(merchant) = env
             .storage()
             .persistent()
-            .get::<_, Merchant>(&DataKey::Merchant(i))
-        {
-            let mut matches = true;
-
-            if let Some(active) = filter.is_active {
-                if merchant.active != active {
-                    matches = false;
-                }
-            }
-
-            if let Some(verified) = filter.is_verified {
-                if merchant.verified != verified {
-                    matches = false;
-                }
-            }
-
-            if matches {
-                merchants.push_back(merchant);
-            }
+            .get::<_, Merchant>(&DataKey::Merchant(merchant_id))
+        else {
+            continue;
+        };
+
+        let active_matches = filter
+            .is_active
+            .is_none_or(|active| merchant.active == active);
+        let verified_matches = filter
+            .is_verified
+            .is_none_or(|verified| merchant.verified == verified);
+        if active_matches && verified_matches {
+            merchants.push_back(merchant);
         }
     }
 
-    merchants
+    let next_cursor = if last < merchant_count {
+        Some(last)
+    } else {
+        None
+    };
+    (merchants, next_cursor)
 }
@@ -0,0 +1,178 @@
+use crate::components::{core, custody, merchant, ttl, velocity};
+use crate::errors::{AccountError, ContractError};
+use crate::events;
+use crate::interface::MerchantAccountClient;
+use crate::types::DataKey;
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// A merchant links the account contract Shade manages for it, after which
+// withdrawals can go through Shade. Protocol checks (merchant status, the
+// account's restriction and pause flags, velocity limits) run here before
+// the cross-call to the account's `withdraw_to`. Once linked, the account
+// also receives the merchant's payouts, which stop while it is paused or
+// restricted.
+
+pub fn link_merchant_account(env: &Env, merchant: &Address, account: &Address) {
+    merchant.require_auth();
+    let merchant_id = get_merchant_id(env, merchant);
+
+    let info = MerchantAccountClient::new(env, account).get_account_info();
+    if info.merchant != *merchant
+        || info.merchant_id != merchant_id
+        || info.manager != env.current_contract_address()
+    {
+        panic_with_error!(env, AccountError::InvalidMerchantAccount);
+    }
+
+    let key = DataKey::MerchantAccount(merchant_id);
+    env.storage().persistent().set(&key, account);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_merchant_account_linked_event(
+        env,
+        merchant_id,
+        account.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_merchant_account(env: &Env, merchant_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantAccount(merchant_id))
+}
+
+// Fails when the merchant's linked account cannot take payouts right now.
+pub fn assert_accepts_payouts(env: &Env, merchant_id: u64) {
+    let Some(account) = get_merchant_account(env, merchant_id) else {
+        return;
+    };
+    let info = MerchantAccountClient::new(env, &account).get_account_info();
+    if info.paused {
+        panic_with_error!(env, AccountError::MerchantAccountPaused);
+    }
+    if info.restricted {
+        panic_with_error!(env, AccountError::MerchantAccountRestricted);
+    }
+}
+
+// Pays a merchant out of custody. A linked account takes the funds as a
+// deposit, earmarked against `invoice_id` when they are invoice proceeds so
+// they stay available for refunds; without one they go to the merchant
+// address.
+pub fn credit_merchant(
+    env: &Env,
+    merchant_id: u64,
+    merchant_address: &Address,
+    invoice_id: Option<u64>,
+    token: &Address,
+    amount: i128,
+) {
+    assert_accepts_payouts(env, merchant_id);
+    let Some(account) = get_merchant_account(env, merchant_id) else {
+        custody::send(env, token, merchant_address, amount);
+        return;
+    };
+    if amount <= 0 {
+        return;
+    }
+
+    custody::authorize_send(env, token, &account, amount);
+    let account_client = MerchantAccountClient::new(env, &account);
+    let shade = env.current_contract_address();
+    match invoice_id {
+        Some(invoice_id) => {
+            account_client.deposit_for_invoice(&shade, &invoice_id, token, &amount);
+            set_invoice_account_credit(
+                env,
+                invoice_id,
+                get_invoice_account_credit(env, invoice_id) + amount,
+            );
+        }
+        None => account_client.deposit(&shade, token, &amount),
+    }
+}
+
+// What the linked account received for an invoice and has not yet paid back
+// in refunds. Refunds never charge the account more than this.
+pub fn get_invoice_account_credit(env: &Env, invoice_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceAccountCredit(invoice_id))
+        .unwrap_or(0)
+}
+
+pub fn set_invoice_account_credit(env: &Env, invoice_id: u64, credit: i128) {
+    let key = DataKey::InvoiceAccountCredit(invoice_id);
+    env.storage().persistent().set(&key, &credit);
+    ttl::extend_persistent(env, &key);
+}
+
+// Shade manages every linked account, so the admin restricts accounts and
+// approves their withdrawal requests through it.
+pub fn set_merchant_account_restricted(
+    env: &Env,
+    admin: &Address,
+    merchant_id: u64,
+    restricted: bool,
+) {
+    core::assert_admin(env, admin);
+    linked_account(env, merchant_id).set_restricted(&restricted);
+}
+
+pub fn approve_merchant_withdrawal(env: &Env, admin: &Address, merchant_id: u64, request_id: u64) {
+    core::assert_admin(env, admin);
+    linked_account(env, merchant_id)
+        .approve_withdrawal(&env.current_contract_address(), &request_id);
+}
+
+pub fn withdraw_merchant_funds(
+    env: &Env,
+    merchant_address: &Address,
+    token: &Address,
+    amount: i128,
+    to: &Address,
+) {
+    merchant_address.require_auth();
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let merchant_id = get_merchant_id(env, merchant_address);
+    if !merchant::get_merchant(env, merchant_id).active {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+
+    let account = get_merchant_account(env, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, AccountError::MerchantAccountNotLinked));
+    let account_client = MerchantAccountClient::new(env, &account);
+    let info = account_client.get_account_info();
+    if info.restricted || info.paused {
+        panic_with_error!(env, AccountError::MerchantAccountRestricted);
+    }
+
+    velocity::record_payment(env, merchant_address, token, amount);
+    account_client.withdraw_to(token, &amount, to);
+
+    events::publish_merchant_funds_withdrawn_event(
+        env,
+        merchant_id,
+        token.clone(),
+        amount,
+        to.clone(),
+    );
+}
+
+fn linked_account(env: &Env, merchant_id: u64) -> MerchantAccountClient<'_> {
+    let account = get_merchant_account(env, merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, AccountError::MerchantAccountNotLinked));
+    MerchantAccountClient::new(env, &account)
+}
+
+fn get_merchant_id(env: &Env, merchant: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound))
+}
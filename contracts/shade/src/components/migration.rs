@@ -0,0 +1,90 @@
+use crate::components::{admin, core, custody, ttl};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ContractInfo, DataKey};
+use soroban_sdk::{contracttype, panic_with_error, token, Address, Env, Val, Vec};
+
+// Bump whenever a stored record layout changes and add the matching step to
+// `apply_migration`. Deployments initialized before versioning report 0.
+pub const SCHEMA_VERSION: u32 = 3;
+
+// ContractInfo as stored by schema v1, before the version field existed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfoV1 {
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SchemaVersion, &version);
+}
+
+// Meant to be called right after an upgrade lands. Each version step runs
+// exactly once: the stored version must equal `from_version` and is
+// advanced to SCHEMA_VERSION, so a replay fails with InvalidSchemaVersion.
+// `args` carries any inputs a step needs (e.g. keys to move).
+pub fn migrate(env: &Env, admin: &Address, from_version: u32, args: Vec<Val>) {
+    core::assert_admin(env, admin);
+
+    let current_version = get_schema_version(env);
+    if from_version != current_version || current_version >= SCHEMA_VERSION {
+        panic_with_error!(env, ContractError::InvalidSchemaVersion);
+    }
+
+    let mut version = current_version;
+    while version < SCHEMA_VERSION {
+        version = apply_migration(env, version, &args);
+    }
+    set_schema_version(env, version);
+
+    events::publish_schema_migrated_event(env, current_version, version, env.ledger().timestamp());
+}
+
+fn apply_migration(env: &Env, from_version: u32, _args: &Vec<Val>) -> u32 {
+    // v0 -> v1 only stamps the version on deployments that predate
+    // versioning. Steps that rewrite record layouts slot in below.
+    if from_version == 1 {
+        migrate_contract_info_v1(env);
+    }
+    if from_version == 2 {
+        seed_custody_balances(env);
+    }
+    from_version + 1
+}
+
+// v1 -> v2 adds the release version to ContractInfo.
+fn migrate_contract_info_v1(env: &Env) {
+    let legacy: Option<ContractInfoV1> = ttl::get_persistent(env, &DataKey::ContractInfo);
+    if let Some(legacy) = legacy {
+        let info = ContractInfo {
+            admin: legacy.admin,
+            timestamp: legacy.timestamp,
+            version: core::CONTRACT_VERSION,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContractInfo, &info);
+        ttl::extend_persistent(env, &DataKey::ContractInfo);
+    }
+}
+
+// v2 -> v3 starts custody accounting. Whatever an accepted token balance
+// holds at upgrade time is treated as owed, so `recover_tokens` can only
+// sweep transfers that arrive afterwards.
+fn seed_custody_balances(env: &Env) {
+    let contract_address = env.current_contract_address();
+    for token_address in admin::get_accepted_tokens(env).iter() {
+        let balance = token::Client::new(env, &token_address).balance(&contract_address);
+        custody::set_custody_balance(env, &token_address, balance);
+    }
+}
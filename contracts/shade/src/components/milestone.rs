@@ -0,0 +1,183 @@
+use crate::components::{escrow, invoice, merchant, reentrancy, routing, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus, Milestone, MilestoneStatus};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Milestone invoices split the principal into parts the payer funds
+// upfront. The merchant requests each release and the payer approves or
+// disputes it; a disputed milestone can be requested again once the
+// parties have settled it off-chain. Each milestone releases its share of
+// the held net proceeds, and the final one releases whatever remains.
+pub const MAX_MILESTONES: u32 = 20;
+
+pub fn set_invoice_milestones(env: &Env, caller: &Address, invoice_id: u64, amounts: &Vec<i128>) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceMilestones(invoice_id);
+    if amounts.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    if amounts.len() > MAX_MILESTONES {
+        panic_with_error!(env, InvoiceError::InvalidMilestones);
+    }
+
+    let mut milestones = Vec::new(env);
+    let mut total: i128 = 0;
+    for amount in amounts.iter() {
+        if amount <= 0 {
+            panic_with_error!(env, InvoiceError::InvalidMilestones);
+        }
+        total += amount;
+        milestones.push_back(Milestone {
+            amount,
+            release_amount: 0,
+            status: MilestoneStatus::Pending,
+        });
+    }
+    if total != invoice.amount {
+        panic_with_error!(env, InvoiceError::InvalidMilestones);
+    }
+
+    env.storage().persistent().set(&key, &milestones);
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn get_invoice_milestones(env: &Env, invoice_id: u64) -> Vec<Milestone> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceMilestones(invoice_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn has_milestones(env: &Env, invoice_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::InvoiceMilestones(invoice_id))
+}
+
+// Splits the held net proceeds across the milestones at settlement.
+pub fn assign_release_amounts(env: &Env, invoice: &Invoice, net: i128) {
+    let mut milestones = get_invoice_milestones(env, invoice.id);
+    let last = milestones.len() - 1;
+    let mut assigned = 0;
+    for i in 0..milestones.len() {
+        let mut milestone = milestones.get_unchecked(i);
+        milestone.release_amount = if i == last {
+            net - assigned
+        } else {
+            net * milestone.amount / invoice.amount
+        };
+        assigned += milestone.release_amount;
+        milestones.set(i, milestone);
+    }
+    save_milestones(env, invoice.id, &milestones);
+}
+
+pub fn request_milestone_release(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    index: u32,
+) {
+    merchant_address.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if merchant::get_merchant(env, invoice.merchant_id).address != *merchant_address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    update_milestone(
+        env,
+        &invoice,
+        index,
+        &[MilestoneStatus::Pending, MilestoneStatus::Disputed],
+        MilestoneStatus::Requested,
+    );
+}
+
+pub fn approve_milestone(env: &Env, payer: &Address, invoice_id: u64, index: u32) {
+    reentrancy::enter(env);
+    let invoice = assert_payer(env, payer, invoice_id);
+    let milestone = update_milestone(
+        env,
+        &invoice,
+        index,
+        &[MilestoneStatus::Requested],
+        MilestoneStatus::Released,
+    );
+
+    let amount = escrow::take_from_hold(env, invoice_id, milestone.release_amount);
+    if amount > 0 {
+        let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+        routing::pay_merchant(
+            env,
+            invoice_id,
+            invoice.merchant_id,
+            &merchant_address,
+            &invoice.token,
+            amount,
+        );
+    }
+    reentrancy::exit(env);
+}
+
+pub fn dispute_milestone(env: &Env, payer: &Address, invoice_id: u64, index: u32) {
+    let invoice = assert_payer(env, payer, invoice_id);
+    update_milestone(
+        env,
+        &invoice,
+        index,
+        &[MilestoneStatus::Requested],
+        MilestoneStatus::Disputed,
+    );
+}
+
+fn assert_payer(env: &Env, payer: &Address, invoice_id: u64) -> Invoice {
+    payer.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if invoice.payer != Some(payer.clone()) {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    invoice
+}
+
+fn update_milestone(
+    env: &Env,
+    invoice: &Invoice,
+    index: u32,
+    from: &[MilestoneStatus],
+    to: MilestoneStatus,
+) -> Milestone {
+    if invoice.status != InvoiceStatus::Paid {
+        panic_with_error!(env, InvoiceError::InvoiceNotPaid);
+    }
+
+    let mut milestones = get_invoice_milestones(env, invoice.id);
+    let mut milestone = milestones
+        .get(index)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvalidMilestoneState));
+    if !from.contains(&milestone.status) {
+        panic_with_error!(env, InvoiceError::InvalidMilestoneState);
+    }
+
+    milestone.status = to;
+    milestones.set(index, milestone.clone());
+    save_milestones(env, invoice.id, &milestones);
+
+    events::publish_milestone_updated_event(env, invoice.id, index, to, env.ledger().timestamp());
+    milestone
+}
+
+fn save_milestones(env: &Env, invoice_id: u64, milestones: &Vec<Milestone>) {
+    let key = DataKey::InvoiceMilestones(invoice_id);
+    env.storage().persistent().set(&key, milestones);
+    ttl::extend_persistent(env, &key);
+}
@@ -1,8 +1,46 @@
 pub mod access_control;
+pub mod activity;
 pub mod admin;
+pub mod affiliate;
+pub mod allowlist;
+pub mod amendment;
+pub mod approval;
+pub mod blocklist;
+pub mod bond;
+pub mod campaign;
+pub mod cleanup;
+pub mod contribution;
 pub mod core;
+pub mod custody;
+pub mod customer;
+pub mod distribution;
+pub mod due_date;
+pub mod escrow;
+pub mod expiry;
+pub mod gift_card;
+pub mod governance;
 pub mod invoice;
+pub mod late_fee;
 pub mod merchant;
+pub mod merchant_account;
+pub mod migration;
+pub mod milestone;
+pub mod oracle;
+pub mod overpayment;
+pub mod pagination;
 pub mod pausable;
+pub mod payment_hook;
+pub mod payment_link;
+pub mod rate_limit;
+pub mod recurring;
 pub mod reentrancy;
+pub mod refund;
+pub mod routing;
+pub mod settlement;
+pub mod stats;
+pub mod stream;
+pub mod tax;
+pub mod tip;
+pub mod ttl;
 pub mod upgrade;
+pub mod velocity;
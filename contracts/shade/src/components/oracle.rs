@@ -0,0 +1,44 @@
+use crate::components::{allowlist, core};
+use crate::errors::ContractError;
+use crate::events;
+use crate::interface::PriceOracleClient;
+use crate::types::{DataKey, OracleAsset, OracleConfig};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+pub fn set_oracle(env: &Env, admin: &Address, oracle: &Address, max_price_age: u64) {
+    core::assert_admin(env, admin);
+    allowlist::assert_trusted_contract(env, oracle);
+
+    let config = OracleConfig {
+        address: oracle.clone(),
+        max_price_age,
+    };
+    env.storage().persistent().set(&DataKey::Oracle, &config);
+
+    events::publish_oracle_set_event(env, oracle.clone(), max_price_age, env.ledger().timestamp());
+}
+
+pub fn get_oracle(env: &Env) -> Option<OracleConfig> {
+    env.storage().persistent().get(&DataKey::Oracle)
+}
+
+// Anything that prices in USD must fail closed: an unreachable oracle, a
+// missing quote or a quote older than `max_price_age` aborts the caller
+// instead of falling back to a guessed price.
+pub fn get_price(env: &Env, token: &Address) -> i128 {
+    let config = get_oracle(env)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::OracleNotConfigured));
+    allowlist::assert_trusted_contract(env, &config.address);
+
+    let client = PriceOracleClient::new(env, &config.address);
+    let price = match client.try_lastprice(&OracleAsset::Stellar(token.clone())) {
+        Ok(Ok(Some(price))) => price,
+        _ => panic_with_error!(env, ContractError::OraclePriceUnavailable),
+    };
+
+    if env.ledger().timestamp() > price.timestamp + config.max_price_age {
+        panic_with_error!(env, ContractError::OraclePriceStale);
+    }
+
+    price.price
+}
@@ -0,0 +1,70 @@
+use crate::components::{admin, custody, customer, merchant, routing, stats, ttl};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Invoice, OverpaymentPolicy};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Decides what happens when escrowed funds exceed what an invoice is due.
+// Merchants reject overpayments by default; they can instead keep the
+// surplus (fee and payout routes apply as for the invoice itself) or have
+// it returned to the payer.
+
+pub fn set_overpayment_policy(env: &Env, merchant_address: &Address, policy: OverpaymentPolicy) {
+    merchant_address.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let key = DataKey::OverpaymentPolicy(merchant_id);
+    env.storage().persistent().set(&key, &policy);
+    ttl::extend_persistent(env, &key);
+
+    events::publish_overpayment_policy_set_event(
+        env,
+        merchant_id,
+        policy,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_overpayment_policy(env: &Env, merchant_id: u64) -> OverpaymentPolicy {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OverpaymentPolicy(merchant_id))
+        .unwrap_or(OverpaymentPolicy::Reject)
+}
+
+// Called after the invoice itself has been settled. Returns false when the
+// merchant rejects overpayments so the caller can surface its own error.
+pub fn settle_surplus(env: &Env, invoice: &Invoice, payer: &Address, surplus: i128) -> bool {
+    let policy = get_overpayment_policy(env, invoice.merchant_id);
+    match policy {
+        OverpaymentPolicy::Reject => return false,
+        OverpaymentPolicy::CreditMerchant => {
+            let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+            let fee =
+                admin::calculate_fee(env, &invoice.token, surplus, &[payer, &merchant_address]);
+            admin::collect_fee(env, &invoice.token, fee);
+            stats::record_volume(env, &invoice.token, surplus);
+            routing::pay_merchant(
+                env,
+                invoice.id,
+                invoice.merchant_id,
+                &merchant_address,
+                &invoice.token,
+                surplus - fee,
+            );
+        }
+        OverpaymentPolicy::RefundPayer => custody::send(
+            env,
+            &invoice.token,
+            &customer::refund_address(env, payer),
+            surplus,
+        ),
+    }
+
+    events::publish_overpayment_settled_event(env, invoice.id, payer.clone(), policy, surplus);
+    true
+}
@@ -0,0 +1,70 @@
+use crate::components::ttl;
+use crate::types::DataKey;
+use soroban_sdk::{Env, Vec};
+
+pub const MAX_PAGE_LIMIT: u32 = 100;
+pub const INDEX_BUCKET_SIZE: u64 = 100;
+
+pub fn clamp_limit(limit: u32) -> u32 {
+    if limit == 0 || limit > MAX_PAGE_LIMIT {
+        MAX_PAGE_LIMIT
+    } else {
+        limit
+    }
+}
+
+// Secondary indexes are append-only lists of ids split across fixed-size
+// buckets, so neither writes nor reads ever touch a single unbounded entry.
+
+pub fn index_len(env: &Env, len_key: &DataKey) -> u64 {
+    ttl::get_persistent(env, len_key).unwrap_or(0)
+}
+
+pub fn index_push<F>(env: &Env, len_key: &DataKey, bucket_key: F, value: u64)
+where
+    F: Fn(u64) -> DataKey,
+{
+    let len = index_len(env, len_key);
+    let key = bucket_key(len / INDEX_BUCKET_SIZE);
+
+    let mut bucket: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    bucket.push_back(value);
+
+    env.storage().persistent().set(&key, &bucket);
+    env.storage().persistent().set(len_key, &(len + 1));
+    ttl::extend_persistent(env, &key);
+    ttl::extend_persistent(env, len_key);
+}
+
+pub fn index_get<F>(env: &Env, bucket_key: F, position: u64) -> Option<u64>
+where
+    F: Fn(u64) -> DataKey,
+{
+    let bucket: Vec<u64> = ttl::get_persistent(env, &bucket_key(position / INDEX_BUCKET_SIZE))?;
+    bucket.get((position % INDEX_BUCKET_SIZE) as u32)
+}
+
+/// Returns the first position whose id is strictly greater than `after`.
+/// Index values are appended in increasing id order, so a binary search over
+/// positions finds it in O(log n) bucket reads.
+pub fn index_lower_bound<F>(env: &Env, len_key: &DataKey, bucket_key: F, after: u64) -> u64
+where
+    F: Fn(u64) -> DataKey,
+{
+    let mut low = 0;
+    let mut high = index_len(env, len_key);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match index_get(env, &bucket_key, mid) {
+            Some(value) if value <= after => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+
+    low
+}
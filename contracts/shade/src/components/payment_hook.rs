@@ -0,0 +1,58 @@
+use crate::components::{allowlist, ttl};
+use crate::errors::ContractError;
+use crate::events;
+use crate::interface::PaymentHookClient;
+use crate::types::{DataKey, Invoice};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// A merchant can register a contract whose `on_payment` Shade calls after
+// each of its invoices is paid, e.g. to mint a ticket. Hooks must be on the
+// admin's trusted contract list. The call is best-effort: a hook that fails,
+// or has since lost its trusted status, never reverts the payment; a
+// payment_hook_failed event records it instead.
+
+pub fn set_payment_hook(env: &Env, merchant: &Address, hook: &Option<Address>) {
+    merchant.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let key = DataKey::PaymentHook(merchant_id);
+    match hook {
+        Some(hook) => {
+            allowlist::assert_trusted_contract(env, hook);
+            env.storage().persistent().set(&key, hook);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    events::publish_payment_hook_set_event(
+        env,
+        merchant_id,
+        hook.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_payment_hook(env: &Env, merchant_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentHook(merchant_id))
+}
+
+pub fn notify_payment(env: &Env, invoice: &Invoice, payer: &Address, amount: i128) {
+    let Some(hook) = get_payment_hook(env, invoice.merchant_id) else {
+        return;
+    };
+
+    let delivered = allowlist::is_trusted_contract(env, &hook)
+        && PaymentHookClient::new(env, &hook)
+            .try_on_payment(&invoice.id, payer, &amount, &invoice.token)
+            .is_ok();
+    if !delivered {
+        events::publish_payment_hook_failed_event(env, invoice.id, hook);
+    }
+}
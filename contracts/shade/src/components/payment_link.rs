@@ -0,0 +1,239 @@
+use crate::components::{
+    activity, admin, blocklist, custody, invoice, overpayment, reentrancy, stats, ttl, velocity,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ActivityKind, DataKey, PaymentLink, PaymentLinkStatus};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{panic_with_error, Address, Bytes, BytesN, Env};
+
+// A payment link escrows funds behind sha256(secret). Whoever presents the
+// secret before expiry can apply it to a matching invoice or take it
+// directly; otherwise the payer reclaims it once the link expires.
+//
+// Claims are commit-reveal so a secret seen in a pending claim can't be
+// replayed by someone else: the claimant first commits
+// sha256(secret || claimant XDR) and can only reveal the secret in a later
+// ledger. A front-runner's commitment would land too late to use it.
+
+pub fn create_payment_link(
+    env: &Env,
+    payer: &Address,
+    token: &Address,
+    amount: i128,
+    claim_hash: &BytesN<32>,
+    expires_at: u64,
+) -> u64 {
+    reentrancy::enter(env);
+    payer.require_auth();
+    blocklist::assert_not_blocked(env, payer);
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if expires_at <= env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::PaymentLinkExpired);
+    }
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+
+    velocity::record_payment(env, payer, token, amount);
+    custody::receive(env, token, payer, amount);
+
+    let link_count: u64 = ttl::get_persistent(env, &DataKey::PaymentLinkCount).unwrap_or(0);
+    let link_id = link_count + 1;
+
+    let link = PaymentLink {
+        id: link_id,
+        payer: payer.clone(),
+        token: token.clone(),
+        amount,
+        claim_hash: claim_hash.clone(),
+        expires_at,
+        status: PaymentLinkStatus::Open,
+    };
+    save_payment_link(env, &link);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PaymentLinkCount, &link_id);
+    ttl::extend_persistent(env, &DataKey::PaymentLinkCount);
+
+    events::publish_payment_link_created_event(
+        env,
+        link_id,
+        payer.clone(),
+        token.clone(),
+        amount,
+        expires_at,
+    );
+    reentrancy::exit(env);
+    link_id
+}
+
+pub fn commit_payment_link_claim(
+    env: &Env,
+    claimant: &Address,
+    link_id: u64,
+    commitment: &BytesN<32>,
+) {
+    claimant.require_auth();
+    blocklist::assert_not_blocked(env, claimant);
+
+    let link = get_payment_link(env, link_id);
+    if link.status != PaymentLinkStatus::Open {
+        panic_with_error!(env, ContractError::PaymentLinkNotOpen);
+    }
+    if env.ledger().timestamp() >= link.expires_at {
+        panic_with_error!(env, ContractError::PaymentLinkExpired);
+    }
+
+    let key = DataKey::PaymentLinkClaimCommitment(link_id, claimant.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(commitment.clone(), env.ledger().sequence()));
+    ttl::extend_persistent(env, &key);
+}
+
+pub fn claim_commitment(env: &Env, claimant: &Address, secret: &Bytes) -> BytesN<32> {
+    let mut preimage = secret.clone();
+    preimage.append(&claimant.clone().to_xdr(env));
+    env.crypto().sha256(&preimage).into()
+}
+
+pub fn claim_payment_link(
+    env: &Env,
+    claimant: &Address,
+    link_id: u64,
+    secret: &Bytes,
+    invoice_id: Option<u64>,
+) {
+    reentrancy::enter(env);
+    claimant.require_auth();
+    blocklist::assert_not_blocked(env, claimant);
+
+    let mut link = get_payment_link(env, link_id);
+    if link.status != PaymentLinkStatus::Open {
+        panic_with_error!(env, ContractError::PaymentLinkNotOpen);
+    }
+    if env.ledger().timestamp() >= link.expires_at {
+        panic_with_error!(env, ContractError::PaymentLinkExpired);
+    }
+    let hash: BytesN<32> = env.crypto().sha256(secret).into();
+    if hash != link.claim_hash {
+        panic_with_error!(env, ContractError::InvalidClaimSecret);
+    }
+    // A missing, mismatched or same-ledger commitment reads as a bad secret.
+    let commitment_key = DataKey::PaymentLinkClaimCommitment(link_id, claimant.clone());
+    let committed: Option<(BytesN<32>, u32)> = env.storage().persistent().get(&commitment_key);
+    match committed {
+        Some((commitment, ledger))
+            if ledger < env.ledger().sequence()
+                && commitment == claim_commitment(env, claimant, secret) => {}
+        _ => panic_with_error!(env, ContractError::InvalidClaimSecret),
+    }
+    env.storage().persistent().remove(&commitment_key);
+
+    link.status = PaymentLinkStatus::Claimed;
+    save_payment_link(env, &link);
+
+    match invoice_id {
+        Some(invoice_id) => {
+            let invoice = invoice::get_invoice(env, invoice_id);
+            invoice::assert_payable(env, &invoice);
+            let amount_due = invoice::amount_due(env, &invoice);
+            if invoice.token != link.token || link.amount < amount_due {
+                panic_with_error!(env, ContractError::PaymentLinkNotApplicable);
+            }
+            let invoice = invoice::settle_from_escrow(env, &invoice, &link.payer);
+            let surplus = link.amount - amount_due;
+            if surplus > 0 && !overpayment::settle_surplus(env, &invoice, &link.payer, surplus) {
+                panic_with_error!(env, ContractError::PaymentLinkNotApplicable);
+            }
+        }
+        None => {
+            let fee = admin::calculate_fee(env, &link.token, link.amount, &[&link.payer, claimant]);
+            admin::collect_fee(env, &link.token, fee);
+            stats::record_volume(env, &link.token, link.amount);
+            custody::send(env, &link.token, claimant, link.amount - fee);
+        }
+    }
+
+    events::publish_payment_link_claimed_event(
+        env,
+        link_id,
+        claimant.clone(),
+        invoice_id,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+pub fn refund_payment_link(env: &Env, link_id: u64) {
+    reentrancy::enter(env);
+
+    let link = get_payment_link(env, link_id);
+    if link.status != PaymentLinkStatus::Open {
+        panic_with_error!(env, ContractError::PaymentLinkNotOpen);
+    }
+    if env.ledger().timestamp() < link.expires_at {
+        panic_with_error!(env, ContractError::PaymentLinkNotExpired);
+    }
+
+    refund_expired_link(env, link_id, link);
+    reentrancy::exit(env);
+}
+
+// Sweep variant: refunds the link only if it exists, is open and has
+// expired, and returns its token when it did.
+pub fn try_refund_payment_link(env: &Env, link_id: u64) -> Option<Address> {
+    let link: PaymentLink = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PaymentLink(link_id))?;
+    if link.status != PaymentLinkStatus::Open || env.ledger().timestamp() < link.expires_at {
+        return None;
+    }
+
+    let token = link.token.clone();
+    refund_expired_link(env, link_id, link);
+    Some(token)
+}
+
+fn refund_expired_link(env: &Env, link_id: u64, mut link: PaymentLink) {
+    link.status = PaymentLinkStatus::Refunded;
+    save_payment_link(env, &link);
+
+    custody::send(env, &link.token, &link.payer, link.amount);
+    activity::record_activity(
+        env,
+        ActivityKind::PaymentLinkRefund,
+        link_id,
+        &link.token,
+        link.amount,
+    );
+
+    events::publish_payment_link_refunded_event(
+        env,
+        link_id,
+        link.amount,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_payment_link(env: &Env, link_id: u64) -> PaymentLink {
+    let key = DataKey::PaymentLink(link_id);
+    let link = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::PaymentLinkNotFound));
+    ttl::extend_persistent(env, &key);
+    link
+}
+
+fn save_payment_link(env: &Env, link: &PaymentLink) {
+    let key = DataKey::PaymentLink(link.id);
+    env.storage().persistent().set(&key, link);
+    ttl::extend_persistent(env, &key);
+}
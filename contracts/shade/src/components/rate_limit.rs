@@ -0,0 +1,98 @@
+use crate::components::{core, merchant, ttl};
+use crate::errors::{ComplianceError, ContractError};
+use crate::events;
+use crate::types::{DataKey, InvoiceRateLimit, InvoiceWindow};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Optional cap on invoices a merchant can create per fixed window, to keep
+// spam from bloating invoice storage and the secondary indexes. A
+// per-merchant override takes precedence over the admin default.
+
+pub fn set_default_invoice_rate_limit(
+    env: &Env,
+    admin: &Address,
+    limit: &Option<InvoiceRateLimit>,
+) {
+    core::assert_admin(env, admin);
+    store_limit(env, &DataKey::DefaultInvoiceRateLimit, limit);
+
+    events::publish_invoice_rate_limit_set_event(
+        env,
+        None,
+        limit.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn set_merchant_invoice_rate_limit(
+    env: &Env,
+    admin: &Address,
+    merchant_id: u64,
+    limit: &Option<InvoiceRateLimit>,
+) {
+    core::assert_admin(env, admin);
+    merchant::get_merchant(env, merchant_id);
+    store_limit(env, &DataKey::MerchantInvoiceRateLimit(merchant_id), limit);
+
+    events::publish_invoice_rate_limit_set_event(
+        env,
+        Some(merchant_id),
+        limit.clone(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_invoice_rate_limit(env: &Env, merchant_id: u64) -> Option<InvoiceRateLimit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantInvoiceRateLimit(merchant_id))
+        .or_else(|| {
+            env.storage()
+                .persistent()
+                .get(&DataKey::DefaultInvoiceRateLimit)
+        })
+}
+
+pub fn record_invoice_created(env: &Env, merchant_id: u64) {
+    let Some(limit) = get_invoice_rate_limit(env, merchant_id) else {
+        return;
+    };
+
+    let key = DataKey::MerchantInvoiceWindow(merchant_id);
+    let now = env.ledger().timestamp();
+    let mut window: InvoiceWindow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(InvoiceWindow {
+            start: now,
+            count: 0,
+        });
+
+    if now >= window.start + limit.window {
+        window = InvoiceWindow {
+            start: now,
+            count: 0,
+        };
+    }
+    if window.count >= limit.max_invoices {
+        panic_with_error!(env, ComplianceError::RateLimited);
+    }
+
+    window.count += 1;
+    env.storage().persistent().set(&key, &window);
+    ttl::extend_persistent(env, &key);
+}
+
+fn store_limit(env: &Env, key: &DataKey, limit: &Option<InvoiceRateLimit>) {
+    match limit {
+        Some(limit) => {
+            if limit.max_invoices == 0 || limit.window == 0 {
+                panic_with_error!(env, ContractError::InvalidAmount);
+            }
+            env.storage().persistent().set(key, limit);
+            ttl::extend_persistent(env, key);
+        }
+        None => env.storage().persistent().remove(key),
+    }
+}
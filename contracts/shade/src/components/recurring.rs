@@ -0,0 +1,127 @@
+use crate::components::{invoice, merchant, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, RecurringSchedule};
+use soroban_sdk::{panic_with_error, Address, Env, String, Vec};
+
+// Recurring schedules issue a new pending invoice every period for push
+// billing, as opposed to pulling funds from the payer. The merchant
+// authorizes the schedule once and the first invoice is due right away;
+// after that anyone may trigger generation of the invoices that have come
+// due. Each call catches up at most MAX_INVOICES_PER_GENERATION periods.
+pub const MAX_INVOICES_PER_GENERATION: u32 = 10;
+
+pub fn create_recurring_schedule(
+    env: &Env,
+    merchant_address: &Address,
+    description: &String,
+    amount: i128,
+    token: &Address,
+    period: u64,
+    max_invoices: Option<u32>,
+) -> u64 {
+    merchant_address.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant_address.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    if amount <= 0 || period == 0 || max_invoices == Some(0) {
+        panic_with_error!(env, InvoiceError::InvalidRecurringSchedule);
+    }
+
+    let schedule_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RecurringScheduleCount)
+        .unwrap_or(0)
+        + 1;
+    let schedule = RecurringSchedule {
+        id: schedule_id,
+        merchant_id,
+        description: description.clone(),
+        amount,
+        token: token.clone(),
+        period,
+        next_at: env.ledger().timestamp(),
+        remaining: max_invoices,
+        active: true,
+    };
+    save_schedule(env, &schedule);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RecurringScheduleCount, &schedule_id);
+
+    events::publish_recurring_schedule_created_event(env, schedule_id, merchant_id, amount, period);
+    schedule_id
+}
+
+pub fn cancel_recurring_schedule(env: &Env, merchant_address: &Address, schedule_id: u64) {
+    merchant_address.require_auth();
+
+    let mut schedule = get_recurring_schedule(env, schedule_id);
+    if merchant::get_merchant(env, schedule.merchant_id).address != *merchant_address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if !schedule.active {
+        panic_with_error!(env, InvoiceError::InvalidRecurringSchedule);
+    }
+
+    schedule.active = false;
+    save_schedule(env, &schedule);
+    events::publish_recurring_schedule_ended_event(env, schedule_id, env.ledger().timestamp());
+}
+
+pub fn get_recurring_schedule(env: &Env, schedule_id: u64) -> RecurringSchedule {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecurringSchedule(schedule_id))
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::RecurringScheduleNotFound))
+}
+
+pub fn generate_due_invoices(env: &Env, schedule_id: u64) -> Vec<u64> {
+    let mut schedule = get_recurring_schedule(env, schedule_id);
+    let merchant_address = merchant::get_merchant(env, schedule.merchant_id).address;
+    let now = env.ledger().timestamp();
+
+    let mut invoice_ids = Vec::new(env);
+    while schedule.active
+        && schedule.next_at <= now
+        && invoice_ids.len() < MAX_INVOICES_PER_GENERATION
+    {
+        let invoice_id = invoice::issue_invoice(
+            env,
+            &merchant_address,
+            schedule.merchant_id,
+            &schedule.description,
+            schedule.amount,
+            &schedule.token,
+            None,
+        );
+        events::publish_recurring_invoice_issued_event(
+            env,
+            schedule_id,
+            invoice_id,
+            schedule.next_at,
+        );
+        invoice_ids.push_back(invoice_id);
+
+        schedule.next_at += schedule.period;
+        if let Some(remaining) = schedule.remaining {
+            schedule.remaining = Some(remaining - 1);
+            schedule.active = remaining > 1;
+        }
+    }
+
+    if !invoice_ids.is_empty() {
+        save_schedule(env, &schedule);
+    }
+    invoice_ids
+}
+
+fn save_schedule(env: &Env, schedule: &RecurringSchedule) {
+    let key = DataKey::RecurringSchedule(schedule.id);
+    env.storage().persistent().set(&key, schedule);
+    ttl::extend_persistent(env, &key);
+}
@@ -0,0 +1,309 @@
+use crate::components::{
+    access_control, admin, contribution, custody, customer, distribution, escrow, invoice,
+    merchant, merchant_account, reentrancy, ttl,
+};
+use crate::errors::{AccountError, ContractError, InvoiceError};
+use crate::events;
+use crate::interface::MerchantAccountClient;
+use crate::types::{DataKey, Invoice, InvoiceStatus, Role};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Refunds come first out of funds Shade still holds in escrow, then out of
+// the merchant's linked account, which only lets its managing Shade
+// contract pull refunds, up to what the account received for the invoice.
+// Anything beyond that (no linked account, routed payouts, tax sent to the
+// tax recipient) is pulled from the merchant address, which must authorize
+// the transfer. Only the owning merchant or an Admin/Manager may trigger a
+// refund. Split-bill invoices are refunded to each
+// contributor in proportion to their share; the last one absorbs rounding.
+// When the admin enables fee refunds, Shade returns the proportional
+// protocol fee it kept and the merchant's account only covers the rest.
+// Invoices marked final-sale before payment cannot be refunded at all.
+//
+// Invoice proceeds paid into a linked account stay earmarked for refunds
+// until REFUND_WINDOW after payment, after which anyone may release them to
+// the account's free balance.
+//
+// When the admin enables refund subsidies, the part of a refund inside the
+// window that neither escrow nor the linked account can cover is paid from
+// undistributed protocol fees instead of the merchant address. The merchant
+// owes it back in that token, netted from its future invoice payouts.
+pub const REFUND_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+pub fn set_invoice_refundable(env: &Env, caller: &Address, invoice_id: u64, refundable: bool) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceFinalSale(invoice_id);
+    if refundable {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &true);
+        ttl::extend_persistent(env, &key);
+    }
+}
+
+pub fn is_invoice_refundable(env: &Env, invoice_id: u64) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&DataKey::InvoiceFinalSale(invoice_id))
+}
+
+pub fn refund_invoice(env: &Env, caller: &Address, invoice_id: u64) -> i128 {
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let remaining = invoice.amount - get_refunded_amount(env, invoice_id);
+    refund_invoice_partial(env, caller, invoice_id, remaining);
+    remaining
+}
+
+pub fn refund_invoice_partial(env: &Env, caller: &Address, invoice_id: u64, amount: i128) {
+    reentrancy::enter(env);
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    assert_can_refund(env, &invoice, caller);
+    refund(env, caller, &invoice, amount, None);
+    reentrancy::exit(env);
+}
+
+// Sends the refund to a replacement address, e.g. when the payer's wallet
+// is compromised or a custodial address can no longer receive the token.
+// Only the owning merchant may redirect a refund.
+pub fn refund_invoice_to(
+    env: &Env,
+    merchant_address: &Address,
+    invoice_id: u64,
+    amount: i128,
+    recipient: &Address,
+) {
+    reentrancy::enter(env);
+    merchant_address.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    if merchant::get_merchant(env, invoice.merchant_id).address != *merchant_address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    refund(env, merchant_address, &invoice, amount, Some(recipient));
+    events::publish_refund_redirected_event(env, invoice_id, recipient.clone(), amount);
+    reentrancy::exit(env);
+}
+
+pub fn release_invoice_funds(env: &Env, invoice_id: u64) {
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let date_paid = invoice
+        .date_paid
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::InvoiceNotPaid));
+    if env.ledger().timestamp() < date_paid + REFUND_WINDOW {
+        panic_with_error!(env, InvoiceError::RefundWindowOpen);
+    }
+
+    let account = merchant_account::get_merchant_account(env, invoice.merchant_id)
+        .unwrap_or_else(|| panic_with_error!(env, AccountError::MerchantAccountNotLinked));
+    MerchantAccountClient::new(env, &account).release_invoice_funds(&invoice_id, &invoice.token);
+}
+
+pub fn get_merchant_debt(env: &Env, merchant_id: u64, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerchantDebt(merchant_id, token.clone()))
+        .unwrap_or(0)
+}
+
+// Withholds what the merchant owes for subsidized refunds from a payout,
+// returns it to the protocol fee reserves and hands back what is left to
+// pay out.
+pub fn repay_merchant_debt(env: &Env, merchant_id: u64, token: &Address, payout: i128) -> i128 {
+    let debt = get_merchant_debt(env, merchant_id, token);
+    let repaid = debt.min(payout);
+    if repaid <= 0 {
+        return payout;
+    }
+
+    admin::collect_fee(env, token, repaid);
+    set_merchant_debt(env, merchant_id, token, debt - repaid);
+    events::publish_merchant_debt_repaid_event(
+        env,
+        merchant_id,
+        token.clone(),
+        repaid,
+        debt - repaid,
+    );
+    payout - repaid
+}
+
+fn set_merchant_debt(env: &Env, merchant_id: u64, token: &Address, debt: i128) {
+    let key = DataKey::MerchantDebt(merchant_id, token.clone());
+    if debt == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &debt);
+        ttl::extend_persistent(env, &key);
+    }
+}
+
+pub fn get_refunded_amount(env: &Env, invoice_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceRefundedAmount(invoice_id))
+        .unwrap_or(0)
+}
+
+fn refund(
+    env: &Env,
+    caller: &Address,
+    invoice: &Invoice,
+    amount: i128,
+    recipient: Option<&Address>,
+) {
+    let invoice_id = invoice.id;
+    if invoice.status != InvoiceStatus::Paid {
+        panic_with_error!(env, InvoiceError::InvoiceNotPaid);
+    }
+    if !is_invoice_refundable(env, invoice_id) {
+        panic_with_error!(env, InvoiceError::InvoiceNotRefundable);
+    }
+
+    let refunded = get_refunded_amount(env, invoice_id);
+    if amount <= 0 || amount > invoice.amount - refunded {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let total_refunded = refunded + amount;
+    let fee_refund = if admin::refunds_protocol_fees(env) {
+        let fee = invoice::get_invoice_fee(env, invoice_id);
+        fee * total_refunded / invoice.amount - fee * refunded / invoice.amount
+    } else {
+        0
+    };
+
+    let merchant_portion = amount - fee_refund;
+    let from_escrow = escrow::take_from_hold(env, invoice_id, merchant_portion);
+    let account_credit = merchant_account::get_invoice_account_credit(env, invoice_id);
+    let from_account = (merchant_portion - from_escrow).min(account_credit);
+    let shortfall = merchant_portion - from_escrow - from_account;
+    let subsidy = subsidy_for(env, invoice, shortfall);
+    let from_merchant = shortfall - subsidy;
+    if from_merchant > 0 {
+        let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+        custody::receive(env, &invoice.token, &merchant_address, from_merchant);
+    }
+    if subsidy > 0 {
+        admin::release_collected_fee(env, &invoice.token, subsidy);
+        set_merchant_debt(
+            env,
+            invoice.merchant_id,
+            &invoice.token,
+            get_merchant_debt(env, invoice.merchant_id, &invoice.token) + subsidy,
+        );
+        events::publish_refund_subsidized_event(
+            env,
+            invoice_id,
+            invoice.merchant_id,
+            invoice.token.clone(),
+            subsidy,
+        );
+    }
+    let from_custody = from_escrow + subsidy + from_merchant;
+    for (payee, portion) in allocate(env, invoice, from_custody, recipient).iter() {
+        custody::send(env, &invoice.token, &payee, portion);
+    }
+    if from_account > 0 {
+        let account = merchant_account::get_merchant_account(env, invoice.merchant_id)
+            .unwrap_or_else(|| panic_with_error!(env, AccountError::MerchantAccountNotLinked));
+        let account_client = MerchantAccountClient::new(env, &account);
+        for (payee, portion) in allocate(env, invoice, from_account, recipient).iter() {
+            account_client.refund(&invoice_id, &invoice.token, &portion, &payee);
+        }
+        merchant_account::set_invoice_account_credit(
+            env,
+            invoice_id,
+            account_credit - from_account,
+        );
+    }
+
+    if fee_refund > 0 {
+        admin::release_collected_fee(env, &invoice.token, fee_refund);
+        for (payee, portion) in allocate(env, invoice, fee_refund, recipient).iter() {
+            custody::send(env, &invoice.token, &payee, portion);
+        }
+        events::publish_fee_refunded_event(env, invoice_id, invoice.token.clone(), fee_refund);
+    }
+
+    let key = DataKey::InvoiceRefundedAmount(invoice_id);
+    env.storage().persistent().set(&key, &total_refunded);
+    ttl::extend_persistent(env, &key);
+    if total_refunded == invoice.amount {
+        invoice::set_invoice_status(env, invoice_id, InvoiceStatus::Refunded);
+    }
+
+    events::publish_invoice_refunded_event(env, invoice_id, caller.clone(), amount, total_refunded);
+}
+
+// How much of a refund shortfall the protocol fee reserves cover: none
+// unless subsidies are enabled and the invoice is still inside its refund
+// window, and never more than the undistributed fees.
+fn subsidy_for(env: &Env, invoice: &Invoice, shortfall: i128) -> i128 {
+    if shortfall <= 0 || !admin::subsidizes_refunds(env) {
+        return 0;
+    }
+    let in_window = invoice
+        .date_paid
+        .is_some_and(|date_paid| env.ledger().timestamp() < date_paid + REFUND_WINDOW);
+    if !in_window {
+        return 0;
+    }
+    shortfall.min(distribution::get_undistributed_fees(env, &invoice.token))
+}
+
+// Splits a refund between the payees: the redirect recipient if any, else
+// the payer or, for split bills, each contributor by share. Payers who
+// registered a refund address are paid there.
+fn allocate(
+    env: &Env,
+    invoice: &Invoice,
+    amount: i128,
+    recipient: Option<&Address>,
+) -> Vec<(Address, i128)> {
+    let mut allocations = Vec::new(env);
+    if amount <= 0 {
+        return allocations;
+    }
+
+    let shares = contribution::get_payer_shares(env, invoice.id);
+    if let Some(recipient) = recipient {
+        allocations.push_back((recipient.clone(), amount));
+    } else if shares.is_empty() {
+        let payer = invoice.payer.clone().unwrap();
+        allocations.push_back((customer::refund_address(env, &payer), amount));
+    } else {
+        let mut remaining = amount;
+        let last = shares.len() - 1;
+        for (i, (payer, share)) in shares.iter().enumerate() {
+            let portion = if i as u32 == last {
+                remaining
+            } else {
+                amount * share / invoice.amount
+            };
+            if portion > 0 {
+                allocations.push_back((customer::refund_address(env, &payer), portion));
+            }
+            remaining -= portion;
+        }
+    }
+    allocations
+}
+
+fn assert_can_refund(env: &Env, invoice: &Invoice, caller: &Address) {
+    let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+    if *caller != merchant_address
+        && !access_control::has_role(env, caller, Role::Admin)
+        && !access_control::has_role(env, caller, Role::Manager)
+    {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::components::{custody, merchant_account, refund, ttl};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, PaymentRoute, PayoutSplit};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// A merchant can split its invoice proceeds between several addresses (main
+// account, tax account, partner). Shares are in basis points and must add
+// up to 10_000; rounding dust goes to the first route. Without routes the
+// merchant's linked account, or its address when none is linked, receives
+// everything.
+pub const MAX_PAYMENT_ROUTES: u32 = 10;
+const TOTAL_SHARE_BPS: u32 = 10_000;
+
+// An empty `routes` clears the table.
+pub fn set_payment_routes(env: &Env, merchant: &Address, routes: &Vec<PaymentRoute>) {
+    merchant.require_auth();
+    let merchant_id: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::MerchantId(merchant.clone()))
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::MerchantNotFound));
+
+    let key = DataKey::PaymentRoutes(merchant_id);
+    if routes.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        if routes.len() > MAX_PAYMENT_ROUTES {
+            panic_with_error!(env, ContractError::InvalidPaymentRoutes);
+        }
+        let mut total: u32 = 0;
+        for route in routes.iter() {
+            if route.share_bps == 0 {
+                panic_with_error!(env, ContractError::InvalidPaymentRoutes);
+            }
+            total = total.saturating_add(route.share_bps);
+        }
+        if total != TOTAL_SHARE_BPS {
+            panic_with_error!(env, ContractError::InvalidPaymentRoutes);
+        }
+
+        env.storage().persistent().set(&key, routes);
+        ttl::extend_persistent(env, &key);
+    }
+
+    events::publish_payment_routes_set_event(
+        env,
+        merchant_id,
+        routes.len(),
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_payment_routes(env: &Env, merchant_id: u64) -> Vec<PaymentRoute> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PaymentRoutes(merchant_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+// Pays a merchant's net invoice proceeds out of custody along its routes,
+// after withholding any debt from subsidized refunds.
+pub fn pay_merchant(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    merchant_address: &Address,
+    token: &Address,
+    net: i128,
+) {
+    let net = refund::repay_merchant_debt(env, merchant_id, token, net);
+    let routes = get_payment_routes(env, merchant_id);
+    if routes.is_empty() {
+        merchant_account::credit_merchant(
+            env,
+            merchant_id,
+            merchant_address,
+            Some(invoice_id),
+            token,
+            net,
+        );
+        return;
+    }
+
+    for (route, amount) in routes.iter().zip(route_amounts(env, &routes, net).iter()) {
+        if amount == 0 {
+            continue;
+        }
+        custody::send(env, token, &route.recipient, amount);
+        events::publish_payment_routed_event(
+            env,
+            invoice_id,
+            route.recipient.clone(),
+            token.clone(),
+            amount,
+        );
+    }
+}
+
+// Where `pay_merchant` would send `net`, without moving anything.
+pub fn preview_payout(
+    env: &Env,
+    merchant_id: u64,
+    merchant_address: &Address,
+    token: &Address,
+    net: i128,
+) -> Vec<PayoutSplit> {
+    let net = net - refund::get_merchant_debt(env, merchant_id, token).clamp(0, net);
+    let routes = get_payment_routes(env, merchant_id);
+    if routes.is_empty() {
+        let recipient = merchant_account::get_merchant_account(env, merchant_id)
+            .unwrap_or_else(|| merchant_address.clone());
+        return Vec::from_array(
+            env,
+            [PayoutSplit {
+                recipient,
+                amount: net,
+            }],
+        );
+    }
+
+    let mut splits = Vec::new(env);
+    for (route, amount) in routes.iter().zip(route_amounts(env, &routes, net).iter()) {
+        if amount != 0 {
+            splits.push_back(PayoutSplit {
+                recipient: route.recipient,
+                amount,
+            });
+        }
+    }
+    splits
+}
+
+fn route_amounts(env: &Env, routes: &Vec<PaymentRoute>, net: i128) -> Vec<i128> {
+    let mut amounts = Vec::new(env);
+    let mut routed = 0;
+    for route in routes.iter() {
+        let amount = net * route.share_bps as i128 / TOTAL_SHARE_BPS as i128;
+        amounts.push_back(amount);
+        routed += amount;
+    }
+    amounts.set(0, amounts.get_unchecked(0) + (net - routed));
+    amounts
+}
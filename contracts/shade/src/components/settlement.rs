@@ -0,0 +1,147 @@
+use crate::components::{access_control, invoice, pagination, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, InvoiceStatus, Role, SettlementBatch, SettlementStatus};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
+
+// Settlement batches are an audit trail for fiat off-ramps. An operator
+// groups a merchant's paid invoices under the anchor's external reference
+// and then records the batch as exported and finally settled. No funds move
+// here; each invoice can only ever belong to one batch.
+
+pub fn create_settlement_batch(
+    env: &Env,
+    operator: &Address,
+    merchant_id: u64,
+    token: &Address,
+    invoice_ids: &Vec<u64>,
+    external_ref: &BytesN<32>,
+) -> u64 {
+    access_control::assert_has_role(env, operator, Role::Operator);
+
+    if invoice_ids.is_empty() {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if invoice_ids.len() > pagination::MAX_PAGE_LIMIT {
+        panic_with_error!(env, ContractError::BatchTooLarge);
+    }
+
+    let batch_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SettlementBatchCount)
+        .unwrap_or(0);
+    let batch_id = batch_count + 1;
+
+    let mut total: i128 = 0;
+    for invoice_id in invoice_ids.iter() {
+        let invoice = invoice::get_invoice(env, invoice_id);
+        if invoice.status != InvoiceStatus::Paid
+            || invoice.merchant_id != merchant_id
+            || invoice.token != *token
+        {
+            panic_with_error!(env, InvoiceError::InvoiceNotSettleable);
+        }
+
+        let key = DataKey::InvoiceSettlementBatch(invoice_id);
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(env, InvoiceError::InvoiceAlreadyBatched);
+        }
+        env.storage().persistent().set(&key, &batch_id);
+        ttl::extend_persistent(env, &key);
+
+        total += invoice.amount;
+    }
+
+    let now = env.ledger().timestamp();
+    let batch = SettlementBatch {
+        id: batch_id,
+        merchant_id,
+        token: token.clone(),
+        invoice_ids: invoice_ids.clone(),
+        total,
+        external_ref: external_ref.clone(),
+        status: SettlementStatus::Pending,
+        created_at: now,
+        updated_at: now,
+    };
+    save_settlement_batch(env, &batch);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SettlementBatchCount, &batch_id);
+
+    events::publish_settlement_batch_created_event(
+        env,
+        batch_id,
+        merchant_id,
+        token.clone(),
+        total,
+        invoice_ids.len(),
+        external_ref.clone(),
+    );
+    batch_id
+}
+
+pub fn mark_settlement_exported(env: &Env, operator: &Address, batch_id: u64) {
+    advance_status(
+        env,
+        operator,
+        batch_id,
+        SettlementStatus::Pending,
+        SettlementStatus::Exported,
+    );
+}
+
+pub fn mark_settlement_settled(env: &Env, operator: &Address, batch_id: u64) {
+    advance_status(
+        env,
+        operator,
+        batch_id,
+        SettlementStatus::Exported,
+        SettlementStatus::Settled,
+    );
+}
+
+pub fn get_settlement_batch(env: &Env, batch_id: u64) -> SettlementBatch {
+    let key = DataKey::SettlementBatch(batch_id);
+    let batch = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, InvoiceError::SettlementBatchNotFound));
+    ttl::extend_persistent(env, &key);
+    batch
+}
+
+pub fn get_invoice_settlement_batch(env: &Env, invoice_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceSettlementBatch(invoice_id))
+}
+
+fn advance_status(
+    env: &Env,
+    operator: &Address,
+    batch_id: u64,
+    from: SettlementStatus,
+    to: SettlementStatus,
+) {
+    access_control::assert_has_role(env, operator, Role::Operator);
+
+    let mut batch = get_settlement_batch(env, batch_id);
+    if batch.status != from {
+        panic_with_error!(env, InvoiceError::InvalidSettlementStatus);
+    }
+
+    batch.status = to;
+    batch.updated_at = env.ledger().timestamp();
+    save_settlement_batch(env, &batch);
+
+    events::publish_settlement_batch_status_event(env, batch_id, to, batch.updated_at);
+}
+
+fn save_settlement_batch(env: &Env, batch: &SettlementBatch) {
+    let key = DataKey::SettlementBatch(batch.id);
+    env.storage().persistent().set(&key, batch);
+    ttl::extend_persistent(env, &key);
+}
@@ -0,0 +1,66 @@
+use crate::components::{admin, core, ttl};
+use crate::types::{DataKey, InvoiceStatus, ProtocolStats, TokenStats};
+use soroban_sdk::{Address, Env, Vec};
+
+// Protocol totals are kept as running counters updated alongside the state
+// they describe, so reading them never scans invoices. Counters start from
+// the release that introduced them; earlier activity is not back-filled.
+
+pub fn record_invoice_status(env: &Env, from: Option<InvoiceStatus>, to: InvoiceStatus) {
+    if let Some(from) = from {
+        let key = DataKey::InvoiceStatusCount(from as u32);
+        let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&key, &count.saturating_sub(1));
+        ttl::extend_persistent(env, &key);
+    }
+
+    let key = DataKey::InvoiceStatusCount(to as u32);
+    let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(count + 1));
+    ttl::extend_persistent(env, &key);
+}
+
+// Called wherever escrowed funds are paid out to a merchant, with the gross
+// amount before the protocol fee is taken.
+pub fn record_volume(env: &Env, token: &Address, gross: i128) {
+    let key = DataKey::GrossVolume(token.clone());
+    let volume = get_gross_volume(env, token);
+    env.storage().persistent().set(&key, &(volume + gross));
+}
+
+pub fn get_gross_volume(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GrossVolume(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_protocol_stats(env: &Env) -> ProtocolStats {
+    let counts = core::get_counts(env);
+
+    let mut tokens = Vec::new(env);
+    for token in admin::get_accepted_tokens(env).iter() {
+        tokens.push_back(TokenStats {
+            gross_volume: get_gross_volume(env, &token),
+            fees: admin::get_collected_fees(env, &token),
+            token,
+        });
+    }
+
+    ProtocolStats {
+        merchants: counts.merchants,
+        invoices: counts.invoices,
+        pending_invoices: get_invoice_status_count(env, InvoiceStatus::Pending),
+        paid_invoices: get_invoice_status_count(env, InvoiceStatus::Paid),
+        cancelled_invoices: get_invoice_status_count(env, InvoiceStatus::Cancelled),
+        refunded_invoices: get_invoice_status_count(env, InvoiceStatus::Refunded),
+        overdue_invoices: get_invoice_status_count(env, InvoiceStatus::Overdue),
+        tokens,
+    }
+}
+
+fn get_invoice_status_count(env: &Env, status: InvoiceStatus) -> u64 {
+    ttl::get_persistent(env, &DataKey::InvoiceStatusCount(status as u32)).unwrap_or(0)
+}
@@ -0,0 +1,223 @@
+use crate::components::{
+    activity, admin, blocklist, custody, merchant, merchant_account, reentrancy, stats, ttl,
+    velocity,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{ActivityKind, DataKey, Stream, StreamStatus};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// A stream escrows the payer's full deposit up front and releases it to the
+// merchant linearly between `start_time` and `end_time`. Protocol fees are
+// taken from each payout, never from the payer's refund.
+
+pub fn create_stream(
+    env: &Env,
+    payer: &Address,
+    merchant_id: u64,
+    token: &Address,
+    amount: i128,
+    start_time: u64,
+    end_time: u64,
+) -> u64 {
+    reentrancy::enter(env);
+    payer.require_auth();
+    blocklist::assert_not_blocked(env, payer);
+
+    if amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+    if end_time <= start_time || start_time < env.ledger().timestamp() {
+        panic_with_error!(env, ContractError::InvalidStreamSchedule);
+    }
+    if !admin::is_accepted_token(env, token) {
+        panic_with_error!(env, ContractError::TokenNotAccepted);
+    }
+    merchant::get_merchant(env, merchant_id);
+
+    velocity::record_payment(env, payer, token, amount);
+    custody::receive(env, token, payer, amount);
+
+    let stream_count: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::StreamCount)
+        .unwrap_or(0);
+    let stream_id = stream_count + 1;
+
+    let stream = Stream {
+        id: stream_id,
+        payer: payer.clone(),
+        merchant_id,
+        token: token.clone(),
+        amount,
+        withdrawn: 0,
+        start_time,
+        end_time,
+        status: StreamStatus::Active,
+    };
+    save_stream(env, &stream);
+    env.storage()
+        .persistent()
+        .set(&DataKey::StreamCount, &stream_id);
+
+    events::publish_stream_created_event(
+        env,
+        stream_id,
+        merchant_id,
+        payer.clone(),
+        token.clone(),
+        amount,
+        start_time,
+        end_time,
+    );
+    reentrancy::exit(env);
+    stream_id
+}
+
+pub fn withdraw_from_stream(env: &Env, merchant_address: &Address, stream_id: u64) -> i128 {
+    reentrancy::enter(env);
+    merchant_address.require_auth();
+
+    let mut stream = get_stream(env, stream_id);
+    assert_stream_merchant(env, &stream, merchant_address);
+    if stream.status != StreamStatus::Active {
+        panic_with_error!(env, ContractError::StreamNotActive);
+    }
+
+    let vested = vested_amount(env, &stream);
+    let payout = vested - stream.withdrawn;
+    stream.withdrawn = vested;
+    if stream.withdrawn == stream.amount {
+        stream.status = StreamStatus::Completed;
+    }
+    save_stream(env, &stream);
+
+    let net = pay_merchant(env, &stream, merchant_address, payout);
+    reentrancy::exit(env);
+    net
+}
+
+// Either party may stop the stream: whatever has vested goes to the
+// merchant and the unvested remainder returns to the payer.
+pub fn cancel_stream(env: &Env, caller: &Address, stream_id: u64) {
+    reentrancy::enter(env);
+    caller.require_auth();
+
+    let mut stream = get_stream(env, stream_id);
+    let merchant_address = merchant::get_merchant(env, stream.merchant_id).address;
+    if *caller != stream.payer && *caller != merchant_address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+    if stream.status != StreamStatus::Active {
+        panic_with_error!(env, ContractError::StreamNotActive);
+    }
+
+    let vested = vested_amount(env, &stream);
+    let payout = vested - stream.withdrawn;
+    let refund = stream.amount - vested;
+    stream.withdrawn = vested;
+    stream.status = StreamStatus::Cancelled;
+    save_stream(env, &stream);
+
+    pay_merchant(env, &stream, &merchant_address, payout);
+    if refund > 0 {
+        custody::send(env, &stream.token, &stream.payer, refund);
+    }
+
+    events::publish_stream_cancelled_event(
+        env,
+        stream_id,
+        caller.clone(),
+        refund,
+        env.ledger().timestamp(),
+    );
+    reentrancy::exit(env);
+}
+
+pub fn get_stream(env: &Env, stream_id: u64) -> Stream {
+    let key = DataKey::Stream(stream_id);
+    let stream = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::StreamNotFound));
+    ttl::extend_persistent(env, &key);
+    stream
+}
+
+pub fn get_withdrawable_amount(env: &Env, stream_id: u64) -> i128 {
+    let stream = get_stream(env, stream_id);
+    if stream.status != StreamStatus::Active {
+        return 0;
+    }
+    vested_amount(env, &stream) - stream.withdrawn
+}
+
+fn vested_amount(env: &Env, stream: &Stream) -> i128 {
+    let now = env.ledger().timestamp();
+    if now <= stream.start_time {
+        return 0;
+    }
+    if now >= stream.end_time {
+        return stream.amount;
+    }
+
+    let elapsed = (now - stream.start_time) as i128;
+    let duration = (stream.end_time - stream.start_time) as i128;
+    stream.amount * elapsed / duration
+}
+
+fn pay_merchant(env: &Env, stream: &Stream, merchant_address: &Address, payout: i128) -> i128 {
+    if payout == 0 {
+        return 0;
+    }
+
+    let fee = admin::calculate_fee(
+        env,
+        &stream.token,
+        payout,
+        &[&stream.payer, merchant_address],
+    );
+    let net = payout - fee;
+    admin::collect_fee(env, &stream.token, fee);
+    stats::record_volume(env, &stream.token, payout);
+    activity::record_activity(
+        env,
+        ActivityKind::StreamCharge,
+        stream.id,
+        &stream.token,
+        payout,
+    );
+    merchant_account::credit_merchant(
+        env,
+        stream.merchant_id,
+        merchant_address,
+        None,
+        &stream.token,
+        net,
+    );
+
+    events::publish_stream_withdrawn_event(
+        env,
+        stream.id,
+        stream.merchant_id,
+        net,
+        fee,
+        env.ledger().timestamp(),
+    );
+    net
+}
+
+fn assert_stream_merchant(env: &Env, stream: &Stream, merchant_address: &Address) {
+    let merchant = merchant::get_merchant(env, stream.merchant_id);
+    if merchant.address != *merchant_address {
+        panic_with_error!(env, ContractError::NotAuthorized);
+    }
+}
+
+fn save_stream(env: &Env, stream: &Stream) {
+    let key = DataKey::Stream(stream.id);
+    env.storage().persistent().set(&key, stream);
+    ttl::extend_persistent(env, &key);
+}
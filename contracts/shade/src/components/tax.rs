@@ -0,0 +1,65 @@
+use crate::components::{admin, custody, invoice, merchant, ttl};
+use crate::errors::{ContractError, InvoiceError};
+use crate::events;
+use crate::types::{DataKey, Invoice, InvoiceStatus, InvoiceTax};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Invoice amounts are tax-inclusive. On settlement the tax portion of the
+// principal goes straight to the tax recipient, the protocol fee is charged
+// on what remains and the merchant receives the rest.
+
+pub fn set_invoice_tax(env: &Env, caller: &Address, invoice_id: u64, tax: Option<InvoiceTax>) {
+    caller.require_auth();
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    merchant::assert_invoice_operator(env, invoice.merchant_id, caller);
+    if invoice.status != InvoiceStatus::Pending {
+        panic_with_error!(env, ContractError::InvoiceNotPending);
+    }
+
+    let key = DataKey::InvoiceTax(invoice_id);
+    match tax {
+        Some(tax) => {
+            if tax.tax_bps == 0
+                || tax.tax_bps as i128 > admin::FEE_DENOMINATOR
+                || tax.tax_recipient == env.current_contract_address()
+            {
+                panic_with_error!(env, InvoiceError::InvalidTaxConfig);
+            }
+            env.storage().persistent().set(&key, &tax);
+            ttl::extend_persistent(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_invoice_tax(env: &Env, invoice_id: u64) -> Option<InvoiceTax> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceTax(invoice_id))
+}
+
+// Late fees are not taxed; only the principal carries the tax line.
+pub fn tax_amount(env: &Env, invoice: &Invoice) -> i128 {
+    get_invoice_tax(env, invoice.id)
+        .map(|tax| invoice.amount * tax.tax_bps as i128 / admin::FEE_DENOMINATOR)
+        .unwrap_or(0)
+}
+
+pub fn pay_tax(env: &Env, invoice: &Invoice, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    let Some(tax) = get_invoice_tax(env, invoice.id) else {
+        return;
+    };
+
+    custody::send(env, &invoice.token, &tax.tax_recipient, amount);
+    events::publish_tax_collected_event(
+        env,
+        invoice.id,
+        tax.tax_recipient,
+        invoice.token.clone(),
+        amount,
+    );
+}
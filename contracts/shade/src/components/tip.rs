@@ -0,0 +1,97 @@
+use crate::components::{
+    admin, blocklist, core, custody, invoice, merchant, merchant_account, reentrancy, stats, ttl,
+    velocity,
+};
+use crate::errors::ContractError;
+use crate::events;
+use crate::types::{DataKey, Invoice};
+use soroban_sdk::{panic_with_error, Address, Env};
+
+// Pays an invoice and adds a tip on top. The invoice settles as usual; the
+// tip bypasses payout routing and goes to the merchant's linked account,
+// or the merchant address when none is linked. Tips carry the protocol fee
+// unless the admin has exempted them. A zero tip is allowed and settles the
+// invoice like a plain payment. The linked account must accept payouts up
+// front, since escrowed invoices would not otherwise reach that check.
+pub fn pay_invoice_with_tip(
+    env: &Env,
+    payer: &Address,
+    invoice_id: u64,
+    tip_amount: i128,
+) -> Invoice {
+    reentrancy::enter(env);
+    if tip_amount < 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let invoice = invoice::get_invoice(env, invoice_id);
+    let total = invoice::amount_due(env, &invoice) + tip_amount;
+    core::require_payment_auth(env, payer, invoice_id, &invoice.token, total);
+    blocklist::assert_not_blocked(env, payer);
+    invoice::assert_payable(env, &invoice);
+    merchant_account::assert_accepts_payouts(env, invoice.merchant_id);
+
+    velocity::record_payment(env, payer, &invoice.token, total);
+    custody::receive(env, &invoice.token, payer, total);
+    let invoice = invoice::settle_from_escrow(env, &invoice, payer);
+
+    let mut fee = 0;
+    if tip_amount > 0 {
+        let merchant_address = merchant::get_merchant(env, invoice.merchant_id).address;
+        if !tips_fee_exempt(env) {
+            fee =
+                admin::calculate_fee(env, &invoice.token, tip_amount, &[payer, &merchant_address]);
+        }
+        admin::collect_fee(env, &invoice.token, fee);
+        stats::record_volume(env, &invoice.token, tip_amount);
+
+        merchant_account::credit_merchant(
+            env,
+            invoice.merchant_id,
+            &merchant_address,
+            None,
+            &invoice.token,
+            tip_amount - fee,
+        );
+
+        let key = DataKey::InvoiceTip(invoice_id);
+        env.storage().persistent().set(&key, &tip_amount);
+        ttl::extend_persistent(env, &key);
+    }
+
+    events::publish_invoice_tip_paid_event(
+        env,
+        invoice_id,
+        payer.clone(),
+        invoice.token.clone(),
+        tip_amount,
+        fee,
+    );
+    reentrancy::exit(env);
+    invoice
+}
+
+pub fn get_invoice_tip(env: &Env, invoice_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InvoiceTip(invoice_id))
+        .unwrap_or(0)
+}
+
+pub fn set_tips_fee_exempt(env: &Env, admin: &Address, exempt: bool) {
+    core::assert_admin(env, admin);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::TipsFeeExempt, &exempt);
+    ttl::extend_persistent(env, &DataKey::TipsFeeExempt);
+
+    events::publish_tip_fee_policy_set_event(env, exempt, env.ledger().timestamp());
+}
+
+pub fn tips_fee_exempt(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TipsFeeExempt)
+        .unwrap_or(false)
+}
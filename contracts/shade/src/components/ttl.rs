@@ -0,0 +1,108 @@
+use crate::components::core;
+use crate::events;
+use crate::types::{DataKey, Merchant, StorageKey};
+use soroban_sdk::{vec, Address, Env, TryFromVal, Val, Vec};
+
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_persistent(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+}
+
+// Reads an entry that every flow depends on (admin, contract info, counters,
+// index buckets) and extends it, so read-mostly entries never lapse.
+pub fn get_persistent<V>(env: &Env, key: &DataKey) -> Option<V>
+where
+    V: TryFromVal<Env, Val>,
+{
+    let value = env.storage().persistent().get(key);
+    extend_persistent(env, key);
+    value
+}
+
+pub fn bump_storage(env: &Env, admin: &Address, keys: Vec<StorageKey>) {
+    core::assert_admin(env, admin);
+
+    for key in keys.iter() {
+        for data_key in data_keys(env, &key).iter() {
+            extend_persistent(env, &data_key);
+        }
+    }
+    env.storage()
+        .instance()
+        .extend_ttl(PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+    events::publish_storage_bumped_event(env, keys.len(), env.ledger().timestamp());
+}
+
+fn data_keys(env: &Env, key: &StorageKey) -> Vec<DataKey> {
+    match key {
+        StorageKey::Protocol => vec![
+            env,
+            DataKey::Admin,
+            DataKey::ContractInfo,
+            DataKey::SchemaVersion,
+            DataKey::LegacyEventFormat,
+            DataKey::WasmHash,
+            DataKey::UpgradeHistory,
+            DataKey::AcceptedTokens,
+            DataKey::MerchantCount,
+            DataKey::InvoiceCount,
+            DataKey::StreamCount,
+            DataKey::GiftCardCount,
+            DataKey::PaymentLinkCount,
+            DataKey::RecurringScheduleCount,
+        ],
+        StorageKey::Token(token) => vec![
+            env,
+            DataKey::TokenFee(token.clone()),
+            DataKey::TokenMetadata(token.clone()),
+            DataKey::CollectedFees(token.clone()),
+            DataKey::CustodyBalance(token.clone()),
+        ],
+        StorageKey::Merchant(merchant_id) => {
+            let mut keys = vec![
+                env,
+                DataKey::Merchant(*merchant_id),
+                DataKey::MerchantInvoiceCount(*merchant_id),
+            ];
+            let merchant: Option<Merchant> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Merchant(*merchant_id));
+            if let Some(merchant) = merchant {
+                keys.push_back(DataKey::MerchantId(merchant.address));
+            }
+            keys
+        }
+        StorageKey::MerchantInvoices(merchant_id, bucket) => {
+            vec![env, DataKey::MerchantInvoices(*merchant_id, *bucket)]
+        }
+        StorageKey::PayerInvoices(payer, bucket) => vec![
+            env,
+            DataKey::PayerInvoices(payer.clone(), *bucket),
+            DataKey::PayerInvoiceCount(payer.clone()),
+        ],
+        StorageKey::Invoice(invoice_id) => vec![env, DataKey::Invoice(*invoice_id)],
+        StorageKey::InvoiceStatusIndex(status, bucket) => vec![
+            env,
+            DataKey::InvoicesByStatus(*status as u32, *bucket),
+            DataKey::InvoiceStatusCount(*status as u32),
+        ],
+        StorageKey::Stream(stream_id) => vec![env, DataKey::Stream(*stream_id)],
+        StorageKey::GiftCard(card_id) => vec![env, DataKey::GiftCard(*card_id)],
+        StorageKey::PaymentLink(link_id) => vec![env, DataKey::PaymentLink(*link_id)],
+        StorageKey::RecurringSchedule(schedule_id) => {
+            vec![env, DataKey::RecurringSchedule(*schedule_id)]
+        }
+        StorageKey::Customer(customer) => vec![env, DataKey::CustomerProfile(customer.clone())],
+    }
+}
@@ -1,13 +1,163 @@
-use crate::components::core;
+use crate::components::{core, migration};
+use crate::errors::{ContractError, GovernanceError};
 use crate::events;
-use soroban_sdk::{BytesN, Env};
+use crate::types::{DataKey, PendingUpgrade, UpgradeRecord};
+use soroban_sdk::{panic_with_error, Address, BytesN, Env, Vec};
 
-pub fn upgrade(env: &Env, new_wasm_hash: &BytesN<32>) {
+pub const UPGRADE_TIMELOCK: u64 = 2 * 24 * 60 * 60;
+pub const ROLLBACK_WINDOW: u64 = 7 * 24 * 60 * 60;
+pub const MAX_UPGRADE_HISTORY: u32 = 10;
+
+// The host does not expose the running wasm hash, so the admin records the
+// hash of the initial deployment once. It seeds the upgrade history, which
+// makes the first upgrade reversible by `rollback`.
+pub fn set_deployed_wasm_hash(env: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    core::assert_admin(env, admin);
+
+    if env.storage().persistent().has(&DataKey::WasmHash) {
+        panic_with_error!(env, GovernanceError::DeployedWasmAlreadyRecorded);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::WasmHash, wasm_hash);
+    record_upgrade(env, wasm_hash);
+
+    events::publish_deployed_wasm_recorded_event(env, wasm_hash.clone(), env.ledger().timestamp());
+}
+
+pub fn propose_upgrade(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>) {
+    core::assert_admin(env, admin);
+
+    if !env.storage().persistent().has(&DataKey::WasmHash) {
+        panic_with_error!(env, GovernanceError::DeployedWasmUnknown);
+    }
+
+    if get_pending_upgrade(env).is_some() {
+        panic_with_error!(env, ContractError::UpgradeAlreadyProposed);
+    }
+
+    let proposed_at = env.ledger().timestamp();
+    let pending = PendingUpgrade {
+        wasm_hash: new_wasm_hash.clone(),
+        proposed_at,
+        executable_at: proposed_at + UPGRADE_TIMELOCK,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingUpgrade, &pending);
+
+    events::publish_upgrade_proposed_event(
+        env,
+        new_wasm_hash.clone(),
+        pending.executable_at,
+        proposed_at,
+    );
+}
+
+pub fn execute_upgrade(env: &Env) {
     let admin = core::get_admin(env);
     core::assert_admin(env, &admin);
 
+    let pending = get_pending_upgrade(env)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NoUpgradeProposed));
+    if env.ledger().timestamp() < pending.executable_at {
+        panic_with_error!(env, ContractError::UpgradeTimelockActive);
+    }
+
+    env.storage().persistent().remove(&DataKey::PendingUpgrade);
+    apply_upgrade(env, &pending.wasm_hash);
+}
+
+pub fn cancel_upgrade(env: &Env, admin: &Address) {
+    core::assert_admin(env, admin);
+
+    let pending = get_pending_upgrade(env)
+        .unwrap_or_else(|| panic_with_error!(env, ContractError::NoUpgradeProposed));
+    env.storage().persistent().remove(&DataKey::PendingUpgrade);
+
+    events::publish_upgrade_cancelled_event(env, pending.wasm_hash, env.ledger().timestamp());
+}
+
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().persistent().get(&DataKey::PendingUpgrade)
+}
+
+// Re-applies the previously upgraded-to hash without the timelock, as an
+// escape hatch for a broken release. Only allowed within ROLLBACK_WINDOW of
+// the latest upgrade, and only back to a hash this contract already ran.
+// A migration run since the latest upgrade has rewritten storage into a
+// layout the previous code cannot read, so it blocks the rollback.
+pub fn rollback(env: &Env, admin: &Address) {
+    core::assert_admin(env, admin);
+
+    let mut history = get_upgrade_history(env);
+    if history.len() < 2 {
+        panic_with_error!(env, ContractError::NoRollbackTarget);
+    }
+
+    let latest = history.pop_back().unwrap();
+    if env.ledger().timestamp() > latest.applied_at + ROLLBACK_WINDOW {
+        panic_with_error!(env, ContractError::RollbackWindowExpired);
+    }
+    if migration::get_schema_version(env) != latest.schema_version {
+        panic_with_error!(env, GovernanceError::RollbackAcrossMigration);
+    }
+    let previous = history.last().unwrap();
+
+    env.deployer()
+        .update_current_contract_wasm(previous.wasm_hash.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::WasmHash, &previous.wasm_hash);
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeHistory, &history);
+
+    events::publish_contract_rolled_back_event(
+        env,
+        latest.wasm_hash,
+        previous.wasm_hash,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn get_upgrade_history(env: &Env) -> Vec<UpgradeRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UpgradeHistory)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn record_upgrade(env: &Env, wasm_hash: &BytesN<32>) {
+    let mut history = get_upgrade_history(env);
+    if history.len() >= MAX_UPGRADE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(UpgradeRecord {
+        wasm_hash: wasm_hash.clone(),
+        applied_at: env.ledger().timestamp(),
+        schema_version: migration::get_schema_version(env),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeHistory, &history);
+}
+
+fn apply_upgrade(env: &Env, new_wasm_hash: &BytesN<32>) {
+    let old_wasm_hash: Option<BytesN<32>> = env.storage().persistent().get(&DataKey::WasmHash);
+
     env.deployer()
         .update_current_contract_wasm(new_wasm_hash.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::WasmHash, new_wasm_hash);
+    record_upgrade(env, new_wasm_hash);
 
-    events::publish_contract_upgraded_event(env, new_wasm_hash.clone(), env.ledger().timestamp());
+    events::publish_contract_upgraded_event(
+        env,
+        old_wasm_hash,
+        new_wasm_hash.clone(),
+        env.ledger().timestamp(),
+    );
 }
@@ -0,0 +1,104 @@
+use crate::components::{core, ttl};
+use crate::errors::{ComplianceError, ContractError};
+use crate::events;
+use crate::types::{DataKey, VelocityLimit};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+// Caps how much one payer can move through the protocol per token over a
+// rolling window. Each payer keeps a list of its own recent payments, pruned
+// on every write, so the list only grows with that payer's activity inside
+// the window.
+
+pub fn set_velocity_limit(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    max_amount: i128,
+    window: u64,
+) {
+    core::assert_admin(env, admin);
+
+    if max_amount <= 0 || window == 0 {
+        panic_with_error!(env, ContractError::InvalidAmount);
+    }
+
+    let key = DataKey::VelocityLimit(token.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &VelocityLimit { max_amount, window });
+    ttl::extend_persistent(env, &key);
+
+    events::publish_velocity_limit_set_event(
+        env,
+        token.clone(),
+        max_amount,
+        window,
+        env.ledger().timestamp(),
+    );
+}
+
+pub fn remove_velocity_limit(env: &Env, admin: &Address, token: &Address) {
+    core::assert_admin(env, admin);
+
+    let key = DataKey::VelocityLimit(token.clone());
+    if env.storage().persistent().has(&key) {
+        env.storage().persistent().remove(&key);
+        events::publish_velocity_limit_removed_event(env, token.clone(), env.ledger().timestamp());
+    }
+}
+
+pub fn get_velocity_limit(env: &Env, token: &Address) -> Option<VelocityLimit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VelocityLimit(token.clone()))
+}
+
+pub fn get_payer_volume(env: &Env, payer: &Address, token: &Address) -> i128 {
+    match get_velocity_limit(env, token) {
+        Some(limit) => sum(&recent_payments(env, payer, token, limit.window)),
+        None => 0,
+    }
+}
+
+// Called from every path that pulls funds from a payer, and from merchant
+// withdrawals, before the transfer.
+pub fn record_payment(env: &Env, payer: &Address, token: &Address, amount: i128) {
+    let Some(limit) = get_velocity_limit(env, token) else {
+        return;
+    };
+
+    let mut payments = recent_payments(env, payer, token, limit.window);
+    if sum(&payments) + amount > limit.max_amount {
+        panic_with_error!(env, ComplianceError::LimitExceeded);
+    }
+    payments.push_back((env.ledger().timestamp(), amount));
+
+    let key = DataKey::PayerVolume(payer.clone(), token.clone());
+    env.storage().persistent().set(&key, &payments);
+    ttl::extend_persistent(env, &key);
+}
+
+fn recent_payments(env: &Env, payer: &Address, token: &Address, window: u64) -> Vec<(u64, i128)> {
+    let payments: Vec<(u64, i128)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PayerVolume(payer.clone(), token.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let now = env.ledger().timestamp();
+    let mut recent = Vec::new(env);
+    for (timestamp, amount) in payments.iter() {
+        if timestamp + window > now {
+            recent.push_back((timestamp, amount));
+        }
+    }
+    recent
+}
+
+fn sum(payments: &Vec<(u64, i128)>) -> i128 {
+    let mut total = 0;
+    for (_, amount) in payments.iter() {
+        total += amount;
+    }
+    total
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::contracterror;
 
+// The contract spec caps an error enum at 50 cases, so errors beyond the
+// original set are grouped by feature. Codes are unique across all enums.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -16,4 +18,120 @@ pub enum ContractError {
     ContractNotPaused = 10,
     MerchantKeyNotFound = 11,
     TokenNotAccepted = 12,
+    BatchTooLarge = 13,
+    InvalidSchemaVersion = 14,
+    UpgradeAlreadyProposed = 15,
+    NoUpgradeProposed = 16,
+    UpgradeTimelockActive = 17,
+    NoRollbackTarget = 18,
+    RollbackWindowExpired = 19,
+    OracleNotConfigured = 20,
+    OraclePriceUnavailable = 21,
+    OraclePriceStale = 22,
+    ContractNotTrusted = 23,
+    StreamNotFound = 24,
+    InvalidStreamSchedule = 25,
+    StreamNotActive = 26,
+    GiftCardNotFound = 27,
+    GiftCardNotActive = 28,
+    GiftCardExpired = 29,
+    GiftCardNotExpired = 30,
+    InvalidClaimSecret = 31,
+    GiftCardNotApplicable = 32,
+    InsufficientGiftCardBalance = 33,
+    InvoiceNotPending = 34,
+    PaymentLinkNotFound = 35,
+    PaymentLinkNotOpen = 36,
+    PaymentLinkExpired = 37,
+    PaymentLinkNotExpired = 38,
+    PaymentLinkNotApplicable = 39,
+    CampaignNotFound = 40,
+    InvalidCampaignDeadline = 41,
+    CampaignClosed = 42,
+    CampaignStillActive = 43,
+    CampaignNotRefundable = 44,
+    NothingToRefund = 45,
+    InvalidToken = 46,
+    InvalidPaymentRoutes = 69,
+}
+
+// Payer screening, limits, merchant onboarding and asset restrictions.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ComplianceError {
+    PayerBlocked = 51,
+    LimitExceeded = 52,
+    RateLimited = 53,
+    MerchantNotApproved = 54,
+    BondNotFound = 55,
+    AmountOutOfBounds = 56,
+    AssetFrozen = 72,
+    TransferBlocked = 73,
+    ClawbackAssetRejected = 74,
+    BondLocked = 100,
+}
+
+// Invoice lifecycle, billing terms, refunds and settlement batches.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum InvoiceError {
+    SettlementBatchNotFound = 47,
+    InvoiceNotSettleable = 48,
+    InvoiceAlreadyBatched = 49,
+    InvalidSettlementStatus = 50,
+    InvoiceExpired = 57,
+    InvoiceAwaitingApproval = 70,
+    InvoiceNotAwaitingApproval = 71,
+    InvalidLineItems = 75,
+    InvalidInvoiceMetadata = 76,
+    InvalidDueDate = 77,
+    InvoiceNotOverdue = 78,
+    InvalidLateFeePolicy = 79,
+    InvalidTaxConfig = 80,
+    InvoiceHasContributions = 81,
+    NoContribution = 82,
+    InvalidPayerAllowlist = 83,
+    InvoiceNotPaid = 84,
+    InsufficientCollectedFees = 85,
+    InvoiceNotRefundable = 86,
+    InvalidEscrowPeriod = 87,
+    EscrowNotHeld = 88,
+    EscrowLocked = 89,
+    InvalidMilestones = 90,
+    InvalidMilestoneState = 91,
+    InvalidRecurringSchedule = 92,
+    RecurringScheduleNotFound = 93,
+    InvalidAmendment = 94,
+    RefundWindowOpen = 99,
+}
+
+// Fee distribution, council governance and treasury operations.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GovernanceError {
+    InvalidDistributionWeights = 58,
+    NothingToClaim = 59,
+    NotCouncilMember = 60,
+    ProposalNotFound = 61,
+    AlreadyVoted = 62,
+    ProposalNotExecutable = 63,
+    InsufficientRecoverableBalance = 68,
+    RollbackAcrossMigration = 96,
+    DeployedWasmUnknown = 97,
+    DeployedWasmAlreadyRecorded = 98,
+}
+
+// Merchant accounts, payout routing and customer profiles.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AccountError {
+    InvalidMerchantAccount = 64,
+    MerchantAccountNotLinked = 65,
+    MerchantAccountRestricted = 66,
+    CustomerNotFound = 67,
+    MerchantAccountPaused = 95,
 }
@@ -1,3 +1,8 @@
+use crate::components::admin;
+use crate::types::{
+    AmountBounds, CampaignStatus, InvoiceRateLimit, MerchantBond, MilestoneStatus,
+    OverpaymentPolicy, ParameterChange, ProposalStatus, SettlementStatus,
+};
 use soroban_sdk::{contractevent, Address, BytesN, Env};
 
 #[contractevent]
@@ -12,215 +17,2351 @@ pub fn publish_initialized_event(env: &Env, admin: Address, timestamp: u64) {
 
 #[contractevent]
 pub struct TokenAddedEvent {
+    #[topic]
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["token_added_event"])]
+pub struct LegacyTokenAddedEvent {
     pub token: Address,
     pub timestamp: u64,
 }
 
 pub fn publish_token_added_event(env: &Env, token: Address, timestamp: u64) {
-    TokenAddedEvent { token, timestamp }.publish(env);
+    if admin::is_legacy_event_format(env) {
+        LegacyTokenAddedEvent { token, timestamp }.publish(env);
+    } else {
+        TokenAddedEvent { token, timestamp }.publish(env);
+    }
+}
+
+#[contractevent]
+pub struct TokenRemovedEvent {
+    #[topic]
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["token_removed_event"])]
+pub struct LegacyTokenRemovedEvent {
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_token_removed_event(env: &Env, token: Address, timestamp: u64) {
+    if admin::is_legacy_event_format(env) {
+        LegacyTokenRemovedEvent { token, timestamp }.publish(env);
+    } else {
+        TokenRemovedEvent { token, timestamp }.publish(env);
+    }
+}
+
+#[contractevent]
+pub struct NativeTokenSetEvent {
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_native_token_set_event(env: &Env, token: Address, timestamp: u64) {
+    NativeTokenSetEvent { token, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct TrustedContractAddedEvent {
+    #[topic]
+    pub contract: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_trusted_contract_added_event(env: &Env, contract: Address, timestamp: u64) {
+    TrustedContractAddedEvent {
+        contract,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TrustedContractRemovedEvent {
+    #[topic]
+    pub contract: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_trusted_contract_removed_event(env: &Env, contract: Address, timestamp: u64) {
+    TrustedContractRemovedEvent {
+        contract,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PayerBlockedEvent {
+    #[topic]
+    pub payer: Address,
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_payer_blocked_event(env: &Env, payer: Address, by: Address, timestamp: u64) {
+    PayerBlockedEvent {
+        payer,
+        by,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PayerUnblockedEvent {
+    #[topic]
+    pub payer: Address,
+    pub by: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_payer_unblocked_event(env: &Env, payer: Address, by: Address, timestamp: u64) {
+    PayerUnblockedEvent {
+        payer,
+        by,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct VelocityLimitSetEvent {
+    #[topic]
+    pub token: Address,
+    pub max_amount: i128,
+    pub window: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_velocity_limit_set_event(
+    env: &Env,
+    token: Address,
+    max_amount: i128,
+    window: u64,
+    timestamp: u64,
+) {
+    VelocityLimitSetEvent {
+        token,
+        max_amount,
+        window,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct VelocityLimitRemovedEvent {
+    #[topic]
+    pub token: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_velocity_limit_removed_event(env: &Env, token: Address, timestamp: u64) {
+    VelocityLimitRemovedEvent { token, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceRateLimitSetEvent {
+    pub merchant_id: Option<u64>,
+    pub limit: Option<InvoiceRateLimit>,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_rate_limit_set_event(
+    env: &Env,
+    merchant_id: Option<u64>,
+    limit: Option<InvoiceRateLimit>,
+    timestamp: u64,
+) {
+    InvoiceRateLimitSetEvent {
+        merchant_id,
+        limit,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct AmountBoundsSetEvent {
+    #[topic]
+    pub token: Address,
+    pub bounds: Option<AmountBounds>,
+    pub timestamp: u64,
+}
+
+pub fn publish_amount_bounds_set_event(
+    env: &Env,
+    token: Address,
+    bounds: Option<AmountBounds>,
+    timestamp: u64,
+) {
+    AmountBoundsSetEvent {
+        token,
+        bounds,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DefaultInvoiceExpirySetEvent {
+    pub duration: Option<u64>,
+    pub timestamp: u64,
+}
+
+pub fn publish_default_invoice_expiry_set_event(env: &Env, duration: Option<u64>, timestamp: u64) {
+    DefaultInvoiceExpirySetEvent {
+        duration,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DistributionSharesSetEvent {
+    pub recipients: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_distribution_shares_set_event(env: &Env, recipients: u32, timestamp: u64) {
+    DistributionSharesSetEvent {
+        recipients,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeesDistributedEvent {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_fees_distributed_event(env: &Env, token: Address, amount: i128, timestamp: u64) {
+    FeesDistributedEvent {
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DistributionClaimedEvent {
+    #[topic]
+    pub recipient: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_distribution_claimed_event(
+    env: &Env,
+    recipient: Address,
+    token: Address,
+    amount: i128,
+) {
+    DistributionClaimedEvent {
+        recipient,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CouncilSetEvent {
+    pub members: u32,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_council_set_event(env: &Env, members: u32, threshold: u32, timestamp: u64) {
+    CouncilSetEvent {
+        members,
+        threshold,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ProposalCreatedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub proposer: Address,
+    pub change: ParameterChange,
+}
+
+pub fn publish_proposal_created_event(
+    env: &Env,
+    proposal_id: u64,
+    proposer: Address,
+    change: ParameterChange,
+) {
+    ProposalCreatedEvent {
+        proposal_id,
+        proposer,
+        change,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ProposalVotedEvent {
+    #[topic]
+    pub proposal_id: u64,
+    #[topic]
+    pub member: Address,
+    pub approvals: u32,
+    pub executable_at: Option<u64>,
+}
+
+pub fn publish_proposal_voted_event(
+    env: &Env,
+    proposal_id: u64,
+    member: Address,
+    approvals: u32,
+    executable_at: Option<u64>,
+) {
+    ProposalVotedEvent {
+        proposal_id,
+        member,
+        approvals,
+        executable_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ProposalStatusEvent {
+    #[topic]
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    pub timestamp: u64,
+}
+
+pub fn publish_proposal_status_event(
+    env: &Env,
+    proposal_id: u64,
+    status: ProposalStatus,
+    timestamp: u64,
+) {
+    ProposalStatusEvent {
+        proposal_id,
+        status,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct OracleSetEvent {
+    pub oracle: Address,
+    pub max_price_age: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_oracle_set_event(env: &Env, oracle: Address, max_price_age: u64, timestamp: u64) {
+    OracleSetEvent {
+        oracle,
+        max_price_age,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantRegisteredEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub merchant: Address,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["merchant_registered_event"])]
+pub struct LegacyMerchantRegisteredEvent {
+    pub merchant: Address,
+    pub merchant_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_registered_event(
+    env: &Env,
+    merchant: Address,
+    merchant_id: u64,
+    timestamp: u64,
+) {
+    if admin::is_legacy_event_format(env) {
+        LegacyMerchantRegisteredEvent {
+            merchant,
+            merchant_id,
+            timestamp,
+        }
+        .publish(env);
+    } else {
+        MerchantRegisteredEvent {
+            merchant_id,
+            merchant,
+            timestamp,
+        }
+        .publish(env);
+    }
+}
+
+#[contractevent]
+pub struct MerchantStatusChangedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub active: bool,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["merchant_status_changed_event"])]
+pub struct LegacyMerchantStatusChangedEvent {
+    pub merchant_id: u64,
+    pub active: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_status_changed_event(
+    env: &Env,
+    merchant_id: u64,
+    active: bool,
+    timestamp: u64,
+) {
+    if admin::is_legacy_event_format(env) {
+        LegacyMerchantStatusChangedEvent {
+            merchant_id,
+            active,
+            timestamp,
+        }
+        .publish(env);
+    } else {
+        MerchantStatusChangedEvent {
+            merchant_id,
+            active,
+            timestamp,
+        }
+        .publish(env);
+    }
+}
+
+#[contractevent]
+pub struct InvoiceCreatedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub merchant: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["invoice_created_event"])]
+pub struct LegacyInvoiceCreatedEvent {
+    pub invoice_id: u64,
+    pub merchant: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
+pub fn publish_invoice_created_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant: Address,
+    amount: i128,
+    token: Address,
+) {
+    if admin::is_legacy_event_format(env) {
+        LegacyInvoiceCreatedEvent {
+            invoice_id,
+            merchant,
+            amount,
+            token,
+        }
+        .publish(env);
+    } else {
+        InvoiceCreatedEvent {
+            invoice_id,
+            merchant,
+            token,
+            amount,
+        }
+        .publish(env);
+    }
+}
+
+#[contractevent]
+pub struct InvoicePaidOnBehalfEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub funder: Address,
+    pub payer: Address,
+    pub amount: i128,
+}
+
+pub fn publish_invoice_paid_on_behalf_event(
+    env: &Env,
+    invoice_id: u64,
+    funder: Address,
+    payer: Address,
+    amount: i128,
+) {
+    InvoicePaidOnBehalfEvent {
+        invoice_id,
+        funder,
+        payer,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceDescriptionHashEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub description_hash: BytesN<32>,
+}
+
+pub fn publish_invoice_description_hash_event(
+    env: &Env,
+    invoice_id: u64,
+    description_hash: BytesN<32>,
+) {
+    InvoiceDescriptionHashEvent {
+        invoice_id,
+        description_hash,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantAccountLinkedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub account: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_account_linked_event(
+    env: &Env,
+    merchant_id: u64,
+    account: Address,
+    timestamp: u64,
+) {
+    MerchantAccountLinkedEvent {
+        merchant_id,
+        account,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantFundsWithdrawnEvent {
+    #[topic]
+    pub merchant_id: u64,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+}
+
+pub fn publish_merchant_funds_withdrawn_event(
+    env: &Env,
+    merchant_id: u64,
+    token: Address,
+    amount: i128,
+    to: Address,
+) {
+    MerchantFundsWithdrawnEvent {
+        merchant_id,
+        token,
+        amount,
+        to,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceDelegateSetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    #[topic]
+    pub delegate: Address,
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_delegate_set_event(
+    env: &Env,
+    merchant_id: u64,
+    delegate: Address,
+    enabled: bool,
+    timestamp: u64,
+) {
+    InvoiceDelegateSetEvent {
+        merchant_id,
+        delegate,
+        enabled,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantVerifiedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub status: bool,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["merchant_verified_event"])]
+pub struct LegacyMerchantVerifiedEvent {
+    pub merchant_id: u64,
+    pub status: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_verified_event(env: &Env, merchant_id: u64, status: bool, timestamp: u64) {
+    if admin::is_legacy_event_format(env) {
+        LegacyMerchantVerifiedEvent {
+            merchant_id,
+            status,
+            timestamp,
+        }
+        .publish(env);
+    } else {
+        MerchantVerifiedEvent {
+            merchant_id,
+            status,
+            timestamp,
+        }
+        .publish(env);
+    }
+}
+
+#[contractevent]
+pub struct MerchantApprovedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_approved_event(
+    env: &Env,
+    merchant_id: u64,
+    approved_by: Address,
+    timestamp: u64,
+) {
+    MerchantApprovedEvent {
+        merchant_id,
+        approved_by,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RegistrationModeSetEvent {
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_permissionless_registration_set_event(env: &Env, enabled: bool, timestamp: u64) {
+    RegistrationModeSetEvent { enabled, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct RegistrationBondSetEvent {
+    pub bond: Option<MerchantBond>,
+    pub timestamp: u64,
+}
+
+pub fn publish_registration_bond_set_event(env: &Env, bond: Option<MerchantBond>, timestamp: u64) {
+    RegistrationBondSetEvent { bond, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct BondStakedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_bond_staked_event(env: &Env, merchant_id: u64, token: Address, amount: i128) {
+    BondStakedEvent {
+        merchant_id,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct BondReleasedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_bond_released_event(env: &Env, merchant_id: u64, token: Address, amount: i128) {
+    BondReleasedEvent {
+        merchant_id,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct BondCoolingOffStartedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub release_at: u64,
+}
+
+pub fn publish_bond_cooling_off_started_event(env: &Env, merchant_id: u64, release_at: u64) {
+    BondCoolingOffStartedEvent {
+        merchant_id,
+        release_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct BondSlashedEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_bond_slashed_event(
+    env: &Env,
+    merchant_id: u64,
+    recipient: Address,
+    amount: i128,
+    timestamp: u64,
+) {
+    BondSlashedEvent {
+        merchant_id,
+        recipient,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MerchantKeySetEvent {
+    pub merchant: Address,
+    pub key: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_merchant_key_set_event(
+    env: &Env,
+    merchant: Address,
+    key: BytesN<32>,
+    timestamp: u64,
+) {
+    MerchantKeySetEvent {
+        merchant,
+        key,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RoleGrantedEvent {
+    pub user: Address,
+    pub role: crate::types::Role,
+    pub timestamp: u64,
+}
+
+pub fn publish_role_granted_event(
+    env: &Env,
+    user: Address,
+    role: crate::types::Role,
+    timestamp: u64,
+) {
+    RoleGrantedEvent {
+        user,
+        role,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RoleRevokedEvent {
+    pub user: Address,
+    pub role: crate::types::Role,
+    pub timestamp: u64,
+}
+
+pub fn publish_role_revoked_event(
+    env: &Env,
+    user: Address,
+    role: crate::types::Role,
+    timestamp: u64,
+) {
+    RoleRevokedEvent {
+        user,
+        role,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ContractPausedEvent {
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_contract_paused_event(env: &Env, admin: Address, timestamp: u64) {
+    ContractPausedEvent { admin, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct ContractUnpausedEvent {
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_contract_unpaused_event(env: &Env, admin: Address, timestamp: u64) {
+    ContractUnpausedEvent { admin, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct FeeExemptionSetEvent {
+    #[topic]
+    pub address: Address,
+    pub exempt: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_exemption_set_event(env: &Env, address: Address, exempt: bool, timestamp: u64) {
+    FeeExemptionSetEvent {
+        address,
+        exempt,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeeWaivedEvent {
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub exempt_party: Address,
+    pub fee: i128,
+}
+
+pub fn publish_fee_waived_event(env: &Env, token: Address, exempt_party: Address, fee: i128) {
+    FeeWaivedEvent {
+        token,
+        exempt_party,
+        fee,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeeSetEvent {
+    #[topic]
+    pub token: Address,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+#[contractevent(topics = ["fee_set_event"])]
+pub struct LegacyFeeSetEvent {
+    pub token: Address,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_fee_set_event(env: &Env, token: Address, fee: i128, timestamp: u64) {
+    if admin::is_legacy_event_format(env) {
+        LegacyFeeSetEvent {
+            token,
+            fee,
+            timestamp,
+        }
+        .publish(env);
+    } else {
+        FeeSetEvent {
+            token,
+            fee,
+            timestamp,
+        }
+        .publish(env);
+    }
+}
+
+#[contractevent]
+pub struct ContractUpgradedEvent {
+    pub old_wasm_hash: Option<BytesN<32>>,
+    pub new_wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_contract_upgraded_event(
+    env: &Env,
+    old_wasm_hash: Option<BytesN<32>>,
+    new_wasm_hash: BytesN<32>,
+    timestamp: u64,
+) {
+    ContractUpgradedEvent {
+        old_wasm_hash,
+        new_wasm_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct DeployedWasmRecordedEvent {
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_deployed_wasm_recorded_event(env: &Env, wasm_hash: BytesN<32>, timestamp: u64) {
+    DeployedWasmRecordedEvent {
+        wasm_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ContractRolledBackEvent {
+    pub from_wasm_hash: BytesN<32>,
+    pub to_wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_contract_rolled_back_event(
+    env: &Env,
+    from_wasm_hash: BytesN<32>,
+    to_wasm_hash: BytesN<32>,
+    timestamp: u64,
+) {
+    ContractRolledBackEvent {
+        from_wasm_hash,
+        to_wasm_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct UpgradeProposedEvent {
+    pub wasm_hash: BytesN<32>,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_upgrade_proposed_event(
+    env: &Env,
+    wasm_hash: BytesN<32>,
+    executable_at: u64,
+    timestamp: u64,
+) {
+    UpgradeProposedEvent {
+        wasm_hash,
+        executable_at,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct UpgradeCancelledEvent {
+    pub wasm_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn publish_upgrade_cancelled_event(env: &Env, wasm_hash: BytesN<32>, timestamp: u64) {
+    UpgradeCancelledEvent {
+        wasm_hash,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct LegacyEventFormatSetEvent {
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_legacy_event_format_set_event(env: &Env, enabled: bool, timestamp: u64) {
+    LegacyEventFormatSetEvent { enabled, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct StorageBumpedEvent {
+    pub key_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_storage_bumped_event(env: &Env, key_count: u32, timestamp: u64) {
+    StorageBumpedEvent {
+        key_count,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SchemaMigratedEvent {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_schema_migrated_event(
+    env: &Env,
+    from_version: u32,
+    to_version: u32,
+    timestamp: u64,
+) {
+    SchemaMigratedEvent {
+        from_version,
+        to_version,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct StreamCreatedEvent {
+    #[topic]
+    pub stream_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn publish_stream_created_event(
+    env: &Env,
+    stream_id: u64,
+    merchant_id: u64,
+    payer: Address,
+    token: Address,
+    amount: i128,
+    start_time: u64,
+    end_time: u64,
+) {
+    StreamCreatedEvent {
+        stream_id,
+        merchant_id,
+        payer,
+        token,
+        amount,
+        start_time,
+        end_time,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct StreamWithdrawnEvent {
+    #[topic]
+    pub stream_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_stream_withdrawn_event(
+    env: &Env,
+    stream_id: u64,
+    merchant_id: u64,
+    amount: i128,
+    fee: i128,
+    timestamp: u64,
+) {
+    StreamWithdrawnEvent {
+        stream_id,
+        merchant_id,
+        amount,
+        fee,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct StreamCancelledEvent {
+    #[topic]
+    pub stream_id: u64,
+    pub cancelled_by: Address,
+    pub refunded: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_stream_cancelled_event(
+    env: &Env,
+    stream_id: u64,
+    cancelled_by: Address,
+    refunded: i128,
+    timestamp: u64,
+) {
+    StreamCancelledEvent {
+        stream_id,
+        cancelled_by,
+        refunded,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct GiftCardIssuedEvent {
+    #[topic]
+    pub card_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub buyer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+pub fn publish_gift_card_issued_event(
+    env: &Env,
+    card_id: u64,
+    merchant_id: u64,
+    buyer: Address,
+    token: Address,
+    amount: i128,
+    expires_at: u64,
+) {
+    GiftCardIssuedEvent {
+        card_id,
+        merchant_id,
+        buyer,
+        token,
+        amount,
+        expires_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct GiftCardClaimedEvent {
+    #[topic]
+    pub card_id: u64,
+    pub holder: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_gift_card_claimed_event(env: &Env, card_id: u64, holder: Address, timestamp: u64) {
+    GiftCardClaimedEvent {
+        card_id,
+        holder,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct GiftCardRedeemedEvent {
+    #[topic]
+    pub card_id: u64,
+    #[topic]
+    pub invoice_id: u64,
+    pub amount: i128,
+    pub remaining_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_gift_card_redeemed_event(
+    env: &Env,
+    card_id: u64,
+    invoice_id: u64,
+    amount: i128,
+    remaining_balance: i128,
+    timestamp: u64,
+) {
+    GiftCardRedeemedEvent {
+        card_id,
+        invoice_id,
+        amount,
+        remaining_balance,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct GiftCardExpiredEvent {
+    #[topic]
+    pub card_id: u64,
+    pub refunded: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_gift_card_expired_event(env: &Env, card_id: u64, refunded: i128, timestamp: u64) {
+    GiftCardExpiredEvent {
+        card_id,
+        refunded,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PaymentLinkCreatedEvent {
+    #[topic]
+    pub link_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+pub fn publish_payment_link_created_event(
+    env: &Env,
+    link_id: u64,
+    payer: Address,
+    token: Address,
+    amount: i128,
+    expires_at: u64,
+) {
+    PaymentLinkCreatedEvent {
+        link_id,
+        payer,
+        token,
+        amount,
+        expires_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PaymentLinkClaimedEvent {
+    #[topic]
+    pub link_id: u64,
+    pub claimant: Address,
+    pub invoice_id: Option<u64>,
+    pub timestamp: u64,
+}
+
+pub fn publish_payment_link_claimed_event(
+    env: &Env,
+    link_id: u64,
+    claimant: Address,
+    invoice_id: Option<u64>,
+    timestamp: u64,
+) {
+    PaymentLinkClaimedEvent {
+        link_id,
+        claimant,
+        invoice_id,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PaymentLinkRefundedEvent {
+    #[topic]
+    pub link_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_payment_link_refunded_event(env: &Env, link_id: u64, amount: i128, timestamp: u64) {
+    PaymentLinkRefundedEvent {
+        link_id,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CampaignCreatedEvent {
+    #[topic]
+    pub campaign_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub token: Address,
+    pub goal: i128,
+    pub deadline: u64,
+}
+
+pub fn publish_campaign_created_event(
+    env: &Env,
+    campaign_id: u64,
+    merchant_id: u64,
+    token: Address,
+    goal: i128,
+    deadline: u64,
+) {
+    CampaignCreatedEvent {
+        campaign_id,
+        merchant_id,
+        token,
+        goal,
+        deadline,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CampaignContributionEvent {
+    #[topic]
+    pub campaign_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub raised: i128,
+}
+
+pub fn publish_campaign_contribution_event(
+    env: &Env,
+    campaign_id: u64,
+    contributor: Address,
+    amount: i128,
+    raised: i128,
+) {
+    CampaignContributionEvent {
+        campaign_id,
+        contributor,
+        amount,
+        raised,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CampaignFinalizedEvent {
+    #[topic]
+    pub campaign_id: u64,
+    pub status: CampaignStatus,
+    pub raised: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_campaign_finalized_event(
+    env: &Env,
+    campaign_id: u64,
+    status: CampaignStatus,
+    raised: i128,
+    timestamp: u64,
+) {
+    CampaignFinalizedEvent {
+        campaign_id,
+        status,
+        raised,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CampaignRefundedEvent {
+    #[topic]
+    pub campaign_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+}
+
+pub fn publish_campaign_refunded_event(
+    env: &Env,
+    campaign_id: u64,
+    contributor: Address,
+    amount: i128,
+) {
+    CampaignRefundedEvent {
+        campaign_id,
+        contributor,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SettlementBatchCreatedEvent {
+    #[topic]
+    pub batch_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub token: Address,
+    pub total: i128,
+    pub invoice_count: u32,
+    pub external_ref: BytesN<32>,
+}
+
+pub fn publish_settlement_batch_created_event(
+    env: &Env,
+    batch_id: u64,
+    merchant_id: u64,
+    token: Address,
+    total: i128,
+    invoice_count: u32,
+    external_ref: BytesN<32>,
+) {
+    SettlementBatchCreatedEvent {
+        batch_id,
+        merchant_id,
+        token,
+        total,
+        invoice_count,
+        external_ref,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct SettlementBatchStatusEvent {
+    #[topic]
+    pub batch_id: u64,
+    pub status: SettlementStatus,
+    pub timestamp: u64,
+}
+
+pub fn publish_settlement_batch_status_event(
+    env: &Env,
+    batch_id: u64,
+    status: SettlementStatus,
+    timestamp: u64,
+) {
+    SettlementBatchStatusEvent {
+        batch_id,
+        status,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TokensRecoveredEvent {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub to: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_tokens_recovered_event(
+    env: &Env,
+    token: Address,
+    amount: i128,
+    to: Address,
+    timestamp: u64,
+) {
+    TokensRecoveredEvent {
+        token,
+        amount,
+        to,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PaymentRoutesSetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub routes: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_payment_routes_set_event(env: &Env, merchant_id: u64, routes: u32, timestamp: u64) {
+    PaymentRoutesSetEvent {
+        merchant_id,
+        routes,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct PaymentRoutedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_payment_routed_event(
+    env: &Env,
+    invoice_id: u64,
+    recipient: Address,
+    token: Address,
+    amount: i128,
+) {
+    PaymentRoutedEvent {
+        invoice_id,
+        recipient,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CleanupBountySetEvent {
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn publish_cleanup_bounty_set_event(env: &Env, token: Address, amount: i128, timestamp: u64) {
+    CleanupBountySetEvent {
+        token,
+        amount,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ExpiredEntriesSweptEvent {
+    #[topic]
+    pub caller: Address,
+    pub processed: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_expired_entries_swept_event(
+    env: &Env,
+    caller: Address,
+    processed: u32,
+    timestamp: u64,
+) {
+    ExpiredEntriesSweptEvent {
+        caller,
+        processed,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CleanupBountyPaidEvent {
+    #[topic]
+    pub caller: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_cleanup_bounty_paid_event(env: &Env, caller: Address, token: Address, amount: i128) {
+    CleanupBountyPaidEvent {
+        caller,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ApprovalThresholdSetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    #[topic]
+    pub token: Address,
+    pub threshold: Option<i128>,
+    pub timestamp: u64,
+}
+
+pub fn publish_approval_threshold_set_event(
+    env: &Env,
+    merchant_id: u64,
+    token: Address,
+    threshold: Option<i128>,
+    timestamp: u64,
+) {
+    ApprovalThresholdSetEvent {
+        merchant_id,
+        token,
+        threshold,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceApprovalRequiredEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub creator: Address,
+    pub amount: i128,
+}
+
+pub fn publish_invoice_approval_required_event(
+    env: &Env,
+    invoice_id: u64,
+    creator: Address,
+    amount: i128,
+) {
+    InvoiceApprovalRequiredEvent {
+        invoice_id,
+        creator,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceApprovedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub approver: Address,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_approved_event(
+    env: &Env,
+    invoice_id: u64,
+    approver: Address,
+    timestamp: u64,
+) {
+    InvoiceApprovedEvent {
+        invoice_id,
+        approver,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ClawbackAssetSetEvent {
+    #[topic]
+    pub token: Address,
+    pub enabled: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_clawback_asset_set_event(env: &Env, token: Address, enabled: bool, timestamp: u64) {
+    ClawbackAssetSetEvent {
+        token,
+        enabled,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ClawbackPolicySetEvent {
+    pub reject: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_clawback_policy_set_event(env: &Env, reject: bool, timestamp: u64) {
+    ClawbackPolicySetEvent { reject, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceMetadataSetEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub entries: u32,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_metadata_set_event(
+    env: &Env,
+    invoice_id: u64,
+    entries: u32,
+    timestamp: u64,
+) {
+    InvoiceMetadataSetEvent {
+        invoice_id,
+        entries,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceOverdueEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub due_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_invoice_overdue_event(env: &Env, invoice_id: u64, due_at: u64, timestamp: u64) {
+    InvoiceOverdueEvent {
+        invoice_id,
+        due_at,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct LateFeeCollectedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub late_fee: i128,
+}
+
+pub fn publish_late_fee_collected_event(env: &Env, invoice_id: u64, late_fee: i128) {
+    LateFeeCollectedEvent {
+        invoice_id,
+        late_fee,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TaxCollectedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub tax_recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_tax_collected_event(
+    env: &Env,
+    invoice_id: u64,
+    tax_recipient: Address,
+    token: Address,
+    amount: i128,
+) {
+    TaxCollectedEvent {
+        invoice_id,
+        tax_recipient,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceTipPaidEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub tip_amount: i128,
+    pub fee: i128,
+}
+
+pub fn publish_invoice_tip_paid_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    token: Address,
+    tip_amount: i128,
+    fee: i128,
+) {
+    InvoiceTipPaidEvent {
+        invoice_id,
+        payer,
+        token,
+        tip_amount,
+        fee,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct TipFeePolicySetEvent {
+    pub exempt: bool,
+    pub timestamp: u64,
+}
+
+pub fn publish_tip_fee_policy_set_event(env: &Env, exempt: bool, timestamp: u64) {
+    TipFeePolicySetEvent { exempt, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct OverpaymentPolicySetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub policy: OverpaymentPolicy,
+    pub timestamp: u64,
+}
+
+pub fn publish_overpayment_policy_set_event(
+    env: &Env,
+    merchant_id: u64,
+    policy: OverpaymentPolicy,
+    timestamp: u64,
+) {
+    OverpaymentPolicySetEvent {
+        merchant_id,
+        policy,
+        timestamp,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct OverpaymentSettledEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub policy: OverpaymentPolicy,
+    pub surplus: i128,
+}
+
+pub fn publish_overpayment_settled_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    policy: OverpaymentPolicy,
+    surplus: i128,
+) {
+    OverpaymentSettledEvent {
+        invoice_id,
+        payer,
+        policy,
+        surplus,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceContributionEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub amount: i128,
+    pub total_contributed: i128,
+}
+
+pub fn publish_invoice_contribution_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    amount: i128,
+    total_contributed: i128,
+) {
+    InvoiceContributionEvent {
+        invoice_id,
+        payer,
+        amount,
+        total_contributed,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct ContributionWithdrawnEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub payer: Address,
+    pub amount: i128,
+}
+
+pub fn publish_contribution_withdrawn_event(
+    env: &Env,
+    invoice_id: u64,
+    payer: Address,
+    amount: i128,
+) {
+    ContributionWithdrawnEvent {
+        invoice_id,
+        payer,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceRefundedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub caller: Address,
+    pub amount: i128,
+    pub total_refunded: i128,
+}
+
+pub fn publish_invoice_refunded_event(
+    env: &Env,
+    invoice_id: u64,
+    caller: Address,
+    amount: i128,
+    total_refunded: i128,
+) {
+    InvoiceRefundedEvent {
+        invoice_id,
+        caller,
+        amount,
+        total_refunded,
+    }
+    .publish(env);
 }
 
 #[contractevent]
-pub struct TokenRemovedEvent {
+pub struct RefundRedirectedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+pub fn publish_refund_redirected_event(
+    env: &Env,
+    invoice_id: u64,
+    recipient: Address,
+    amount: i128,
+) {
+    RefundRedirectedEvent {
+        invoice_id,
+        recipient,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeeRefundedEvent {
+    #[topic]
+    pub invoice_id: u64,
     pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_fee_refunded_event(env: &Env, invoice_id: u64, token: Address, amount: i128) {
+    FeeRefundedEvent {
+        invoice_id,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct FeeRefundPolicySetEvent {
+    pub enabled: bool,
     pub timestamp: u64,
 }
 
-pub fn publish_token_removed_event(env: &Env, token: Address, timestamp: u64) {
-    TokenRemovedEvent { token, timestamp }.publish(env);
+pub fn publish_fee_refund_policy_set_event(env: &Env, enabled: bool, timestamp: u64) {
+    FeeRefundPolicySetEvent { enabled, timestamp }.publish(env);
 }
 
 #[contractevent]
-pub struct MerchantRegisteredEvent {
-    pub merchant: Address,
-    pub merchant_id: u64,
+pub struct RefundSubsidyPolicySetEvent {
+    pub enabled: bool,
     pub timestamp: u64,
 }
 
-pub fn publish_merchant_registered_event(
+pub fn publish_refund_subsidy_policy_set_event(env: &Env, enabled: bool, timestamp: u64) {
+    RefundSubsidyPolicySetEvent { enabled, timestamp }.publish(env);
+}
+
+#[contractevent]
+pub struct RefundSubsidizedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_refund_subsidized_event(
     env: &Env,
-    merchant: Address,
+    invoice_id: u64,
     merchant_id: u64,
-    timestamp: u64,
+    token: Address,
+    amount: i128,
 ) {
-    MerchantRegisteredEvent {
-        merchant,
+    RefundSubsidizedEvent {
+        invoice_id,
         merchant_id,
-        timestamp,
+        token,
+        amount,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct MerchantStatusChangedEvent {
+pub struct MerchantDebtRepaidEvent {
+    #[topic]
     pub merchant_id: u64,
-    pub active: bool,
-    pub timestamp: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub remaining: i128,
 }
 
-pub fn publish_merchant_status_changed_event(
+pub fn publish_merchant_debt_repaid_event(
     env: &Env,
     merchant_id: u64,
-    active: bool,
-    timestamp: u64,
+    token: Address,
+    amount: i128,
+    remaining: i128,
 ) {
-    MerchantStatusChangedEvent {
+    MerchantDebtRepaidEvent {
         merchant_id,
-        active,
-        timestamp,
+        token,
+        amount,
+        remaining,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct InvoiceCreatedEvent {
+pub struct EscrowHeldEvent {
+    #[topic]
     pub invoice_id: u64,
-    pub merchant: Address,
     pub amount: i128,
-    pub token: Address,
+    pub release_at: u64,
 }
 
-pub fn publish_invoice_created_event(
+pub fn publish_escrow_held_event(env: &Env, invoice_id: u64, amount: i128, release_at: u64) {
+    EscrowHeldEvent {
+        invoice_id,
+        amount,
+        release_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct EscrowReleasedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub amount: i128,
+    pub confirmed: bool,
+}
+
+pub fn publish_escrow_released_event(env: &Env, invoice_id: u64, amount: i128, confirmed: bool) {
+    EscrowReleasedEvent {
+        invoice_id,
+        amount,
+        confirmed,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct MilestoneUpdatedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub index: u32,
+    pub status: MilestoneStatus,
+    pub timestamp: u64,
+}
+
+pub fn publish_milestone_updated_event(
     env: &Env,
     invoice_id: u64,
-    merchant: Address,
-    amount: i128,
-    token: Address,
+    index: u32,
+    status: MilestoneStatus,
+    timestamp: u64,
 ) {
-    InvoiceCreatedEvent {
+    MilestoneUpdatedEvent {
         invoice_id,
-        merchant,
-        amount,
-        token,
+        index,
+        status,
+        timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct MerchantVerifiedEvent {
+pub struct InvoiceExpiredEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
     pub merchant_id: u64,
-    pub status: bool,
+    pub expires_at: u64,
     pub timestamp: u64,
 }
 
-pub fn publish_merchant_verified_event(env: &Env, merchant_id: u64, status: bool, timestamp: u64) {
-    MerchantVerifiedEvent {
+pub fn publish_invoice_expired_event(
+    env: &Env,
+    invoice_id: u64,
+    merchant_id: u64,
+    expires_at: u64,
+    timestamp: u64,
+) {
+    InvoiceExpiredEvent {
+        invoice_id,
         merchant_id,
-        status,
+        expires_at,
         timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct MerchantKeySetEvent {
-    pub merchant: Address,
-    pub key: BytesN<32>,
+pub struct ExpiredInvoiceCancelledEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub refunded: i128,
     pub timestamp: u64,
 }
 
-pub fn publish_merchant_key_set_event(
+pub fn publish_expired_invoice_cancelled_event(
     env: &Env,
-    merchant: Address,
-    key: BytesN<32>,
+    invoice_id: u64,
+    refunded: i128,
     timestamp: u64,
 ) {
-    MerchantKeySetEvent {
-        merchant,
-        key,
+    ExpiredInvoiceCancelledEvent {
+        invoice_id,
+        refunded,
         timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct RoleGrantedEvent {
-    pub user: Address,
-    pub role: crate::types::Role,
-    pub timestamp: u64,
+pub struct RecurringScheduleCreatedEvent {
+    #[topic]
+    pub schedule_id: u64,
+    #[topic]
+    pub merchant_id: u64,
+    pub amount: i128,
+    pub period: u64,
 }
 
-pub fn publish_role_granted_event(
+pub fn publish_recurring_schedule_created_event(
     env: &Env,
-    user: Address,
-    role: crate::types::Role,
-    timestamp: u64,
+    schedule_id: u64,
+    merchant_id: u64,
+    amount: i128,
+    period: u64,
 ) {
-    RoleGrantedEvent {
-        user,
-        role,
+    RecurringScheduleCreatedEvent {
+        schedule_id,
+        merchant_id,
+        amount,
+        period,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct RecurringScheduleEndedEvent {
+    #[topic]
+    pub schedule_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn publish_recurring_schedule_ended_event(env: &Env, schedule_id: u64, timestamp: u64) {
+    RecurringScheduleEndedEvent {
+        schedule_id,
         timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct RoleRevokedEvent {
-    pub user: Address,
-    pub role: crate::types::Role,
+pub struct RecurringInvoiceIssuedEvent {
+    #[topic]
+    pub schedule_id: u64,
+    pub invoice_id: u64,
+    pub due_at: u64,
+}
+
+pub fn publish_recurring_invoice_issued_event(
+    env: &Env,
+    schedule_id: u64,
+    invoice_id: u64,
+    due_at: u64,
+) {
+    RecurringInvoiceIssuedEvent {
+        schedule_id,
+        invoice_id,
+        due_at,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct InvoiceAmendedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub actor: Address,
+    pub old_amount: i128,
+    pub new_amount: i128,
     pub timestamp: u64,
 }
 
-pub fn publish_role_revoked_event(
+pub fn publish_invoice_amended_event(
     env: &Env,
-    user: Address,
-    role: crate::types::Role,
+    invoice_id: u64,
+    actor: Address,
+    old_amount: i128,
+    new_amount: i128,
     timestamp: u64,
 ) {
-    RoleRevokedEvent {
-        user,
-        role,
+    InvoiceAmendedEvent {
+        invoice_id,
+        actor,
+        old_amount,
+        new_amount,
         timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct ContractPausedEvent {
-    pub admin: Address,
+pub struct CustomerRegisteredEvent {
+    #[topic]
+    pub customer: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_contract_paused_event(env: &Env, admin: Address, timestamp: u64) {
-    ContractPausedEvent { admin, timestamp }.publish(env);
+pub fn publish_customer_registered_event(env: &Env, customer: Address, timestamp: u64) {
+    CustomerRegisteredEvent {
+        customer,
+        timestamp,
+    }
+    .publish(env);
 }
 
 #[contractevent]
-pub struct ContractUnpausedEvent {
-    pub admin: Address,
+pub struct CustomerRemovedEvent {
+    #[topic]
+    pub customer: Address,
     pub timestamp: u64,
 }
 
-pub fn publish_contract_unpaused_event(env: &Env, admin: Address, timestamp: u64) {
-    ContractUnpausedEvent { admin, timestamp }.publish(env);
+pub fn publish_customer_removed_event(env: &Env, customer: Address, timestamp: u64) {
+    CustomerRemovedEvent {
+        customer,
+        timestamp,
+    }
+    .publish(env);
 }
 
 #[contractevent]
-pub struct FeeSetEvent {
-    pub token: Address,
-    pub fee: i128,
+pub struct PaymentHookSetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub hook: Option<Address>,
     pub timestamp: u64,
 }
 
-pub fn publish_fee_set_event(env: &Env, token: Address, fee: i128, timestamp: u64) {
-    FeeSetEvent {
-        token,
-        fee,
+pub fn publish_payment_hook_set_event(
+    env: &Env,
+    merchant_id: u64,
+    hook: Option<Address>,
+    timestamp: u64,
+) {
+    PaymentHookSetEvent {
+        merchant_id,
+        hook,
         timestamp,
     }
     .publish(env);
 }
 
 #[contractevent]
-pub struct ContractUpgradedEvent {
-    pub new_wasm_hash: BytesN<32>,
+pub struct PaymentHookFailedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    pub hook: Address,
+}
+
+pub fn publish_payment_hook_failed_event(env: &Env, invoice_id: u64, hook: Address) {
+    PaymentHookFailedEvent { invoice_id, hook }.publish(env);
+}
+
+#[contractevent]
+pub struct AffiliateCommissionSetEvent {
+    #[topic]
+    pub merchant_id: u64,
+    pub commission_bps: u32,
     pub timestamp: u64,
 }
 
-pub fn publish_contract_upgraded_event(env: &Env, new_wasm_hash: BytesN<32>, timestamp: u64) {
-    ContractUpgradedEvent {
-        new_wasm_hash,
+pub fn publish_affiliate_commission_set_event(
+    env: &Env,
+    merchant_id: u64,
+    commission_bps: u32,
+    timestamp: u64,
+) {
+    AffiliateCommissionSetEvent {
+        merchant_id,
+        commission_bps,
         timestamp,
     }
     .publish(env);
 }
+
+#[contractevent]
+pub struct CommissionAccruedEvent {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub affiliate: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_commission_accrued_event(
+    env: &Env,
+    invoice_id: u64,
+    affiliate: Address,
+    token: Address,
+    amount: i128,
+) {
+    CommissionAccruedEvent {
+        invoice_id,
+        affiliate,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent]
+pub struct CommissionClaimedEvent {
+    #[topic]
+    pub affiliate: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+pub fn publish_commission_claimed_event(
+    env: &Env,
+    affiliate: Address,
+    token: Address,
+    amount: i128,
+) {
+    CommissionClaimedEvent {
+        affiliate,
+        token,
+        amount,
+    }
+    .publish(env);
+}
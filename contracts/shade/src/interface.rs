@@ -1,23 +1,134 @@
-use crate::types::{Invoice, InvoiceFilter, Merchant, MerchantFilter, Role};
-use soroban_sdk::{contracttrait, Address, BytesN, Env, String, Vec};
+use crate::types::{
+    ActivityRecord, AffiliateEarnings, AmountBounds, Campaign, CampaignStatus, ContractInfo,
+    Council, CustomerProfile, DistributionShare, EntityCounts, EscrowHold, GiftCard, Invoice,
+    InvoiceAmendment, InvoiceBalance, InvoiceExpiryPolicy, InvoiceFilter, InvoiceRateLimit,
+    InvoiceStatus, InvoiceTax, LateFeePolicy, LineItem, Merchant, MerchantBond, MerchantFilter,
+    Milestone, OracleAsset, OracleConfig, OverpaymentPolicy, ParameterChange, PaymentLink,
+    PaymentPreview, PaymentRoute, PendingUpgrade, PriceData, Proposal, ProtocolConfig,
+    ProtocolStats, RecurringSchedule, Role, SettlementBatch, StorageKey, Stream, TokenMetadata,
+    UpgradeRecord, VelocityLimit,
+};
+use soroban_sdk::{
+    contractclient, contracttrait, Address, Bytes, BytesN, Env, Map, String, Symbol, Val, Vec,
+};
 
 #[contracttrait]
 pub trait ShadeTrait {
     fn initialize(env: Env, admin: Address);
     fn get_admin(env: Env) -> Address;
+    fn get_version(env: Env) -> (u32, u32, u32);
+    fn get_contract_info(env: Env) -> ContractInfo;
+    fn get_config(env: Env) -> ProtocolConfig;
+    fn get_counts(env: Env) -> EntityCounts;
+    fn get_protocol_stats(env: Env) -> ProtocolStats;
+    fn get_recent_activity(env: Env, limit: u32) -> Vec<ActivityRecord>;
     fn add_accepted_token(env: Env, admin: Address, token: Address);
     fn remove_accepted_token(env: Env, admin: Address, token: Address);
     fn is_accepted_token(env: Env, token: Address) -> bool;
+    fn get_token_metadata(env: Env, token: Address) -> Option<TokenMetadata>;
+    fn set_native_token(env: Env, admin: Address, token: Address);
+    fn get_native_token(env: Env) -> Option<Address>;
+    fn set_clawback_asset(env: Env, admin: Address, token: Address, enabled: bool);
+    fn is_clawback_asset(env: Env, token: Address) -> bool;
+    fn set_reject_clawback_assets(env: Env, admin: Address, reject: bool);
+    fn rejects_clawback_assets(env: Env) -> bool;
+    fn set_refund_protocol_fees(env: Env, admin: Address, enabled: bool);
+    fn refunds_protocol_fees(env: Env) -> bool;
+    fn set_refund_subsidy(env: Env, admin: Address, enabled: bool);
+    fn subsidizes_refunds(env: Env) -> bool;
+    fn add_trusted_contract(env: Env, admin: Address, contract: Address);
+    fn remove_trusted_contract(env: Env, admin: Address, contract: Address);
+    fn is_trusted_contract(env: Env, contract: Address) -> bool;
+    fn block_payer(env: Env, caller: Address, payer: Address);
+    fn unblock_payer(env: Env, caller: Address, payer: Address);
+    fn is_payer_blocked(env: Env, payer: Address) -> bool;
+    fn set_velocity_limit(env: Env, admin: Address, token: Address, max_amount: i128, window: u64);
+    fn remove_velocity_limit(env: Env, admin: Address, token: Address);
+    fn get_velocity_limit(env: Env, token: Address) -> Option<VelocityLimit>;
+    fn get_payer_volume(env: Env, payer: Address, token: Address) -> i128;
+    fn set_oracle(env: Env, admin: Address, oracle: Address, max_price_age: u64);
+    fn get_oracle(env: Env) -> Option<OracleConfig>;
+    fn get_token_price(env: Env, token: Address) -> i128;
+    // `fee` is in basis points of each payment (0..=10_000), no longer a
+    // flat amount per payment.
     fn set_fee(env: Env, admin: Address, token: Address, fee: i128);
     fn get_fee(env: Env, token: Address) -> i128;
-    fn register_merchant(env: Env, merchant: Address);
+    fn set_fee_exempt(env: Env, admin: Address, address: Address, exempt: bool);
+    fn is_fee_exempt(env: Env, address: Address) -> bool;
+    fn get_collected_fees(env: Env, token: Address) -> i128;
+    fn get_recoverable_balance(env: Env, token: Address) -> i128;
+    fn recover_tokens(env: Env, admin: Address, token: Address, amount: i128, to: Address);
+    fn set_distribution_shares(env: Env, admin: Address, shares: Vec<DistributionShare>);
+    fn get_distribution_shares(env: Env) -> Vec<DistributionShare>;
+    fn get_undistributed_fees(env: Env, token: Address) -> i128;
+    fn distribute_fees(env: Env, token: Address) -> i128;
+    fn get_claimable_distribution(env: Env, recipient: Address, token: Address) -> i128;
+    fn claim_distribution(env: Env, recipient: Address, token: Address) -> i128;
+    fn set_council(env: Env, admin: Address, members: Vec<Address>, threshold: u32);
+    fn get_council(env: Env) -> Option<Council>;
+    fn propose_change(env: Env, proposer: Address, change: ParameterChange) -> u64;
+    fn vote_on_proposal(env: Env, member: Address, proposal_id: u64);
+    fn execute_proposal(env: Env, proposal_id: u64);
+    fn cancel_proposal(env: Env, admin: Address, proposal_id: u64);
+    fn get_proposal(env: Env, proposal_id: u64) -> Proposal;
+    fn set_amount_bounds(env: Env, admin: Address, token: Address, bounds: Option<AmountBounds>);
+    fn get_amount_bounds(env: Env, token: Address) -> Option<AmountBounds>;
+    fn set_legacy_event_format(env: Env, admin: Address, enabled: bool);
+    fn is_legacy_event_format(env: Env) -> bool;
+    fn register_merchant(env: Env, merchant: Address) -> u64;
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant;
-    fn get_merchants(env: Env, filter: MerchantFilter) -> Vec<Merchant>;
+    fn get_merchants(
+        env: Env,
+        filter: MerchantFilter,
+        cursor: u64,
+        limit: u32,
+    ) -> (Vec<Merchant>, Option<u64>);
     fn is_merchant(env: Env, merchant: Address) -> bool;
+    fn set_default_invoice_rate_limit(env: Env, admin: Address, limit: Option<InvoiceRateLimit>);
+    fn set_merchant_invoice_rate_limit(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        limit: Option<InvoiceRateLimit>,
+    );
+    fn get_invoice_rate_limit(env: Env, merchant_id: u64) -> Option<InvoiceRateLimit>;
     fn set_merchant_status(env: Env, admin: Address, merchant_id: u64, status: bool);
     fn is_merchant_active(env: Env, merchant_id: u64) -> bool;
     fn verify_merchant(env: Env, admin: Address, merchant_id: u64, status: bool);
     fn is_merchant_verified(env: Env, merchant_id: u64) -> bool;
+    fn approve_merchant(env: Env, caller: Address, merchant_id: u64);
+    fn is_merchant_approved(env: Env, merchant_id: u64) -> bool;
+    fn set_permissionless_registration(env: Env, admin: Address, enabled: bool);
+    fn is_permissionless_registration(env: Env) -> bool;
+    fn offboard_merchant(env: Env, merchant: Address);
+    fn link_merchant_account(env: Env, merchant: Address, account: Address);
+    fn get_merchant_account(env: Env, merchant_id: u64) -> Option<Address>;
+    fn set_merchant_account_restricted(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        restricted: bool,
+    );
+    fn approve_merchant_withdrawal(env: Env, admin: Address, merchant_id: u64, request_id: u64);
+    fn withdraw_merchant_funds(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    );
+    fn set_registration_bond(env: Env, admin: Address, bond: Option<MerchantBond>);
+    fn get_registration_bond(env: Env) -> Option<MerchantBond>;
+    fn get_merchant_bond(env: Env, merchant_id: u64) -> Option<MerchantBond>;
+    fn get_merchant_bond_release_at(env: Env, merchant_id: u64) -> Option<u64>;
+    fn withdraw_bond(env: Env, merchant: Address);
+    fn slash_merchant_bond(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        amount: i128,
+        recipient: Address,
+    );
     fn create_invoice(
         env: Env,
         merchant: Address,
@@ -25,15 +136,300 @@ pub trait ShadeTrait {
         amount: i128,
         token: Address,
     ) -> u64;
+    fn create_delegated_invoice(
+        env: Env,
+        delegate: Address,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+    ) -> u64;
+    fn delegate_invoice_creation(env: Env, merchant: Address, delegate: Address, enabled: bool);
+    fn is_invoice_delegate(env: Env, merchant_id: u64, delegate: Address) -> bool;
+    fn set_invoice_approval_threshold(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        threshold: Option<i128>,
+    );
+    fn get_invoice_approval_threshold(env: Env, merchant_id: u64, token: Address) -> Option<i128>;
+    fn approve_invoice(env: Env, approver: Address, invoice_id: u64);
+    fn is_invoice_awaiting_approval(env: Env, invoice_id: u64) -> bool;
+    fn set_payment_routes(env: Env, merchant: Address, routes: Vec<PaymentRoute>);
+    fn get_payment_routes(env: Env, merchant_id: u64) -> Vec<PaymentRoute>;
+    fn set_payment_hook(env: Env, merchant: Address, hook: Option<Address>);
+    fn get_payment_hook(env: Env, merchant_id: u64) -> Option<Address>;
+    fn create_invoice_with_expiry(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+    ) -> u64;
+    fn create_private_invoice(
+        env: Env,
+        merchant: Address,
+        description_hash: BytesN<32>,
+        amount: i128,
+        token: Address,
+    ) -> u64;
+    fn create_itemized_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        line_items: Vec<LineItem>,
+        amount: i128,
+        token: Address,
+    ) -> u64;
+    fn get_invoice_line_items(env: Env, invoice_id: u64) -> Vec<LineItem>;
+    fn create_addressed_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        payer_allowlist: Vec<Address>,
+    ) -> u64;
+    fn get_invoice_payer_allowlist(env: Env, invoice_id: u64) -> Vec<Address>;
+    fn set_invoice_metadata(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        metadata: Map<Symbol, String>,
+    );
+    fn get_invoice_metadata(env: Env, invoice_id: u64) -> Map<Symbol, String>;
+    fn amend_invoice(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        new_amount: Option<i128>,
+        new_description: Option<String>,
+        new_expires_at: Option<u64>,
+        new_token: Option<Address>,
+    );
+    fn get_invoice_history(env: Env, invoice_id: u64) -> Vec<InvoiceAmendment>;
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) -> Invoice;
+    fn pay_invoice_with_affiliate(
+        env: Env,
+        payer: Address,
+        invoice_id: u64,
+        affiliate: Address,
+    ) -> Invoice;
+    fn set_affiliate_commission(env: Env, merchant: Address, commission_bps: u32);
+    fn get_affiliate_commission(env: Env, merchant_id: u64) -> u32;
+    fn get_affiliate_earnings(env: Env, affiliate: Address, token: Address) -> AffiliateEarnings;
+    fn claim_affiliate_commission(env: Env, affiliate: Address, token: Address) -> i128;
+    fn pay_invoice_on_behalf(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        invoice_id: u64,
+    ) -> Invoice;
     fn get_invoice(env: Env, invoice_id: u64) -> Invoice;
+    fn get_invoice_by_merchant_seq(env: Env, merchant_id: u64, seq: u64) -> Invoice;
+    fn get_invoice_merchant_seq(env: Env, invoice_id: u64) -> u64;
+    fn get_merchant_invoices(env: Env, merchant_id: u64, offset: u64, limit: u32) -> Vec<Invoice>;
+    fn get_payer_invoices(env: Env, payer: Address, offset: u64, limit: u32) -> Vec<Invoice>;
+    fn preview_payment(env: Env, invoice_id: u64, amount: i128) -> PaymentPreview;
+    fn get_invoice_status(env: Env, invoice_id: u64) -> InvoiceStatus;
+    fn get_invoice_balance(env: Env, invoice_id: u64) -> InvoiceBalance;
+    fn get_invoice_description_hash(env: Env, invoice_id: u64) -> Option<BytesN<32>>;
+    fn verify_invoice_description(env: Env, invoice_id: u64, description: String) -> bool;
+    fn get_invoice_expiry(env: Env, invoice_id: u64) -> Option<u64>;
+    fn set_invoice_due_date(env: Env, caller: Address, invoice_id: u64, due_at: Option<u64>);
+    fn get_invoice_due_date(env: Env, invoice_id: u64) -> Option<u64>;
+    fn mark_overdue(env: Env, invoice_id: u64);
+    fn set_invoice_late_fee(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        policy: Option<LateFeePolicy>,
+    );
+    fn get_invoice_late_fee(env: Env, invoice_id: u64) -> Option<LateFeePolicy>;
+    fn get_amount_due(env: Env, invoice_id: u64) -> i128;
+    fn set_invoice_tax(env: Env, caller: Address, invoice_id: u64, tax: Option<InvoiceTax>);
+    fn get_invoice_tax(env: Env, invoice_id: u64) -> Option<InvoiceTax>;
+    fn pay_invoice_with_tip(env: Env, payer: Address, invoice_id: u64, tip_amount: i128)
+        -> Invoice;
+    fn get_invoice_tip(env: Env, invoice_id: u64) -> i128;
+    fn set_tips_fee_exempt(env: Env, admin: Address, exempt: bool);
+    fn tips_fee_exempt(env: Env) -> bool;
+    fn set_overpayment_policy(env: Env, merchant: Address, policy: OverpaymentPolicy);
+    fn get_overpayment_policy(env: Env, merchant_id: u64) -> OverpaymentPolicy;
+    fn set_invoice_multi_payer(env: Env, caller: Address, invoice_id: u64, multi_payer: bool);
+    fn is_invoice_multi_payer(env: Env, invoice_id: u64) -> bool;
+    fn contribute_to_invoice(env: Env, payer: Address, invoice_id: u64, amount: i128) -> Invoice;
+    fn withdraw_contribution(env: Env, payer: Address, invoice_id: u64) -> i128;
+    fn get_invoice_contribution(env: Env, invoice_id: u64, payer: Address) -> i128;
+    fn get_invoice_contributions(env: Env, invoice_id: u64) -> Map<Address, i128>;
+    fn get_invoice_payer_shares(env: Env, invoice_id: u64) -> Map<Address, i128>;
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64) -> i128;
+    fn refund_invoice_partial(env: Env, caller: Address, invoice_id: u64, amount: i128);
+    fn refund_invoice_to(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        amount: i128,
+        recipient: Address,
+    );
+    fn get_invoice_refunded_amount(env: Env, invoice_id: u64) -> i128;
+    fn set_invoice_refundable(env: Env, caller: Address, invoice_id: u64, refundable: bool);
+    fn is_invoice_refundable(env: Env, invoice_id: u64) -> bool;
+    fn release_invoice_funds(env: Env, invoice_id: u64);
+    fn get_merchant_debt(env: Env, merchant_id: u64, token: Address) -> i128;
+    fn set_invoice_escrow(env: Env, caller: Address, invoice_id: u64, period: Option<u64>);
+    fn get_invoice_escrow_period(env: Env, invoice_id: u64) -> Option<u64>;
+    fn get_escrow_hold(env: Env, invoice_id: u64) -> Option<EscrowHold>;
+    fn confirm_delivery(env: Env, payer: Address, invoice_id: u64);
+    fn release_escrow(env: Env, invoice_id: u64);
+    fn set_invoice_milestones(env: Env, caller: Address, invoice_id: u64, amounts: Vec<i128>);
+    fn get_invoice_milestones(env: Env, invoice_id: u64) -> Vec<Milestone>;
+    fn request_milestone_release(env: Env, merchant: Address, invoice_id: u64, index: u32);
+    fn approve_milestone(env: Env, payer: Address, invoice_id: u64, index: u32);
+    fn dispute_milestone(env: Env, payer: Address, invoice_id: u64, index: u32);
+    fn create_recurring_schedule(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        period: u64,
+        max_invoices: Option<u32>,
+    ) -> u64;
+    fn cancel_recurring_schedule(env: Env, merchant: Address, schedule_id: u64);
+    fn get_recurring_schedule(env: Env, schedule_id: u64) -> RecurringSchedule;
+    fn generate_due_invoices(env: Env, schedule_id: u64) -> Vec<u64>;
+    fn list_expiring_invoices(env: Env, window_secs: u64, page: u32) -> Vec<Invoice>;
+    fn set_default_invoice_expiry(env: Env, admin: Address, duration: Option<u64>);
+    fn get_default_invoice_expiry(env: Env) -> Option<u64>;
+    fn set_merchant_invoice_expiry(env: Env, merchant: Address, policy: InvoiceExpiryPolicy);
+    fn get_merchant_invoice_expiry(env: Env, merchant_id: u64) -> InvoiceExpiryPolicy;
+    fn get_invoices_by_ids(env: Env, invoice_ids: Vec<u64>) -> Vec<Invoice>;
     fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>);
     fn get_merchant_key(env: Env, merchant: Address) -> BytesN<32>;
     fn grant_role(env: Env, admin: Address, user: Address, role: Role);
     fn revoke_role(env: Env, admin: Address, user: Address, role: Role);
+    fn grant_roles(env: Env, admin: Address, grants: Vec<(Address, Role)>);
+    fn revoke_roles(env: Env, admin: Address, revocations: Vec<(Address, Role)>);
     fn has_role(env: Env, user: Address, role: Role) -> bool;
-    fn get_invoices(env: Env, filter: InvoiceFilter) -> Vec<Invoice>;
+    fn get_invoices(
+        env: Env,
+        filter: InvoiceFilter,
+        cursor: u64,
+        limit: u32,
+    ) -> (Vec<Invoice>, Option<u64>);
     fn pause(env: Env, admin: Address);
     fn unpause(env: Env, admin: Address);
     fn is_paused(env: Env) -> bool;
-    fn upgrade(env: Env, new_wasm_hash: BytesN<32>);
+    fn set_deployed_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>);
+    fn propose_upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>);
+    fn execute_upgrade(env: Env);
+    fn cancel_upgrade(env: Env, admin: Address);
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade>;
+    fn rollback(env: Env, admin: Address);
+    fn get_upgrade_history(env: Env) -> Vec<UpgradeRecord>;
+    fn bump_storage(env: Env, admin: Address, keys: Vec<StorageKey>);
+    fn get_schema_version(env: Env) -> u32;
+    fn migrate(env: Env, admin: Address, from_version: u32, args: Vec<Val>);
+    fn create_stream(
+        env: Env,
+        payer: Address,
+        merchant_id: u64,
+        token: Address,
+        amount: i128,
+        start_time: u64,
+        end_time: u64,
+    ) -> u64;
+    fn withdraw_from_stream(env: Env, merchant: Address, stream_id: u64) -> i128;
+    fn cancel_stream(env: Env, caller: Address, stream_id: u64);
+    fn get_stream(env: Env, stream_id: u64) -> Stream;
+    fn get_withdrawable_amount(env: Env, stream_id: u64) -> i128;
+    fn issue_gift_card(
+        env: Env,
+        buyer: Address,
+        merchant_id: u64,
+        token: Address,
+        amount: i128,
+        expires_at: u64,
+        claim_hash: Option<BytesN<32>>,
+    ) -> u64;
+    fn claim_gift_card(env: Env, claimant: Address, card_id: u64, secret: Bytes);
+    fn redeem_gift_card(env: Env, holder: Address, card_id: u64, invoice_id: u64);
+    fn expire_gift_card(env: Env, card_id: u64);
+    fn set_cleanup_bounty(env: Env, admin: Address, token: Address, amount: i128);
+    fn get_cleanup_bounty(env: Env, token: Address) -> i128;
+    fn sweep_expired(
+        env: Env,
+        caller: Address,
+        gift_card_ids: Vec<u64>,
+        payment_link_ids: Vec<u64>,
+    ) -> u32;
+    fn cancel_expired_invoices(env: Env, invoice_ids: Vec<u64>) -> u32;
+    fn get_gift_card(env: Env, card_id: u64) -> GiftCard;
+    fn create_payment_link(
+        env: Env,
+        payer: Address,
+        token: Address,
+        amount: i128,
+        claim_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> u64;
+    fn commit_payment_link_claim(env: Env, claimant: Address, link_id: u64, commitment: BytesN<32>);
+    fn claim_payment_link(
+        env: Env,
+        claimant: Address,
+        link_id: u64,
+        secret: Bytes,
+        invoice_id: Option<u64>,
+    );
+    fn refund_payment_link(env: Env, link_id: u64);
+    fn get_payment_link(env: Env, link_id: u64) -> PaymentLink;
+    fn create_campaign(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        goal: i128,
+        deadline: u64,
+    ) -> u64;
+    fn contribute(env: Env, contributor: Address, campaign_id: u64, amount: i128);
+    fn finalize_campaign(env: Env, campaign_id: u64) -> CampaignStatus;
+    fn claim_refund(env: Env, contributor: Address, campaign_id: u64) -> i128;
+    fn get_campaign(env: Env, campaign_id: u64) -> Campaign;
+    fn get_contribution(env: Env, campaign_id: u64, contributor: Address) -> i128;
+    fn create_settlement_batch(
+        env: Env,
+        operator: Address,
+        merchant_id: u64,
+        token: Address,
+        invoice_ids: Vec<u64>,
+        external_ref: BytesN<32>,
+    ) -> u64;
+    fn mark_settlement_exported(env: Env, operator: Address, batch_id: u64);
+    fn mark_settlement_settled(env: Env, operator: Address, batch_id: u64);
+    fn get_settlement_batch(env: Env, batch_id: u64) -> SettlementBatch;
+    fn get_invoice_settlement_batch(env: Env, invoice_id: u64) -> Option<u64>;
+    fn register_customer(
+        env: Env,
+        customer: Address,
+        preferred_token: Option<Address>,
+        refund_address: Option<Address>,
+        contact_hash: Option<BytesN<32>>,
+    );
+    fn remove_customer(env: Env, customer: Address);
+    fn get_customer(env: Env, customer: Address) -> Option<CustomerProfile>;
+}
+
+pub use shared::account::MerchantAccountTraitClient as MerchantAccountClient;
+
+// Subset of the SEP-40 price feed interface Shade relies on.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: OracleAsset) -> Option<PriceData>;
+}
+
+// Implemented by merchant contracts that want to act on their payments.
+#[contractclient(name = "PaymentHookClient")]
+pub trait PaymentHook {
+    fn on_payment(env: Env, invoice_id: u64, payer: Address, amount: i128, token: Address);
 }
@@ -6,5 +6,8 @@ pub mod interface;
 pub mod shade;
 pub mod types;
 
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
 #[cfg(test)]
 pub mod tests;
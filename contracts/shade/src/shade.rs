@@ -1,13 +1,41 @@
 use crate::components::{
-    access_control as access_control_component, admin as admin_component, core as core_component,
-    invoice as invoice_component, merchant as merchant_component, pausable as pausable_component,
-    upgrade as upgrade_component,
+    access_control as access_control_component, activity as activity_component,
+    admin as admin_component, affiliate as affiliate_component, allowlist as allowlist_component,
+    amendment as amendment_component, approval as approval_component,
+    blocklist as blocklist_component, bond as bond_component, campaign as campaign_component,
+    cleanup as cleanup_component, contribution as contribution_component, core as core_component,
+    custody as custody_component, customer as customer_component,
+    distribution as distribution_component, due_date as due_date_component,
+    escrow as escrow_component, expiry as expiry_component, gift_card as gift_card_component,
+    governance as governance_component, invoice as invoice_component,
+    late_fee as late_fee_component, merchant as merchant_component,
+    merchant_account as merchant_account_component, migration as migration_component,
+    milestone as milestone_component, oracle as oracle_component,
+    overpayment as overpayment_component, pausable as pausable_component,
+    payment_hook as payment_hook_component, payment_link as payment_link_component,
+    rate_limit as rate_limit_component, recurring as recurring_component,
+    refund as refund_component, routing as routing_component, settlement as settlement_component,
+    stats as stats_component, stream as stream_component, tax as tax_component,
+    tip as tip_component, ttl as ttl_component, upgrade as upgrade_component,
+    velocity as velocity_component,
 };
 use crate::errors::ContractError;
 use crate::events;
 use crate::interface::ShadeTrait;
-use crate::types::{ContractInfo, DataKey, Invoice, InvoiceFilter, Merchant, MerchantFilter, Role};
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, String, Vec};
+use crate::types::{
+    ActivityRecord, AffiliateEarnings, AmountBounds, Campaign, CampaignStatus, ContractInfo,
+    Council, CustomerProfile, DataKey, DistributionShare, EntityCounts, EscrowHold, GiftCard,
+    Invoice, InvoiceAmendment, InvoiceBalance, InvoiceExpiryPolicy, InvoiceFilter,
+    InvoiceRateLimit, InvoiceStatus, InvoiceTax, LateFeePolicy, LineItem, Merchant, MerchantBond,
+    MerchantFilter, Milestone, OracleConfig, OverpaymentPolicy, ParameterChange, PaymentLink,
+    PaymentPreview, PaymentRoute, PendingUpgrade, Proposal, ProtocolConfig, ProtocolStats,
+    RecurringSchedule, Role, SettlementBatch, StorageKey, Stream, TokenMetadata, UpgradeRecord,
+    VelocityLimit,
+};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, Bytes, BytesN, Env, Map, String, Symbol,
+    Val, Vec,
+};
 
 #[contract]
 pub struct Shade;
@@ -21,17 +49,45 @@ impl ShadeTrait for Shade {
         let contract_info = ContractInfo {
             admin: admin.clone(),
             timestamp: env.ledger().timestamp(),
+            version: core_component::CONTRACT_VERSION,
         };
         env.storage().persistent().set(&DataKey::Admin, &admin);
         env.storage()
             .persistent()
             .set(&DataKey::ContractInfo, &contract_info);
+        ttl_component::extend_persistent(&env, &DataKey::Admin);
+        ttl_component::extend_persistent(&env, &DataKey::ContractInfo);
+        migration_component::set_schema_version(&env, migration_component::SCHEMA_VERSION);
         events::publish_initialized_event(&env, admin, env.ledger().timestamp());
     }
     fn get_admin(env: Env) -> Address {
         core_component::get_admin(&env)
     }
 
+    fn get_version(_env: Env) -> (u32, u32, u32) {
+        core_component::get_version()
+    }
+
+    fn get_contract_info(env: Env) -> ContractInfo {
+        core_component::get_contract_info(&env)
+    }
+
+    fn get_config(env: Env) -> ProtocolConfig {
+        core_component::get_config(&env)
+    }
+
+    fn get_counts(env: Env) -> EntityCounts {
+        core_component::get_counts(&env)
+    }
+
+    fn get_protocol_stats(env: Env) -> ProtocolStats {
+        stats_component::get_protocol_stats(&env)
+    }
+
+    fn get_recent_activity(env: Env, limit: u32) -> Vec<ActivityRecord> {
+        activity_component::get_recent_activity(&env, limit)
+    }
+
     fn add_accepted_token(env: Env, admin: Address, token: Address) {
         pausable_component::assert_not_paused(&env);
         admin_component::add_accepted_token(&env, &admin, &token);
@@ -46,6 +102,106 @@ impl ShadeTrait for Shade {
         admin_component::is_accepted_token(&env, &token)
     }
 
+    fn get_token_metadata(env: Env, token: Address) -> Option<TokenMetadata> {
+        admin_component::get_token_metadata(&env, &token)
+    }
+
+    fn set_native_token(env: Env, admin: Address, token: Address) {
+        admin_component::set_native_token(&env, &admin, &token);
+    }
+
+    fn get_native_token(env: Env) -> Option<Address> {
+        admin_component::get_native_token(&env)
+    }
+
+    fn set_clawback_asset(env: Env, admin: Address, token: Address, enabled: bool) {
+        pausable_component::assert_not_paused(&env);
+        admin_component::set_clawback_asset(&env, &admin, &token, enabled);
+    }
+
+    fn is_clawback_asset(env: Env, token: Address) -> bool {
+        admin_component::is_clawback_asset(&env, &token)
+    }
+
+    fn set_reject_clawback_assets(env: Env, admin: Address, reject: bool) {
+        pausable_component::assert_not_paused(&env);
+        admin_component::set_reject_clawback_assets(&env, &admin, reject);
+    }
+
+    fn rejects_clawback_assets(env: Env) -> bool {
+        admin_component::rejects_clawback_assets(&env)
+    }
+
+    fn set_refund_protocol_fees(env: Env, admin: Address, enabled: bool) {
+        pausable_component::assert_not_paused(&env);
+        admin_component::set_refund_protocol_fees(&env, &admin, enabled);
+    }
+
+    fn refunds_protocol_fees(env: Env) -> bool {
+        admin_component::refunds_protocol_fees(&env)
+    }
+
+    fn set_refund_subsidy(env: Env, admin: Address, enabled: bool) {
+        pausable_component::assert_not_paused(&env);
+        admin_component::set_refund_subsidy(&env, &admin, enabled);
+    }
+
+    fn subsidizes_refunds(env: Env) -> bool {
+        admin_component::subsidizes_refunds(&env)
+    }
+
+    fn add_trusted_contract(env: Env, admin: Address, contract: Address) {
+        allowlist_component::add_trusted_contract(&env, &admin, &contract);
+    }
+
+    fn remove_trusted_contract(env: Env, admin: Address, contract: Address) {
+        allowlist_component::remove_trusted_contract(&env, &admin, &contract);
+    }
+
+    fn is_trusted_contract(env: Env, contract: Address) -> bool {
+        allowlist_component::is_trusted_contract(&env, &contract)
+    }
+
+    fn block_payer(env: Env, caller: Address, payer: Address) {
+        blocklist_component::block_payer(&env, &caller, &payer);
+    }
+
+    fn unblock_payer(env: Env, caller: Address, payer: Address) {
+        blocklist_component::unblock_payer(&env, &caller, &payer);
+    }
+
+    fn is_payer_blocked(env: Env, payer: Address) -> bool {
+        blocklist_component::is_payer_blocked(&env, &payer)
+    }
+
+    fn set_velocity_limit(env: Env, admin: Address, token: Address, max_amount: i128, window: u64) {
+        velocity_component::set_velocity_limit(&env, &admin, &token, max_amount, window);
+    }
+
+    fn remove_velocity_limit(env: Env, admin: Address, token: Address) {
+        velocity_component::remove_velocity_limit(&env, &admin, &token);
+    }
+
+    fn get_velocity_limit(env: Env, token: Address) -> Option<VelocityLimit> {
+        velocity_component::get_velocity_limit(&env, &token)
+    }
+
+    fn get_payer_volume(env: Env, payer: Address, token: Address) -> i128 {
+        velocity_component::get_payer_volume(&env, &payer, &token)
+    }
+
+    fn set_oracle(env: Env, admin: Address, oracle: Address, max_price_age: u64) {
+        oracle_component::set_oracle(&env, &admin, &oracle, max_price_age);
+    }
+
+    fn get_oracle(env: Env) -> Option<OracleConfig> {
+        oracle_component::get_oracle(&env)
+    }
+
+    fn get_token_price(env: Env, token: Address) -> i128 {
+        oracle_component::get_price(&env, &token)
+    }
+
     fn set_fee(env: Env, admin: Address, token: Address, fee: i128) {
         pausable_component::assert_not_paused(&env);
         admin_component::set_fee(&env, &admin, &token, fee);
@@ -55,23 +211,139 @@ impl ShadeTrait for Shade {
         admin_component::get_fee(&env, &token)
     }
 
-    fn register_merchant(env: Env, merchant: Address) {
+    fn set_fee_exempt(env: Env, admin: Address, address: Address, exempt: bool) {
+        admin_component::set_fee_exempt(&env, &admin, &address, exempt);
+    }
+
+    fn is_fee_exempt(env: Env, address: Address) -> bool {
+        admin_component::is_fee_exempt(&env, &address)
+    }
+
+    fn get_collected_fees(env: Env, token: Address) -> i128 {
+        admin_component::get_collected_fees(&env, &token)
+    }
+
+    fn get_recoverable_balance(env: Env, token: Address) -> i128 {
+        custody_component::get_recoverable_balance(&env, &token)
+    }
+
+    fn recover_tokens(env: Env, admin: Address, token: Address, amount: i128, to: Address) {
+        pausable_component::assert_not_paused(&env);
+        custody_component::recover_tokens(&env, &admin, &token, amount, &to);
+    }
+
+    fn set_distribution_shares(env: Env, admin: Address, shares: Vec<DistributionShare>) {
+        distribution_component::set_distribution_shares(&env, &admin, &shares);
+    }
+
+    fn get_distribution_shares(env: Env) -> Vec<DistributionShare> {
+        distribution_component::get_distribution_shares(&env)
+    }
+
+    fn get_undistributed_fees(env: Env, token: Address) -> i128 {
+        distribution_component::get_undistributed_fees(&env, &token)
+    }
+
+    fn distribute_fees(env: Env, token: Address) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        distribution_component::distribute_fees(&env, &token)
+    }
+
+    fn get_claimable_distribution(env: Env, recipient: Address, token: Address) -> i128 {
+        distribution_component::get_claimable_distribution(&env, &recipient, &token)
+    }
+
+    fn claim_distribution(env: Env, recipient: Address, token: Address) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        distribution_component::claim_distribution(&env, &recipient, &token)
+    }
+
+    fn set_council(env: Env, admin: Address, members: Vec<Address>, threshold: u32) {
+        governance_component::set_council(&env, &admin, &members, threshold);
+    }
+
+    fn get_council(env: Env) -> Option<Council> {
+        governance_component::get_council(&env)
+    }
+
+    fn propose_change(env: Env, proposer: Address, change: ParameterChange) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        governance_component::propose_change(&env, &proposer, &change)
+    }
+
+    fn vote_on_proposal(env: Env, member: Address, proposal_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        governance_component::vote_on_proposal(&env, &member, proposal_id);
+    }
+
+    fn execute_proposal(env: Env, proposal_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        governance_component::execute_proposal(&env, proposal_id);
+    }
+
+    fn cancel_proposal(env: Env, admin: Address, proposal_id: u64) {
+        governance_component::cancel_proposal(&env, &admin, proposal_id);
+    }
+
+    fn get_proposal(env: Env, proposal_id: u64) -> Proposal {
+        governance_component::get_proposal(&env, proposal_id)
+    }
+
+    fn set_amount_bounds(env: Env, admin: Address, token: Address, bounds: Option<AmountBounds>) {
+        admin_component::set_amount_bounds(&env, &admin, &token, &bounds);
+    }
+
+    fn get_amount_bounds(env: Env, token: Address) -> Option<AmountBounds> {
+        admin_component::get_amount_bounds(&env, &token)
+    }
+
+    fn set_legacy_event_format(env: Env, admin: Address, enabled: bool) {
+        admin_component::set_legacy_event_format(&env, &admin, enabled);
+    }
+
+    fn is_legacy_event_format(env: Env) -> bool {
+        admin_component::is_legacy_event_format(&env)
+    }
+
+    fn register_merchant(env: Env, merchant: Address) -> u64 {
         pausable_component::assert_not_paused(&env);
-        merchant_component::register_merchant(&env, &merchant);
+        merchant_component::register_merchant(&env, &merchant)
     }
 
     fn get_merchant(env: Env, merchant_id: u64) -> Merchant {
         merchant_component::get_merchant(&env, merchant_id)
     }
 
-    fn get_merchants(env: Env, filter: MerchantFilter) -> Vec<Merchant> {
-        merchant_component::get_merchants(&env, filter)
+    fn get_merchants(
+        env: Env,
+        filter: MerchantFilter,
+        cursor: u64,
+        limit: u32,
+    ) -> (Vec<Merchant>, Option<u64>) {
+        merchant_component::get_merchants(&env, filter, cursor, limit)
     }
 
     fn is_merchant(env: Env, merchant: Address) -> bool {
         merchant_component::is_merchant(&env, &merchant)
     }
 
+    fn set_default_invoice_rate_limit(env: Env, admin: Address, limit: Option<InvoiceRateLimit>) {
+        rate_limit_component::set_default_invoice_rate_limit(&env, &admin, &limit);
+    }
+
+    fn set_merchant_invoice_rate_limit(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        limit: Option<InvoiceRateLimit>,
+    ) {
+        rate_limit_component::set_merchant_invoice_rate_limit(&env, &admin, merchant_id, &limit);
+    }
+
+    fn get_invoice_rate_limit(env: Env, merchant_id: u64) -> Option<InvoiceRateLimit> {
+        rate_limit_component::get_invoice_rate_limit(&env, merchant_id)
+    }
+
     fn set_merchant_status(env: Env, admin: Address, merchant_id: u64, status: bool) {
         merchant_component::set_merchant_status(&env, &admin, merchant_id, status);
     }
@@ -88,6 +360,103 @@ impl ShadeTrait for Shade {
         merchant_component::is_merchant_verified(&env, merchant_id)
     }
 
+    fn approve_merchant(env: Env, caller: Address, merchant_id: u64) {
+        merchant_component::approve_merchant(&env, &caller, merchant_id);
+    }
+
+    fn is_merchant_approved(env: Env, merchant_id: u64) -> bool {
+        merchant_component::is_merchant_approved(&env, merchant_id)
+    }
+
+    fn set_permissionless_registration(env: Env, admin: Address, enabled: bool) {
+        merchant_component::set_permissionless_registration(&env, &admin, enabled);
+    }
+
+    fn is_permissionless_registration(env: Env) -> bool {
+        merchant_component::is_permissionless_registration(&env)
+    }
+
+    fn offboard_merchant(env: Env, merchant: Address) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::offboard_merchant(&env, &merchant);
+    }
+
+    fn link_merchant_account(env: Env, merchant: Address, account: Address) {
+        pausable_component::assert_not_paused(&env);
+        merchant_account_component::link_merchant_account(&env, &merchant, &account);
+    }
+
+    fn get_merchant_account(env: Env, merchant_id: u64) -> Option<Address> {
+        merchant_account_component::get_merchant_account(&env, merchant_id)
+    }
+
+    fn set_merchant_account_restricted(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        restricted: bool,
+    ) {
+        merchant_account_component::set_merchant_account_restricted(
+            &env,
+            &admin,
+            merchant_id,
+            restricted,
+        );
+    }
+
+    fn approve_merchant_withdrawal(env: Env, admin: Address, merchant_id: u64, request_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        merchant_account_component::approve_merchant_withdrawal(
+            &env,
+            &admin,
+            merchant_id,
+            request_id,
+        );
+    }
+
+    fn withdraw_merchant_funds(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        amount: i128,
+        to: Address,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        merchant_account_component::withdraw_merchant_funds(&env, &merchant, &token, amount, &to);
+    }
+
+    fn set_registration_bond(env: Env, admin: Address, bond: Option<MerchantBond>) {
+        bond_component::set_registration_bond(&env, &admin, &bond);
+    }
+
+    fn get_registration_bond(env: Env) -> Option<MerchantBond> {
+        bond_component::get_registration_bond(&env)
+    }
+
+    fn get_merchant_bond(env: Env, merchant_id: u64) -> Option<MerchantBond> {
+        bond_component::get_merchant_bond(&env, merchant_id)
+    }
+
+    fn get_merchant_bond_release_at(env: Env, merchant_id: u64) -> Option<u64> {
+        bond_component::get_bond_release_at(&env, merchant_id)
+    }
+
+    fn withdraw_bond(env: Env, merchant: Address) {
+        pausable_component::assert_not_paused(&env);
+        bond_component::withdraw_bond(&env, &merchant);
+    }
+
+    fn slash_merchant_bond(
+        env: Env,
+        admin: Address,
+        merchant_id: u64,
+        amount: i128,
+        recipient: Address,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        bond_component::slash_bond(&env, &admin, merchant_id, amount, &recipient);
+    }
+
     fn create_invoice(
         env: Env,
         merchant: Address,
@@ -96,50 +465,885 @@ impl ShadeTrait for Shade {
         token: Address,
     ) -> u64 {
         pausable_component::assert_not_paused(&env);
-        invoice_component::create_invoice(&env, &merchant, &description, amount, &token)
+        invoice_component::create_invoice(
+            &env,
+            &merchant,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            None,
+        )
     }
 
-    fn get_invoice(env: Env, invoice_id: u64) -> Invoice {
-        invoice_component::get_invoice(&env, invoice_id)
+    fn create_delegated_invoice(
+        env: Env,
+        delegate: Address,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_invoice(
+            &env,
+            &delegate,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            None,
+        )
     }
 
-    fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>) {
-        merchant_component::set_merchant_key(&env, &merchant, &key);
+    fn delegate_invoice_creation(env: Env, merchant: Address, delegate: Address, enabled: bool) {
+        pausable_component::assert_not_paused(&env);
+        merchant_component::delegate_invoice_creation(&env, &merchant, &delegate, enabled);
     }
 
-    fn get_merchant_key(env: Env, merchant: Address) -> BytesN<32> {
-        merchant_component::get_merchant_key(&env, &merchant)
+    fn is_invoice_delegate(env: Env, merchant_id: u64, delegate: Address) -> bool {
+        merchant_component::is_invoice_delegate(&env, merchant_id, &delegate)
     }
 
-    fn grant_role(env: Env, admin: Address, user: Address, role: Role) {
-        access_control_component::grant_role(&env, &admin, &user, role);
+    fn set_invoice_approval_threshold(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        threshold: Option<i128>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        approval_component::set_invoice_approval_threshold(&env, &merchant, &token, threshold);
     }
 
-    fn revoke_role(env: Env, admin: Address, user: Address, role: Role) {
-        access_control_component::revoke_role(&env, &admin, &user, role);
+    fn get_invoice_approval_threshold(env: Env, merchant_id: u64, token: Address) -> Option<i128> {
+        approval_component::get_invoice_approval_threshold(&env, merchant_id, &token)
     }
 
-    fn has_role(env: Env, user: Address, role: Role) -> bool {
-        access_control_component::has_role(&env, &user, role)
+    fn approve_invoice(env: Env, approver: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        approval_component::approve_invoice(&env, &approver, invoice_id);
     }
 
-    fn get_invoices(env: Env, filter: InvoiceFilter) -> Vec<Invoice> {
-        invoice_component::get_invoices(&env, filter)
+    fn is_invoice_awaiting_approval(env: Env, invoice_id: u64) -> bool {
+        approval_component::is_invoice_awaiting_approval(&env, invoice_id)
     }
 
-    fn pause(env: Env, admin: Address) {
-        pausable_component::pause(&env, &admin);
+    fn set_payment_routes(env: Env, merchant: Address, routes: Vec<PaymentRoute>) {
+        pausable_component::assert_not_paused(&env);
+        routing_component::set_payment_routes(&env, &merchant, &routes);
     }
 
-    fn unpause(env: Env, admin: Address) {
-        pausable_component::unpause(&env, &admin);
+    fn get_payment_routes(env: Env, merchant_id: u64) -> Vec<PaymentRoute> {
+        routing_component::get_payment_routes(&env, merchant_id)
     }
 
-    fn is_paused(env: Env) -> bool {
-        pausable_component::is_paused(&env)
+    fn set_payment_hook(env: Env, merchant: Address, hook: Option<Address>) {
+        pausable_component::assert_not_paused(&env);
+        payment_hook_component::set_payment_hook(&env, &merchant, &hook);
+    }
+
+    fn get_payment_hook(env: Env, merchant_id: u64) -> Option<Address> {
+        payment_hook_component::get_payment_hook(&env, merchant_id)
+    }
+
+    fn create_invoice_with_expiry(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        expires_at: Option<u64>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_invoice(
+            &env,
+            &merchant,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            expires_at,
+        )
+    }
+
+    fn create_private_invoice(
+        env: Env,
+        merchant: Address,
+        description_hash: BytesN<32>,
+        amount: i128,
+        token: Address,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_private_invoice(
+            &env,
+            &merchant,
+            &description_hash,
+            amount,
+            &token,
+        )
+    }
+
+    fn create_itemized_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        line_items: Vec<LineItem>,
+        amount: i128,
+        token: Address,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_itemized_invoice(
+            &env,
+            &merchant,
+            &description,
+            &line_items,
+            amount,
+            &token,
+        )
+    }
+
+    fn get_invoice_line_items(env: Env, invoice_id: u64) -> Vec<LineItem> {
+        invoice_component::get_invoice_line_items(&env, invoice_id)
+    }
+
+    fn create_addressed_invoice(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        payer_allowlist: Vec<Address>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::create_addressed_invoice(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            &payer_allowlist,
+        )
+    }
+
+    fn get_invoice_payer_allowlist(env: Env, invoice_id: u64) -> Vec<Address> {
+        invoice_component::get_invoice_payer_allowlist(&env, invoice_id)
+    }
+
+    fn set_invoice_metadata(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        metadata: Map<Symbol, String>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::set_invoice_metadata(&env, &caller, invoice_id, &metadata);
+    }
+
+    fn get_invoice_metadata(env: Env, invoice_id: u64) -> Map<Symbol, String> {
+        invoice_component::get_invoice_metadata(&env, invoice_id)
+    }
+
+    fn amend_invoice(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        new_amount: Option<i128>,
+        new_description: Option<String>,
+        new_expires_at: Option<u64>,
+        new_token: Option<Address>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        amendment_component::amend_invoice(
+            &env,
+            &caller,
+            invoice_id,
+            new_amount,
+            new_description,
+            new_expires_at,
+            new_token,
+        );
+    }
+
+    fn get_invoice_history(env: Env, invoice_id: u64) -> Vec<InvoiceAmendment> {
+        amendment_component::get_invoice_history(&env, invoice_id)
+    }
+
+    fn pay_invoice(env: Env, payer: Address, invoice_id: u64) -> Invoice {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoice(&env, &payer, invoice_id)
+    }
+
+    fn pay_invoice_with_affiliate(
+        env: Env,
+        payer: Address,
+        invoice_id: u64,
+        affiliate: Address,
+    ) -> Invoice {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoice_with_affiliate(&env, &payer, invoice_id, &affiliate)
+    }
+
+    fn set_affiliate_commission(env: Env, merchant: Address, commission_bps: u32) {
+        pausable_component::assert_not_paused(&env);
+        affiliate_component::set_affiliate_commission(&env, &merchant, commission_bps);
+    }
+
+    fn get_affiliate_commission(env: Env, merchant_id: u64) -> u32 {
+        affiliate_component::get_affiliate_commission(&env, merchant_id)
+    }
+
+    fn get_affiliate_earnings(env: Env, affiliate: Address, token: Address) -> AffiliateEarnings {
+        affiliate_component::get_affiliate_earnings(&env, &affiliate, &token)
+    }
+
+    fn claim_affiliate_commission(env: Env, affiliate: Address, token: Address) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        affiliate_component::claim_affiliate_commission(&env, &affiliate, &token)
+    }
+
+    fn pay_invoice_on_behalf(
+        env: Env,
+        funder: Address,
+        beneficiary: Address,
+        invoice_id: u64,
+    ) -> Invoice {
+        pausable_component::assert_not_paused(&env);
+        invoice_component::pay_invoice_on_behalf(&env, &funder, &beneficiary, invoice_id)
+    }
+
+    fn get_invoice(env: Env, invoice_id: u64) -> Invoice {
+        invoice_component::get_invoice(&env, invoice_id)
+    }
+
+    fn get_invoice_by_merchant_seq(env: Env, merchant_id: u64, seq: u64) -> Invoice {
+        invoice_component::get_invoice_by_merchant_seq(&env, merchant_id, seq)
+    }
+
+    fn get_invoice_merchant_seq(env: Env, invoice_id: u64) -> u64 {
+        invoice_component::get_invoice_merchant_seq(&env, invoice_id)
+    }
+
+    fn get_merchant_invoices(env: Env, merchant_id: u64, offset: u64, limit: u32) -> Vec<Invoice> {
+        invoice_component::get_merchant_invoices(&env, merchant_id, offset, limit)
+    }
+
+    fn get_payer_invoices(env: Env, payer: Address, offset: u64, limit: u32) -> Vec<Invoice> {
+        invoice_component::get_payer_invoices(&env, &payer, offset, limit)
+    }
+
+    fn preview_payment(env: Env, invoice_id: u64, amount: i128) -> PaymentPreview {
+        invoice_component::preview_payment(&env, invoice_id, amount)
+    }
+
+    fn get_invoice_status(env: Env, invoice_id: u64) -> InvoiceStatus {
+        invoice_component::get_invoice_status(&env, invoice_id)
+    }
+
+    fn get_invoice_balance(env: Env, invoice_id: u64) -> InvoiceBalance {
+        invoice_component::get_invoice_balance(&env, invoice_id)
+    }
+
+    fn get_invoice_description_hash(env: Env, invoice_id: u64) -> Option<BytesN<32>> {
+        invoice_component::get_invoice_description_hash(&env, invoice_id)
+    }
+
+    fn verify_invoice_description(env: Env, invoice_id: u64, description: String) -> bool {
+        invoice_component::verify_invoice_description(&env, invoice_id, &description)
+    }
+
+    fn get_invoice_expiry(env: Env, invoice_id: u64) -> Option<u64> {
+        expiry_component::get_invoice_expiry(&env, invoice_id)
+    }
+
+    fn set_invoice_due_date(env: Env, caller: Address, invoice_id: u64, due_at: Option<u64>) {
+        pausable_component::assert_not_paused(&env);
+        due_date_component::set_invoice_due_date(&env, &caller, invoice_id, due_at);
+    }
+
+    fn get_invoice_due_date(env: Env, invoice_id: u64) -> Option<u64> {
+        due_date_component::get_invoice_due_date(&env, invoice_id)
+    }
+
+    fn mark_overdue(env: Env, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        due_date_component::mark_overdue(&env, invoice_id);
+    }
+
+    fn set_invoice_late_fee(
+        env: Env,
+        caller: Address,
+        invoice_id: u64,
+        policy: Option<LateFeePolicy>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        late_fee_component::set_invoice_late_fee(&env, &caller, invoice_id, policy);
+    }
+
+    fn get_invoice_late_fee(env: Env, invoice_id: u64) -> Option<LateFeePolicy> {
+        late_fee_component::get_invoice_late_fee(&env, invoice_id)
+    }
+
+    fn get_amount_due(env: Env, invoice_id: u64) -> i128 {
+        let invoice = invoice_component::get_invoice(&env, invoice_id);
+        invoice_component::amount_due(&env, &invoice)
+    }
+
+    fn set_invoice_tax(env: Env, caller: Address, invoice_id: u64, tax: Option<InvoiceTax>) {
+        pausable_component::assert_not_paused(&env);
+        tax_component::set_invoice_tax(&env, &caller, invoice_id, tax);
+    }
+
+    fn get_invoice_tax(env: Env, invoice_id: u64) -> Option<InvoiceTax> {
+        tax_component::get_invoice_tax(&env, invoice_id)
+    }
+
+    fn pay_invoice_with_tip(
+        env: Env,
+        payer: Address,
+        invoice_id: u64,
+        tip_amount: i128,
+    ) -> Invoice {
+        pausable_component::assert_not_paused(&env);
+        tip_component::pay_invoice_with_tip(&env, &payer, invoice_id, tip_amount)
+    }
+
+    fn get_invoice_tip(env: Env, invoice_id: u64) -> i128 {
+        tip_component::get_invoice_tip(&env, invoice_id)
+    }
+
+    fn set_tips_fee_exempt(env: Env, admin: Address, exempt: bool) {
+        pausable_component::assert_not_paused(&env);
+        tip_component::set_tips_fee_exempt(&env, &admin, exempt);
+    }
+
+    fn tips_fee_exempt(env: Env) -> bool {
+        tip_component::tips_fee_exempt(&env)
+    }
+
+    fn set_overpayment_policy(env: Env, merchant: Address, policy: OverpaymentPolicy) {
+        pausable_component::assert_not_paused(&env);
+        overpayment_component::set_overpayment_policy(&env, &merchant, policy);
+    }
+
+    fn get_overpayment_policy(env: Env, merchant_id: u64) -> OverpaymentPolicy {
+        overpayment_component::get_overpayment_policy(&env, merchant_id)
+    }
+
+    fn set_invoice_multi_payer(env: Env, caller: Address, invoice_id: u64, multi_payer: bool) {
+        pausable_component::assert_not_paused(&env);
+        contribution_component::set_invoice_multi_payer(&env, &caller, invoice_id, multi_payer);
+    }
+
+    fn is_invoice_multi_payer(env: Env, invoice_id: u64) -> bool {
+        contribution_component::is_multi_payer(&env, invoice_id)
+    }
+
+    fn contribute_to_invoice(env: Env, payer: Address, invoice_id: u64, amount: i128) -> Invoice {
+        pausable_component::assert_not_paused(&env);
+        contribution_component::contribute_to_invoice(&env, &payer, invoice_id, amount)
+    }
+
+    fn withdraw_contribution(env: Env, payer: Address, invoice_id: u64) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        contribution_component::withdraw_contribution(&env, &payer, invoice_id)
+    }
+
+    fn get_invoice_contribution(env: Env, invoice_id: u64, payer: Address) -> i128 {
+        contribution_component::get_contribution(&env, invoice_id, &payer)
+    }
+
+    fn get_invoice_contributions(env: Env, invoice_id: u64) -> Map<Address, i128> {
+        contribution_component::get_contributions(&env, invoice_id)
+    }
+
+    fn get_invoice_payer_shares(env: Env, invoice_id: u64) -> Map<Address, i128> {
+        contribution_component::get_payer_shares(&env, invoice_id)
+    }
+
+    fn refund_invoice(env: Env, caller: Address, invoice_id: u64) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        refund_component::refund_invoice(&env, &caller, invoice_id)
+    }
+
+    fn refund_invoice_partial(env: Env, caller: Address, invoice_id: u64, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        refund_component::refund_invoice_partial(&env, &caller, invoice_id, amount);
+    }
+
+    fn refund_invoice_to(
+        env: Env,
+        merchant: Address,
+        invoice_id: u64,
+        amount: i128,
+        recipient: Address,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        refund_component::refund_invoice_to(&env, &merchant, invoice_id, amount, &recipient);
+    }
+
+    fn get_invoice_refunded_amount(env: Env, invoice_id: u64) -> i128 {
+        refund_component::get_refunded_amount(&env, invoice_id)
+    }
+
+    fn set_invoice_refundable(env: Env, caller: Address, invoice_id: u64, refundable: bool) {
+        pausable_component::assert_not_paused(&env);
+        refund_component::set_invoice_refundable(&env, &caller, invoice_id, refundable);
+    }
+
+    fn is_invoice_refundable(env: Env, invoice_id: u64) -> bool {
+        refund_component::is_invoice_refundable(&env, invoice_id)
+    }
+
+    fn release_invoice_funds(env: Env, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        refund_component::release_invoice_funds(&env, invoice_id);
+    }
+
+    fn get_merchant_debt(env: Env, merchant_id: u64, token: Address) -> i128 {
+        refund_component::get_merchant_debt(&env, merchant_id, &token)
+    }
+
+    fn set_invoice_escrow(env: Env, caller: Address, invoice_id: u64, period: Option<u64>) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::set_invoice_escrow(&env, &caller, invoice_id, period);
+    }
+
+    fn get_invoice_escrow_period(env: Env, invoice_id: u64) -> Option<u64> {
+        escrow_component::get_invoice_escrow_period(&env, invoice_id)
+    }
+
+    fn get_escrow_hold(env: Env, invoice_id: u64) -> Option<EscrowHold> {
+        escrow_component::get_escrow_hold(&env, invoice_id)
+    }
+
+    fn confirm_delivery(env: Env, payer: Address, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::confirm_delivery(&env, &payer, invoice_id);
+    }
+
+    fn release_escrow(env: Env, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        escrow_component::release_escrow(&env, invoice_id);
+    }
+
+    fn set_invoice_milestones(env: Env, caller: Address, invoice_id: u64, amounts: Vec<i128>) {
+        pausable_component::assert_not_paused(&env);
+        milestone_component::set_invoice_milestones(&env, &caller, invoice_id, &amounts);
+    }
+
+    fn get_invoice_milestones(env: Env, invoice_id: u64) -> Vec<Milestone> {
+        milestone_component::get_invoice_milestones(&env, invoice_id)
+    }
+
+    fn request_milestone_release(env: Env, merchant: Address, invoice_id: u64, index: u32) {
+        pausable_component::assert_not_paused(&env);
+        milestone_component::request_milestone_release(&env, &merchant, invoice_id, index);
+    }
+
+    fn approve_milestone(env: Env, payer: Address, invoice_id: u64, index: u32) {
+        pausable_component::assert_not_paused(&env);
+        milestone_component::approve_milestone(&env, &payer, invoice_id, index);
+    }
+
+    fn dispute_milestone(env: Env, payer: Address, invoice_id: u64, index: u32) {
+        pausable_component::assert_not_paused(&env);
+        milestone_component::dispute_milestone(&env, &payer, invoice_id, index);
+    }
+
+    fn create_recurring_schedule(
+        env: Env,
+        merchant: Address,
+        description: String,
+        amount: i128,
+        token: Address,
+        period: u64,
+        max_invoices: Option<u32>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        recurring_component::create_recurring_schedule(
+            &env,
+            &merchant,
+            &description,
+            amount,
+            &token,
+            period,
+            max_invoices,
+        )
+    }
+
+    fn cancel_recurring_schedule(env: Env, merchant: Address, schedule_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        recurring_component::cancel_recurring_schedule(&env, &merchant, schedule_id);
+    }
+
+    fn get_recurring_schedule(env: Env, schedule_id: u64) -> RecurringSchedule {
+        recurring_component::get_recurring_schedule(&env, schedule_id)
+    }
+
+    fn generate_due_invoices(env: Env, schedule_id: u64) -> Vec<u64> {
+        pausable_component::assert_not_paused(&env);
+        recurring_component::generate_due_invoices(&env, schedule_id)
+    }
+
+    fn list_expiring_invoices(env: Env, window_secs: u64, page: u32) -> Vec<Invoice> {
+        expiry_component::list_expiring_invoices(&env, window_secs, page)
+    }
+
+    fn set_default_invoice_expiry(env: Env, admin: Address, duration: Option<u64>) {
+        expiry_component::set_default_invoice_expiry(&env, &admin, duration);
+    }
+
+    fn get_default_invoice_expiry(env: Env) -> Option<u64> {
+        expiry_component::get_default_invoice_expiry(&env)
+    }
+
+    fn set_merchant_invoice_expiry(env: Env, merchant: Address, policy: InvoiceExpiryPolicy) {
+        expiry_component::set_merchant_invoice_expiry(&env, &merchant, &policy);
+    }
+
+    fn get_merchant_invoice_expiry(env: Env, merchant_id: u64) -> InvoiceExpiryPolicy {
+        expiry_component::get_merchant_invoice_expiry(&env, merchant_id)
+    }
+
+    fn get_invoices_by_ids(env: Env, invoice_ids: Vec<u64>) -> Vec<Invoice> {
+        invoice_component::get_invoices_by_ids(&env, invoice_ids)
+    }
+
+    fn set_merchant_key(env: Env, merchant: Address, key: BytesN<32>) {
+        merchant_component::set_merchant_key(&env, &merchant, &key);
+    }
+
+    fn get_merchant_key(env: Env, merchant: Address) -> BytesN<32> {
+        merchant_component::get_merchant_key(&env, &merchant)
+    }
+
+    fn grant_role(env: Env, admin: Address, user: Address, role: Role) {
+        access_control_component::grant_role(&env, &admin, &user, role);
+    }
+
+    fn revoke_role(env: Env, admin: Address, user: Address, role: Role) {
+        access_control_component::revoke_role(&env, &admin, &user, role);
+    }
+
+    fn grant_roles(env: Env, admin: Address, grants: Vec<(Address, Role)>) {
+        access_control_component::grant_roles(&env, &admin, &grants);
+    }
+
+    fn revoke_roles(env: Env, admin: Address, revocations: Vec<(Address, Role)>) {
+        access_control_component::revoke_roles(&env, &admin, &revocations);
+    }
+
+    fn has_role(env: Env, user: Address, role: Role) -> bool {
+        access_control_component::has_role(&env, &user, role)
+    }
+
+    fn get_invoices(
+        env: Env,
+        filter: InvoiceFilter,
+        cursor: u64,
+        limit: u32,
+    ) -> (Vec<Invoice>, Option<u64>) {
+        invoice_component::get_invoices(&env, filter, cursor, limit)
+    }
+
+    fn pause(env: Env, admin: Address) {
+        pausable_component::pause(&env, &admin);
+    }
+
+    fn unpause(env: Env, admin: Address) {
+        pausable_component::unpause(&env, &admin);
+    }
+
+    fn is_paused(env: Env) -> bool {
+        pausable_component::is_paused(&env)
+    }
+
+    fn set_deployed_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        upgrade_component::set_deployed_wasm_hash(&env, &admin, &wasm_hash);
+    }
+
+    fn propose_upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        upgrade_component::propose_upgrade(&env, &admin, &new_wasm_hash);
+    }
+
+    fn execute_upgrade(env: Env) {
+        upgrade_component::execute_upgrade(&env);
+    }
+
+    fn cancel_upgrade(env: Env, admin: Address) {
+        upgrade_component::cancel_upgrade(&env, &admin);
+    }
+
+    fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        upgrade_component::get_pending_upgrade(&env)
+    }
+
+    fn rollback(env: Env, admin: Address) {
+        upgrade_component::rollback(&env, &admin);
+    }
+
+    fn get_upgrade_history(env: Env) -> Vec<UpgradeRecord> {
+        upgrade_component::get_upgrade_history(&env)
+    }
+
+    fn bump_storage(env: Env, admin: Address, keys: Vec<StorageKey>) {
+        ttl_component::bump_storage(&env, &admin, keys);
+    }
+
+    fn get_schema_version(env: Env) -> u32 {
+        migration_component::get_schema_version(&env)
+    }
+
+    fn migrate(env: Env, admin: Address, from_version: u32, args: Vec<Val>) {
+        migration_component::migrate(&env, &admin, from_version, args);
+    }
+
+    fn create_stream(
+        env: Env,
+        payer: Address,
+        merchant_id: u64,
+        token: Address,
+        amount: i128,
+        start_time: u64,
+        end_time: u64,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        stream_component::create_stream(
+            &env,
+            &payer,
+            merchant_id,
+            &token,
+            amount,
+            start_time,
+            end_time,
+        )
+    }
+
+    fn withdraw_from_stream(env: Env, merchant: Address, stream_id: u64) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        stream_component::withdraw_from_stream(&env, &merchant, stream_id)
+    }
+
+    fn cancel_stream(env: Env, caller: Address, stream_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        stream_component::cancel_stream(&env, &caller, stream_id);
+    }
+
+    fn get_stream(env: Env, stream_id: u64) -> Stream {
+        stream_component::get_stream(&env, stream_id)
+    }
+
+    fn get_withdrawable_amount(env: Env, stream_id: u64) -> i128 {
+        stream_component::get_withdrawable_amount(&env, stream_id)
+    }
+
+    fn issue_gift_card(
+        env: Env,
+        buyer: Address,
+        merchant_id: u64,
+        token: Address,
+        amount: i128,
+        expires_at: u64,
+        claim_hash: Option<BytesN<32>>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        gift_card_component::issue_gift_card(
+            &env,
+            &buyer,
+            merchant_id,
+            &token,
+            amount,
+            expires_at,
+            claim_hash,
+        )
+    }
+
+    fn claim_gift_card(env: Env, claimant: Address, card_id: u64, secret: Bytes) {
+        pausable_component::assert_not_paused(&env);
+        gift_card_component::claim_gift_card(&env, &claimant, card_id, &secret);
+    }
+
+    fn redeem_gift_card(env: Env, holder: Address, card_id: u64, invoice_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        gift_card_component::redeem_gift_card(&env, &holder, card_id, invoice_id);
+    }
+
+    fn expire_gift_card(env: Env, card_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        gift_card_component::expire_gift_card(&env, card_id);
+    }
+
+    fn set_cleanup_bounty(env: Env, admin: Address, token: Address, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        cleanup_component::set_cleanup_bounty(&env, &admin, &token, amount);
+    }
+
+    fn get_cleanup_bounty(env: Env, token: Address) -> i128 {
+        cleanup_component::get_cleanup_bounty(&env, &token)
+    }
+
+    fn sweep_expired(
+        env: Env,
+        caller: Address,
+        gift_card_ids: Vec<u64>,
+        payment_link_ids: Vec<u64>,
+    ) -> u32 {
+        pausable_component::assert_not_paused(&env);
+        cleanup_component::sweep_expired(&env, &caller, &gift_card_ids, &payment_link_ids)
+    }
+
+    fn cancel_expired_invoices(env: Env, invoice_ids: Vec<u64>) -> u32 {
+        pausable_component::assert_not_paused(&env);
+        cleanup_component::cancel_expired_invoices(&env, &invoice_ids)
+    }
+
+    fn get_gift_card(env: Env, card_id: u64) -> GiftCard {
+        gift_card_component::get_gift_card(&env, card_id)
+    }
+
+    fn create_payment_link(
+        env: Env,
+        payer: Address,
+        token: Address,
+        amount: i128,
+        claim_hash: BytesN<32>,
+        expires_at: u64,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        payment_link_component::create_payment_link(
+            &env,
+            &payer,
+            &token,
+            amount,
+            &claim_hash,
+            expires_at,
+        )
+    }
+
+    fn commit_payment_link_claim(
+        env: Env,
+        claimant: Address,
+        link_id: u64,
+        commitment: BytesN<32>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        payment_link_component::commit_payment_link_claim(&env, &claimant, link_id, &commitment);
+    }
+
+    fn claim_payment_link(
+        env: Env,
+        claimant: Address,
+        link_id: u64,
+        secret: Bytes,
+        invoice_id: Option<u64>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        payment_link_component::claim_payment_link(&env, &claimant, link_id, &secret, invoice_id);
+    }
+
+    fn refund_payment_link(env: Env, link_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        payment_link_component::refund_payment_link(&env, link_id);
+    }
+
+    fn get_payment_link(env: Env, link_id: u64) -> PaymentLink {
+        payment_link_component::get_payment_link(&env, link_id)
+    }
+
+    fn create_campaign(
+        env: Env,
+        merchant: Address,
+        token: Address,
+        goal: i128,
+        deadline: u64,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        campaign_component::create_campaign(&env, &merchant, &token, goal, deadline)
+    }
+
+    fn contribute(env: Env, contributor: Address, campaign_id: u64, amount: i128) {
+        pausable_component::assert_not_paused(&env);
+        campaign_component::contribute(&env, &contributor, campaign_id, amount);
+    }
+
+    fn finalize_campaign(env: Env, campaign_id: u64) -> CampaignStatus {
+        pausable_component::assert_not_paused(&env);
+        campaign_component::finalize_campaign(&env, campaign_id)
+    }
+
+    fn claim_refund(env: Env, contributor: Address, campaign_id: u64) -> i128 {
+        pausable_component::assert_not_paused(&env);
+        campaign_component::claim_refund(&env, &contributor, campaign_id)
+    }
+
+    fn get_campaign(env: Env, campaign_id: u64) -> Campaign {
+        campaign_component::get_campaign(&env, campaign_id)
+    }
+
+    fn get_contribution(env: Env, campaign_id: u64, contributor: Address) -> i128 {
+        campaign_component::get_contribution(&env, campaign_id, &contributor)
+    }
+
+    fn create_settlement_batch(
+        env: Env,
+        operator: Address,
+        merchant_id: u64,
+        token: Address,
+        invoice_ids: Vec<u64>,
+        external_ref: BytesN<32>,
+    ) -> u64 {
+        pausable_component::assert_not_paused(&env);
+        settlement_component::create_settlement_batch(
+            &env,
+            &operator,
+            merchant_id,
+            &token,
+            &invoice_ids,
+            &external_ref,
+        )
+    }
+
+    fn mark_settlement_exported(env: Env, operator: Address, batch_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        settlement_component::mark_settlement_exported(&env, &operator, batch_id);
+    }
+
+    fn mark_settlement_settled(env: Env, operator: Address, batch_id: u64) {
+        pausable_component::assert_not_paused(&env);
+        settlement_component::mark_settlement_settled(&env, &operator, batch_id);
+    }
+
+    fn get_settlement_batch(env: Env, batch_id: u64) -> SettlementBatch {
+        settlement_component::get_settlement_batch(&env, batch_id)
+    }
+
+    fn get_invoice_settlement_batch(env: Env, invoice_id: u64) -> Option<u64> {
+        settlement_component::get_invoice_settlement_batch(&env, invoice_id)
+    }
+
+    fn register_customer(
+        env: Env,
+        customer: Address,
+        preferred_token: Option<Address>,
+        refund_address: Option<Address>,
+        contact_hash: Option<BytesN<32>>,
+    ) {
+        pausable_component::assert_not_paused(&env);
+        customer_component::register_customer(
+            &env,
+            &customer,
+            preferred_token,
+            refund_address,
+            contact_hash,
+        );
+    }
+
+    fn remove_customer(env: Env, customer: Address) {
+        pausable_component::assert_not_paused(&env);
+        customer_component::remove_customer(&env, &customer);
     }
 
-    fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        upgrade_component::upgrade(&env, &new_wasm_hash);
+    fn get_customer(env: Env, customer: Address) -> Option<CustomerProfile> {
+        customer_component::get_customer(&env, &customer)
     }
 }
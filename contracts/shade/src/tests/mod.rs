@@ -1,10 +1,60 @@
 pub mod test;
 pub mod test_accepted_tokens;
+pub mod test_access_control;
+pub mod test_activity;
+pub mod test_addressed_invoice;
+pub mod test_affiliate;
+pub mod test_amount_bounds;
+pub mod test_blocklist;
+pub mod test_campaign;
+pub mod test_cleanup;
+pub mod test_custom_account;
+pub mod test_customer_profiles;
+pub mod test_distribution;
+pub mod test_escrow_invoice;
+pub mod test_fee_exemption;
 pub mod test_fees;
+pub mod test_frozen_assets;
+pub mod test_gift_card;
+pub mod test_governance;
 pub mod test_invoice;
+pub mod test_invoice_amendments;
+pub mod test_invoice_approval;
+pub mod test_invoice_contributions;
+pub mod test_invoice_delegation;
+pub mod test_invoice_due_date;
+pub mod test_invoice_expiry;
+pub mod test_invoice_metadata;
+pub mod test_invoice_refund;
+pub mod test_invoice_tax;
+pub mod test_late_fees;
+pub mod test_line_items;
 pub mod test_merchant;
+pub mod test_merchant_account;
 pub mod test_merchant_activation;
+pub mod test_merchant_approval;
+pub mod test_merchant_bond;
+pub mod test_merchant_invoice_seq;
 pub mod test_merchant_key;
 pub mod test_merchant_verification;
+pub mod test_migration;
+pub mod test_milestones;
+pub mod test_native_token;
+pub mod test_oracle;
+pub mod test_pagination;
 pub mod test_pausable;
-pub mod test_upgrade;
\ No newline at end of file
+pub mod test_payment_hook;
+pub mod test_payment_link;
+pub mod test_payment_preview;
+pub mod test_payment_routing;
+pub mod test_private_invoice;
+pub mod test_rate_limit;
+pub mod test_recurring_invoices;
+pub mod test_settlement;
+pub mod test_stats;
+pub mod test_stream;
+pub mod test_tips;
+pub mod test_token_recovery;
+pub mod test_ttl;
+pub mod test_upgrade;
+pub mod test_velocity;
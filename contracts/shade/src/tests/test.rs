@@ -1,9 +1,11 @@
 #![cfg(test)]
 
+use crate::components::core::CONTRACT_VERSION;
+use crate::components::migration::SCHEMA_VERSION;
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, String};
 
 #[test]
 fn test_initialize() {
@@ -37,3 +39,75 @@ fn test_get_admin_not_initialized() {
 
     client.get_admin();
 }
+
+#[test]
+fn test_get_counts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let counts = client.get_counts();
+    assert_eq!(counts.merchants, 0);
+    assert_eq!(counts.invoices, 0);
+
+    let merchant = Address::generate(&env);
+    let merchant_id = client.register_merchant(&merchant);
+    client.register_merchant(&Address::generate(&env));
+    client.approve_merchant(&admin, &merchant_id);
+    let token = Address::generate(&env);
+    client.create_invoice(&merchant, &String::from_str(&env, "Invoice"), &100, &token);
+
+    let counts = client.get_counts();
+    assert_eq!(counts.merchants, 2);
+    assert_eq!(counts.invoices, 1);
+}
+
+#[test]
+fn test_get_version_and_contract_info() {
+    let env = Env::default();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.admin, admin);
+    assert_eq!(info.version, CONTRACT_VERSION);
+}
+
+#[test]
+fn test_get_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.add_accepted_token(&admin, &token);
+    client.set_fee(&admin, &token, &250);
+    client.set_default_invoice_expiry(&admin, &Some(86_400));
+    client.pause(&admin);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.token_fees.len(), 1);
+    assert_eq!(config.token_fees.get(0).unwrap().token, token);
+    assert_eq!(config.token_fees.get(0).unwrap().fee, 250);
+    assert_eq!(config.native_token, None);
+    assert_eq!(config.default_invoice_expiry, Some(86_400));
+    assert!(config.paused);
+    assert!(config.legacy_event_format);
+    assert_eq!(config.schema_version, SCHEMA_VERSION);
+    assert_eq!(config.version, CONTRACT_VERSION);
+}
@@ -5,7 +5,7 @@ use crate::errors::ContractError;
 use crate::shade::Shade;
 use crate::shade::ShadeClient;
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Map, Symbol, TryIntoVal, Val};
+use soroban_sdk::{token, Address, Env, Map, Symbol, TryIntoVal, Val};
 
 fn assert_latest_token_event(
     env: &Env,
@@ -15,20 +15,20 @@ fn assert_latest_token_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(event_contract_id, contract_id.clone());
-    assert_eq!(topics.len(), 1);
+    assert_eq!(topics.len(), 2);
 
     let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
     assert_eq!(event_name, Symbol::new(env, expected_event));
 
+    let token_in_event: Address = topics.get(1).unwrap().try_into_val(env).unwrap();
+
     let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
-    let token_val = data_map.get(Symbol::new(env, "token")).unwrap();
     let timestamp_val = data_map.get(Symbol::new(env, "timestamp")).unwrap();
 
-    let token_in_event: Address = token_val.try_into_val(env).unwrap();
     let timestamp_in_event: u64 = timestamp_val.try_into_val(env).unwrap();
 
     assert_eq!(token_in_event, expected_token.clone());
@@ -45,6 +45,7 @@ fn test_admin_adds_token_and_emits_event() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     let token_admin = Address::generate(&env);
     let token = env
@@ -77,6 +78,7 @@ fn test_admin_removes_token_and_emits_event() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     let token_admin = Address::generate(&env);
     let token = env
@@ -173,3 +175,140 @@ fn test_invalid_token_address_panics() {
     let invalid_token = Address::generate(&env);
     client.add_accepted_token(&admin, &invalid_token);
 }
+
+#[test]
+fn test_non_token_contract_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // A deployed contract that does not implement SEP-41.
+    let not_a_token = env.register(Shade, ());
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::InvalidToken as u32);
+    let result = client.try_add_accepted_token(&admin, &not_a_token);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert!(!client.is_accepted_token(&not_a_token));
+    assert!(client.get_token_metadata(&not_a_token).is_none());
+
+    let result = client.try_set_native_token(&admin, &not_a_token);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_token_metadata_is_stored_on_accept() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    assert!(client.get_token_metadata(&token).is_none());
+
+    client.add_accepted_token(&admin, &token);
+
+    let token_client = token::TokenClient::new(&env, &token);
+    let metadata = client.get_token_metadata(&token).unwrap();
+    assert_eq!(metadata.name, token_client.name());
+    assert_eq!(metadata.symbol, token_client.symbol());
+    assert_eq!(metadata.decimals, 7);
+}
+
+#[test]
+fn test_legacy_event_format_keeps_token_in_data() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    assert!(client.is_legacy_event_format());
+
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    env.as_contract(&contract_id, || {
+        admin_component::add_accepted_token(&env, &admin, &token);
+
+        let events = env.events().all();
+        let (_, topics, data) = events.get(events.len() - 1).unwrap();
+        assert_eq!(topics.len(), 1);
+
+        let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+        assert_eq!(event_name, Symbol::new(&env, "token_added_event"));
+
+        let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+        let token_in_event: Address = data_map
+            .get(Symbol::new(&env, "token"))
+            .unwrap()
+            .try_into_val(&env)
+            .unwrap();
+        assert_eq!(token_in_event, token);
+    });
+}
+
+#[test]
+fn test_non_admin_cannot_set_legacy_event_format() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let non_admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+    let result = client.try_set_legacy_event_format(&non_admin, &false);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert!(client.is_legacy_event_format());
+}
+
+#[test]
+fn test_admin_turns_off_legacy_event_format() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
+    assert_eq!(
+        event_name,
+        Symbol::new(&env, "legacy_event_format_set_event")
+    );
+
+    let data_map: Map<Symbol, Val> = data.try_into_val(&env).unwrap();
+    let enabled: bool = data_map
+        .get(Symbol::new(&env, "enabled"))
+        .unwrap()
+        .try_into_val(&env)
+        .unwrap();
+    assert!(!enabled);
+    assert!(!client.is_legacy_event_format());
+}
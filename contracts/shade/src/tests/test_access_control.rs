@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::Role;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Vec};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_grant_and_revoke_roles_in_batch() {
+    let t = ShadeTestEnv::new();
+    let manager = Address::generate(&t.env);
+    let operator = Address::generate(&t.env);
+
+    t.client.grant_roles(
+        &t.admin,
+        &vec![
+            &t.env,
+            (manager.clone(), Role::Manager),
+            (operator.clone(), Role::Operator),
+            (manager.clone(), Role::Operator),
+        ],
+    );
+    assert!(t.client.has_role(&manager, &Role::Manager));
+    assert!(t.client.has_role(&manager, &Role::Operator));
+    assert!(t.client.has_role(&operator, &Role::Operator));
+    assert!(!t.client.has_role(&operator, &Role::Manager));
+
+    t.client.revoke_roles(
+        &t.admin,
+        &vec![
+            &t.env,
+            (manager.clone(), Role::Operator),
+            (operator.clone(), Role::Operator),
+        ],
+    );
+    assert!(t.client.has_role(&manager, &Role::Manager));
+    assert!(!t.client.has_role(&manager, &Role::Operator));
+    assert!(!t.client.has_role(&operator, &Role::Operator));
+}
+
+#[test]
+fn test_batch_roles_require_admin_and_bounded_size() {
+    let t = ShadeTestEnv::new();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_grant_roles(&outsider, &vec![&t.env, (outsider.clone(), Role::Manager)]),
+        ContractError::NotAuthorized,
+    );
+    assert!(!t.client.has_role(&outsider, &Role::Manager));
+
+    let mut grants = Vec::new(&t.env);
+    for _ in 0..101 {
+        grants.push_back((Address::generate(&t.env), Role::Operator));
+    }
+    assert_contract_error(
+        t.client.try_grant_roles(&t.admin, &grants),
+        ContractError::BatchTooLarge,
+    );
+}
@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use crate::components::activity::{self as activity_component, ACTIVITY_CAPACITY};
+use crate::testutils::ShadeTestEnv;
+use crate::types::ActivityKind;
+
+#[test]
+fn test_recent_activity_lists_payments_newest_first() {
+    let t = ShadeTestEnv::new()
+        .with_token(0)
+        .with_merchant()
+        .with_paid_invoice(1_000)
+        .with_paid_invoice(2_000);
+
+    let activity = t.client.get_recent_activity(&10);
+    assert_eq!(activity.len(), 2);
+
+    let latest = activity.get(0).unwrap();
+    assert_eq!(latest.kind, ActivityKind::InvoicePayment);
+    assert_eq!(latest.reference_id, t.paid_invoices.get(1).unwrap());
+    assert_eq!(latest.amount, 2_000);
+    assert_eq!(latest.token, t.token());
+    assert_eq!(
+        activity.get(1).unwrap().reference_id,
+        t.paid_invoices.get(0).unwrap()
+    );
+
+    assert_eq!(t.client.get_recent_activity(&1).len(), 1);
+}
+
+#[test]
+fn test_recent_activity_keeps_only_latest_records() {
+    let t = ShadeTestEnv::new().with_token(0);
+    let token = t.token();
+
+    for id in 0..ACTIVITY_CAPACITY + 5 {
+        t.env.as_contract(&t.client.address, || {
+            activity_component::record_activity(
+                &t.env,
+                ActivityKind::StreamCharge,
+                id,
+                &token,
+                100,
+            );
+        });
+    }
+
+    let activity = t.client.get_recent_activity(&0);
+    assert_eq!(activity.len() as u64, ACTIVITY_CAPACITY);
+    assert_eq!(activity.get(0).unwrap().reference_id, ACTIVITY_CAPACITY + 4);
+    assert_eq!(activity.last().unwrap().reference_id, 5);
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String, Vec};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_only_listed_customers_can_pay() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let customer = Address::generate(&t.env);
+    let stranger = Address::generate(&t.env);
+    t.mint(&customer, 1_000);
+    t.mint(&stranger, 1_000);
+
+    let invoice_id = t.client.create_addressed_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Q3 consulting"),
+        &1_000,
+        &t.token(),
+        &vec![&t.env, customer.clone()],
+    );
+    assert_eq!(
+        t.client.get_invoice_payer_allowlist(&invoice_id),
+        vec![&t.env, customer.clone()]
+    );
+
+    assert_contract_error(
+        t.client.try_pay_invoice(&stranger, &invoice_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_contribute_to_invoice(&stranger, &invoice_id, &500),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.token_client().balance(&stranger), 1_000);
+
+    let invoice = t.client.pay_invoice(&customer, &invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(customer));
+}
+
+#[test]
+fn test_addressed_invoice_requires_an_allowlist() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+
+    assert_contract_error(
+        t.client.try_create_addressed_invoice(
+            &t.merchant(),
+            &String::from_str(&t.env, "Nobody"),
+            &1_000,
+            &t.token(),
+            &Vec::new(&t.env),
+        ),
+        InvoiceError::InvalidPayerAllowlist,
+    );
+}
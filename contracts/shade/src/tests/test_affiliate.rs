@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, GovernanceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::AffiliateEarnings;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, String, Symbol, TryIntoVal};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Referred order"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_commission_is_taken_from_merchant_share_and_claimable() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.client.set_affiliate_commission(&t.merchant(), &500);
+    assert_eq!(t.client.get_affiliate_commission(&t.merchant_id()), 500);
+
+    let affiliate = Address::generate(&t.env);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 2_000);
+    t.client
+        .pay_invoice_with_affiliate(&payer, &create_invoice(&t), &affiliate);
+
+    let accrued = t.env.events().all().iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "commission_accrued_event")
+    });
+    assert!(accrued);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_eq!(t.token_client().balance(&t.merchant()), 941);
+
+    t.client
+        .pay_invoice_with_affiliate(&payer, &create_invoice(&t), &affiliate);
+    assert_eq!(
+        t.client.get_affiliate_earnings(&affiliate, &t.token()),
+        AffiliateEarnings {
+            accrued: 98,
+            claimed: 0,
+        }
+    );
+
+    assert_eq!(
+        t.client.claim_affiliate_commission(&affiliate, &t.token()),
+        98
+    );
+    assert_eq!(t.token_client().balance(&affiliate), 98);
+    assert_eq!(
+        t.client.get_affiliate_earnings(&affiliate, &t.token()),
+        AffiliateEarnings {
+            accrued: 98,
+            claimed: 98,
+        }
+    );
+    assert_contract_error(
+        t.client
+            .try_claim_affiliate_commission(&affiliate, &t.token()),
+        GovernanceError::NothingToClaim,
+    );
+}
+
+#[test]
+fn test_no_commission_without_merchant_rate() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let affiliate = Address::generate(&t.env);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+
+    t.client
+        .pay_invoice_with_affiliate(&payer, &create_invoice(&t), &affiliate);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+    assert_eq!(
+        t.client
+            .get_affiliate_earnings(&affiliate, &t.token())
+            .accrued,
+        0
+    );
+}
+
+#[test]
+fn test_commission_rate_validation() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+
+    assert_contract_error(
+        t.client
+            .try_set_affiliate_commission(&t.merchant(), &10_001),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_affiliate_commission(&Address::generate(&t.env), &100),
+        ContractError::MerchantNotFound,
+    );
+
+    t.client.set_affiliate_commission(&t.merchant(), &100);
+    t.client.set_affiliate_commission(&t.merchant(), &0);
+    assert_eq!(t.client.get_affiliate_commission(&t.merchant_id()), 0);
+}
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::AmountBounds;
+use soroban_sdk::String;
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn try_invoice(
+    t: &ShadeTestEnv,
+    amount: i128,
+) -> Result<u64, Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    t.client
+        .try_create_invoice(
+            &t.merchant(),
+            &String::from_str(&t.env, "Invoice"),
+            &amount,
+            &t.token(),
+        )
+        .map(|invoice_id| invoice_id.unwrap())
+}
+
+#[test]
+fn test_invoice_amount_must_be_within_bounds() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let bounds = AmountBounds {
+        min: 100,
+        max: 10_000,
+        unverified_max: Some(1_000),
+    };
+    t.client
+        .set_amount_bounds(&t.admin, &t.token(), &Some(bounds.clone()));
+    assert_eq!(t.client.get_amount_bounds(&t.token()), Some(bounds));
+
+    assert_contract_error(try_invoice(&t, 99), ComplianceError::AmountOutOfBounds);
+    assert!(try_invoice(&t, 100).is_ok());
+    assert!(try_invoice(&t, 1_000).is_ok());
+
+    // Unverified merchants are held to the lower ceiling.
+    assert_contract_error(try_invoice(&t, 1_001), ComplianceError::AmountOutOfBounds);
+    t.client.verify_merchant(&t.admin, &t.merchant_id(), &true);
+    assert!(try_invoice(&t, 10_000).is_ok());
+    assert_contract_error(try_invoice(&t, 10_001), ComplianceError::AmountOutOfBounds);
+
+    t.client.set_amount_bounds(&t.admin, &t.token(), &None);
+    assert!(try_invoice(&t, 1).is_ok());
+}
+
+#[test]
+fn test_set_amount_bounds_validation() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let bounds = AmountBounds {
+        min: 500,
+        max: 100,
+        unverified_max: None,
+    };
+
+    assert_contract_error(
+        t.client
+            .try_set_amount_bounds(&t.admin, &t.token(), &Some(bounds)),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_set_amount_bounds(
+            &t.admin,
+            &t.token(),
+            &Some(AmountBounds {
+                min: 100,
+                max: 500,
+                unverified_max: Some(1_000),
+            }),
+        ),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_amount_bounds(&t.merchant(), &t.token(), &None),
+        ContractError::NotAuthorized,
+    );
+}
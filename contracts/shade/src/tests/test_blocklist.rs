@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::Role;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, BytesN, Symbol, TryIntoVal};
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address, Address) {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+
+    let operator = Address::generate(&t.env);
+    t.client.grant_role(&t.admin, &operator, &Role::Operator);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 10_000);
+
+    (t, operator, payer)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_blocked_payer_cannot_fund_payments() {
+    let (t, operator, payer) = setup_test();
+
+    t.client.block_payer(&operator, &payer);
+
+    let events = t.env.events().all();
+    let (event_contract_id, topics, _) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, t.client.address);
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(event_name, Symbol::new(&t.env, "payer_blocked_event"));
+    let blocked: Address = topics.get(1).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(blocked, payer);
+    assert!(t.client.is_payer_blocked(&payer));
+
+    let now = t.env.ledger().timestamp();
+    assert_contract_error(
+        t.client
+            .try_create_stream(&payer, &1, &t.token(), &1_000, &now, &(now + 100)),
+        ComplianceError::PayerBlocked,
+    );
+    assert_contract_error(
+        t.client
+            .try_issue_gift_card(&payer, &1, &t.token(), &1_000, &(now + 100), &None),
+        ComplianceError::PayerBlocked,
+    );
+    assert_contract_error(
+        t.client.try_create_payment_link(
+            &payer,
+            &t.token(),
+            &1_000,
+            &BytesN::from_array(&t.env, &[0; 32]),
+            &(now + 100),
+        ),
+        ComplianceError::PayerBlocked,
+    );
+
+    t.client.unblock_payer(&t.admin, &payer);
+    assert!(!t.client.is_payer_blocked(&payer));
+    t.client
+        .create_stream(&payer, &1, &t.token(), &1_000, &now, &(now + 100));
+}
+
+#[test]
+fn test_only_operators_manage_blocklist() {
+    let (t, _, payer) = setup_test();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_block_payer(&outsider, &payer),
+        ContractError::NotAuthorized,
+    );
+    assert!(!t.client.is_payer_blocked(&payer));
+
+    t.client.block_payer(&t.admin, &payer);
+    assert_contract_error(
+        t.client.try_unblock_payer(&outsider, &payer),
+        ContractError::NotAuthorized,
+    );
+    assert!(t.client.is_payer_blocked(&payer));
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::CampaignStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+const NOW: u64 = 1_000;
+const DEADLINE: u64 = 5_000;
+const GOAL: i128 = 1_000;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address, Address, u64) {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    t.mint(&alice, 5_000);
+    t.mint(&bob, 5_000);
+
+    let campaign_id = t
+        .client
+        .create_campaign(&t.merchant(), &t.token(), &GOAL, &DEADLINE);
+
+    (t, alice, bob, campaign_id)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_contributions_are_tracked() {
+    let (t, alice, bob, campaign_id) = setup_test();
+
+    t.client.contribute(&alice, &campaign_id, &300);
+    t.client.contribute(&bob, &campaign_id, &200);
+    t.client.contribute(&alice, &campaign_id, &100);
+
+    let campaign = t.client.get_campaign(&campaign_id);
+    assert_eq!(campaign.raised, 600);
+    assert_eq!(campaign.status, CampaignStatus::Active);
+    assert_eq!(t.client.get_contribution(&campaign_id, &alice), 400);
+    assert_eq!(t.client.get_contribution(&campaign_id, &bob), 200);
+    assert_eq!(t.token_client().balance(&t.client.address), 600);
+}
+
+#[test]
+fn test_successful_campaign_pays_merchant() {
+    let (t, alice, bob, campaign_id) = setup_test();
+    t.client.contribute(&alice, &campaign_id, &700);
+    t.client.contribute(&bob, &campaign_id, &500);
+
+    assert_contract_error(
+        t.client.try_finalize_campaign(&campaign_id),
+        ContractError::CampaignStillActive,
+    );
+
+    t.env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(
+        t.client.finalize_campaign(&campaign_id),
+        CampaignStatus::Succeeded
+    );
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_188);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 12);
+
+    assert_contract_error(
+        t.client.try_claim_refund(&alice, &campaign_id),
+        ContractError::CampaignNotRefundable,
+    );
+}
+
+#[test]
+fn test_failed_campaign_refunds_contributors() {
+    let (t, alice, bob, campaign_id) = setup_test();
+    t.client.contribute(&alice, &campaign_id, &300);
+    t.client.contribute(&bob, &campaign_id, &200);
+
+    assert_contract_error(
+        t.client.try_claim_refund(&alice, &campaign_id),
+        ContractError::CampaignNotRefundable,
+    );
+
+    t.env.ledger().set_timestamp(DEADLINE);
+    assert_contract_error(
+        t.client.try_contribute(&alice, &campaign_id, &1_000),
+        ContractError::CampaignClosed,
+    );
+
+    assert_eq!(t.client.claim_refund(&alice, &campaign_id), 300);
+    assert_eq!(
+        t.client.get_campaign(&campaign_id).status,
+        CampaignStatus::Failed
+    );
+    assert_eq!(t.token_client().balance(&alice), 5_000);
+
+    assert_contract_error(
+        t.client.try_finalize_campaign(&campaign_id),
+        ContractError::CampaignClosed,
+    );
+    assert_eq!(t.client.claim_refund(&bob, &campaign_id), 200);
+    assert_eq!(t.token_client().balance(&bob), 5_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+
+    assert_contract_error(
+        t.client.try_claim_refund(&alice, &campaign_id),
+        ContractError::NothingToRefund,
+    );
+}
+
+#[test]
+fn test_create_campaign_validation() {
+    let (t, alice, _, _) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_create_campaign(&t.merchant(), &t.token(), &0, &DEADLINE),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client
+            .try_create_campaign(&t.merchant(), &t.token(), &GOAL, &NOW),
+        ContractError::InvalidCampaignDeadline,
+    );
+    assert_contract_error(
+        t.client
+            .try_create_campaign(&alice, &t.token(), &GOAL, &DEADLINE),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_get_campaign(&9),
+        ContractError::CampaignNotFound,
+    );
+}
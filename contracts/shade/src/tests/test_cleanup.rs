@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::{GiftCardStatus, InvoiceExpiryPolicy, InvoiceStatus, PaymentLinkStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    vec, Address, Bytes, BytesN, Map, String, Symbol, TryFromVal, TryIntoVal, Val, Vec,
+};
+
+const EXPIRES_AT: u64 = 3_600;
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+// 10_000 paid at 1% leaves 100 of undistributed fees to fund bounties.
+fn setup_test<'a>() -> ShadeTestEnv<'a> {
+    let t = ShadeTestEnv::new()
+        .with_token(100)
+        .with_merchant()
+        .with_paid_invoice(10_000);
+    t.client.set_cleanup_bounty(&t.admin, &t.token(), &30);
+    t
+}
+
+fn open_payment_link(t: &ShadeTestEnv, expires_at: u64) -> u64 {
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 500);
+    let claim_hash: BytesN<32> = t
+        .env
+        .crypto()
+        .sha256(&Bytes::from_slice(&t.env, b"secret"))
+        .into();
+    t.client
+        .create_payment_link(&payer, &t.token(), &500, &claim_hash, &expires_at)
+}
+
+fn issue_gift_card(t: &ShadeTestEnv) -> u64 {
+    let buyer = Address::generate(&t.env);
+    t.mint(&buyer, 500);
+    t.client.issue_gift_card(
+        &buyer,
+        &t.merchant_id(),
+        &t.token(),
+        &500,
+        &EXPIRES_AT,
+        &None,
+    )
+}
+
+#[test]
+fn test_sweep_pays_bounty_per_processed_entry() {
+    let t = setup_test();
+    let card_id = issue_gift_card(&t);
+    let first_link = open_payment_link(&t, EXPIRES_AT);
+    let second_link = open_payment_link(&t, EXPIRES_AT);
+    let live_link = open_payment_link(&t, 10 * EXPIRES_AT);
+    t.env.ledger().set_timestamp(EXPIRES_AT);
+
+    let sweeper = Address::generate(&t.env);
+    let processed = t.client.sweep_expired(
+        &sweeper,
+        &vec![&t.env, card_id, 99],
+        &vec![&t.env, first_link, second_link, live_link],
+    );
+    assert_eq!(processed, 3);
+
+    assert_eq!(
+        t.client.get_gift_card(&card_id).status,
+        GiftCardStatus::Expired
+    );
+    assert_eq!(
+        t.client.get_payment_link(&first_link).status,
+        PaymentLinkStatus::Refunded
+    );
+    assert_eq!(
+        t.client.get_payment_link(&live_link).status,
+        PaymentLinkStatus::Open
+    );
+    assert_eq!(t.token_client().balance(&sweeper), 90);
+    assert_eq!(t.client.get_undistributed_fees(&t.token()), 10);
+
+    // Bounties never exceed the fees left to pay them.
+    let last_link = open_payment_link(&t, EXPIRES_AT + 1);
+    t.env.ledger().set_timestamp(EXPIRES_AT + 1);
+    t.client
+        .sweep_expired(&sweeper, &Vec::new(&t.env), &vec![&t.env, last_link]);
+    assert_eq!(t.token_client().balance(&sweeper), 100);
+    assert_eq!(t.client.get_undistributed_fees(&t.token()), 0);
+}
+
+#[test]
+fn test_sweep_batch_is_capped() {
+    let t = setup_test();
+    let mut ids = Vec::new(&t.env);
+    for id in 0..21 {
+        ids.push_back(id);
+    }
+
+    assert_contract_error(
+        t.client
+            .try_sweep_expired(&t.admin, &ids, &Vec::new(&t.env)),
+        ContractError::BatchTooLarge,
+    );
+}
+
+#[test]
+fn test_only_admin_sets_cleanup_bounty() {
+    let t = setup_test();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_set_cleanup_bounty(&outsider, &t.token(), &50),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.client.get_cleanup_bounty(&t.token()), 30);
+
+    t.client.set_cleanup_bounty(&t.admin, &t.token(), &0);
+    assert_eq!(t.client.get_cleanup_bounty(&t.token()), 0);
+}
+
+#[test]
+fn test_cancel_expired_invoices_refunds_contributions() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let now = t.env.ledger().timestamp();
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::After(EXPIRES_AT));
+    let description = String::from_str(&t.env, "Stale order");
+    let partly_paid = t
+        .client
+        .create_invoice(&t.merchant(), &description, &1_000, &t.token());
+    let unpaid = t
+        .client
+        .create_invoice(&t.merchant(), &description, &1_000, &t.token());
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 400);
+    t.client.contribute_to_invoice(&payer, &partly_paid, &400);
+
+    assert_eq!(
+        t.client
+            .cancel_expired_invoices(&vec![&t.env, partly_paid, unpaid]),
+        0
+    );
+
+    t.env.ledger().set_timestamp(now + EXPIRES_AT);
+    assert_eq!(
+        t.client
+            .cancel_expired_invoices(&vec![&t.env, partly_paid, unpaid, 999]),
+        2
+    );
+    assert_eq!(
+        t.client.get_invoice_status(&partly_paid),
+        InvoiceStatus::Cancelled
+    );
+    assert_eq!(
+        t.client.get_invoice_status(&unpaid),
+        InvoiceStatus::Cancelled
+    );
+    assert_eq!(t.token_client().balance(&payer), 400);
+    assert!(t.client.get_invoice_contributions(&partly_paid).is_empty());
+}
+
+#[test]
+fn test_cancel_expired_invoices_publishes_invoice_expired() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let now = t.env.ledger().timestamp();
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::After(EXPIRES_AT));
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Stale order"),
+        &1_000,
+        &t.token(),
+    );
+
+    t.env.ledger().set_timestamp(now + EXPIRES_AT + 10);
+    t.client.cancel_expired_invoices(&vec![&t.env, invoice_id]);
+
+    let expired_event = Symbol::new(&t.env, "invoice_expired_event");
+    let events = t.env.events().all();
+    let (_, topics, data) = events
+        .iter()
+        .find(|(contract_id, topics, _)| {
+            *contract_id == t.client.address
+                && topics.get(0).is_some_and(|name| {
+                    Symbol::try_from_val(&t.env, &name) == Ok(expired_event.clone())
+                })
+        })
+        .unwrap();
+    let event_invoice_id: u64 = topics.get(1).unwrap().try_into_val(&t.env).unwrap();
+    let event_merchant_id: u64 = topics.get(2).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(event_invoice_id, invoice_id);
+    assert_eq!(event_merchant_id, t.merchant_id());
+    let data: Map<Symbol, Val> = data.try_into_val(&t.env).unwrap();
+    let expires_at: u64 = data
+        .get(Symbol::new(&t.env, "expires_at"))
+        .unwrap()
+        .try_into_val(&t.env)
+        .unwrap();
+    assert_eq!(expires_at, now + EXPIRES_AT);
+}
@@ -0,0 +1,174 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::xdr::{
+    InvokeContractArgs, ScVal, SorobanAddressCredentials, SorobanAuthorizationEntry,
+    SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials,
+};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short, Address, Env, IntoVal, String, TryFromVal,
+    Val, Vec,
+};
+
+// Sample contract wallet that refuses to sign anything spending more than a
+// fixed amount. Shade payment contexts are `(reference_id, token, amount)`
+// and token transfers are `(from, to, amount)`, so the amount is always the
+// third argument.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum WalletError {
+    SpendLimitExceeded = 1,
+}
+
+#[contract]
+pub struct SpendLimitWallet;
+
+#[contractimpl]
+impl SpendLimitWallet {
+    pub fn __constructor(env: Env, limit: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("limit"), &limit);
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for SpendLimitWallet {
+    type Signature = ();
+    type Error = WalletError;
+
+    fn __check_auth(
+        env: Env,
+        _signature_payload: Hash<32>,
+        _signature: (),
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), WalletError> {
+        let limit: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("limit"))
+            .unwrap();
+        for context in auth_contexts.iter() {
+            if let Context::Contract(context) = context {
+                let amount = context
+                    .args
+                    .get(2)
+                    .and_then(|amount| i128::try_from_val(&env, &amount).ok())
+                    .unwrap_or(0);
+                if amount > limit {
+                    return Err(WalletError::SpendLimitExceeded);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invocation(
+    env: &Env,
+    contract: &Address,
+    function: &str,
+    args: Vec<Val>,
+    sub_invocations: std::vec::Vec<SorobanAuthorizedInvocation>,
+) -> SorobanAuthorizedInvocation {
+    let args: std::vec::Vec<ScVal> = args
+        .iter()
+        .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+        .collect();
+    SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: contract.into(),
+            function_name: function.try_into().unwrap(),
+            args: args.try_into().unwrap(),
+        }),
+        sub_invocations: sub_invocations.try_into().unwrap(),
+    }
+}
+
+// Authorizes `wallet` to pay an invoice on behalf of itself. The wallet's
+// `__check_auth` runs against the payment context and the token transfer.
+fn authorize_payment(
+    t: &ShadeTestEnv,
+    wallet: &Address,
+    invoice_id: u64,
+    amount: i128,
+    nonce: i64,
+) {
+    let transfer = invocation(
+        &t.env,
+        &t.token(),
+        "transfer",
+        (wallet.clone(), t.client.address.clone(), amount).into_val(&t.env),
+        std::vec![],
+    );
+    let payment = invocation(
+        &t.env,
+        &t.client.address,
+        "pay_invoice_on_behalf",
+        (invoice_id, t.token(), amount).into_val(&t.env),
+        std::vec![transfer],
+    );
+
+    t.env.set_auths(&[SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: wallet.into(),
+            nonce,
+            signature_expiration_ledger: t.env.ledger().sequence() + 100,
+            signature: ScVal::Void,
+        }),
+        root_invocation: payment,
+    }]);
+}
+
+fn create_invoice(t: &ShadeTestEnv, amount: i128) -> u64 {
+    t.env.mock_all_auths();
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Wallet payment"),
+        &amount,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_contract_wallet_pays_within_its_spend_limit() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let wallet = t.env.register(SpendLimitWallet, (1_500_i128,));
+    t.mint(&wallet, 5_000);
+    let invoice_id = create_invoice(&t, 1_000);
+
+    authorize_payment(&t, &wallet, invoice_id, 1_000, 1);
+    t.client
+        .pay_invoice_on_behalf(&wallet, &wallet, &invoice_id);
+
+    assert_eq!(
+        t.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Paid
+    );
+    assert_eq!(t.token_client().balance(&wallet), 4_000);
+}
+
+#[test]
+fn test_contract_wallet_rejects_payment_over_its_spend_limit() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let wallet = t.env.register(SpendLimitWallet, (1_500_i128,));
+    t.mint(&wallet, 5_000);
+    let invoice_id = create_invoice(&t, 2_000);
+
+    authorize_payment(&t, &wallet, invoice_id, 2_000, 1);
+    assert!(t
+        .client
+        .try_pay_invoice_on_behalf(&wallet, &wallet, &invoice_id)
+        .is_err());
+
+    assert_eq!(
+        t.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+    assert_eq!(t.token_client().balance(&wallet), 5_000);
+}
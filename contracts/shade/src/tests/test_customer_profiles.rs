@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::errors::{AccountError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceExpiryPolicy;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_register_update_and_remove_customer() {
+    let t = ShadeTestEnv::new().with_token(0);
+    let customer = Address::generate(&t.env);
+    assert_eq!(t.client.get_customer(&customer), None);
+
+    let unknown_token = Address::generate(&t.env);
+    assert_contract_error(
+        t.client
+            .try_register_customer(&customer, &Some(unknown_token), &None, &None),
+        ContractError::TokenNotAccepted,
+    );
+
+    t.env.ledger().set_timestamp(100);
+    let contact_hash = BytesN::from_array(&t.env, &[7; 32]);
+    t.client.register_customer(
+        &customer,
+        &Some(t.token()),
+        &None,
+        &Some(contact_hash.clone()),
+    );
+
+    t.env.ledger().set_timestamp(200);
+    let refund_wallet = Address::generate(&t.env);
+    t.client.register_customer(
+        &customer,
+        &Some(t.token()),
+        &Some(refund_wallet.clone()),
+        &Some(contact_hash.clone()),
+    );
+    let profile = t.client.get_customer(&customer).unwrap();
+    assert_eq!(profile.preferred_token, Some(t.token()));
+    assert_eq!(profile.refund_address, Some(refund_wallet));
+    assert_eq!(profile.contact_hash, Some(contact_hash));
+    assert_eq!(profile.registered_at, 100);
+
+    t.client.remove_customer(&customer);
+    assert_eq!(t.client.get_customer(&customer), None);
+    assert_contract_error(
+        t.client.try_remove_customer(&customer),
+        AccountError::CustomerNotFound,
+    );
+}
+
+#[test]
+fn test_campaign_refund_goes_to_registered_refund_address() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let campaign_id = t
+        .client
+        .create_campaign(&t.merchant(), &t.token(), &1_000, &3_600);
+
+    let contributor = Address::generate(&t.env);
+    t.mint(&contributor, 300);
+    t.client.contribute(&contributor, &campaign_id, &300);
+
+    let refund_wallet = Address::generate(&t.env);
+    t.client
+        .register_customer(&contributor, &None, &Some(refund_wallet.clone()), &None);
+    t.env.ledger().set_timestamp(3_600);
+    t.client.claim_refund(&contributor, &campaign_id);
+
+    assert_eq!(t.token_client().balance(&refund_wallet), 300);
+    assert_eq!(t.token_client().balance(&contributor), 0);
+}
+
+#[test]
+fn test_refund_goes_to_registered_refund_address() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    t.mint(&account_id, 5_000);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    let refund_wallet = Address::generate(&t.env);
+    t.client
+        .register_customer(&payer, &None, &Some(refund_wallet.clone()), &None);
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &400);
+
+    assert_eq!(t.token_client().balance(&refund_wallet), 400);
+    assert_eq!(t.token_client().balance(&payer), 0);
+}
+
+#[test]
+fn test_withdrawn_contribution_goes_to_refund_address() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::After(3_600));
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Group dinner"),
+        &1_000,
+        &t.token(),
+    );
+    t.client
+        .set_invoice_multi_payer(&t.merchant(), &invoice_id, &true);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 300);
+    t.client.contribute_to_invoice(&payer, &invoice_id, &300);
+
+    let refund_wallet = Address::generate(&t.env);
+    t.client
+        .register_customer(&payer, &None, &Some(refund_wallet.clone()), &None);
+    t.env.ledger().set_timestamp(3_600);
+    t.client.withdraw_contribution(&payer, &invoice_id);
+
+    assert_eq!(t.token_client().balance(&refund_wallet), 300);
+    assert_eq!(t.token_client().balance(&payer), 0);
+}
@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, GovernanceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::DistributionShare;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_fees_are_distributed_by_weight_and_claimed() {
+    // 1% fee on 10_000 collects 100 in fees.
+    let t = ShadeTestEnv::new()
+        .with_token(100)
+        .with_merchant()
+        .with_paid_invoice(10_000);
+    let token = t.token();
+    let staker = Address::generate(&t.env);
+    let partner = Address::generate(&t.env);
+
+    t.client.set_distribution_shares(
+        &t.admin,
+        &vec![
+            &t.env,
+            DistributionShare {
+                recipient: staker.clone(),
+                weight: 2,
+            },
+            DistributionShare {
+                recipient: partner.clone(),
+                weight: 1,
+            },
+        ],
+    );
+    assert_eq!(t.client.get_undistributed_fees(&token), 100);
+
+    assert_eq!(t.client.distribute_fees(&token), 99);
+    assert_eq!(t.client.get_claimable_distribution(&staker, &token), 66);
+    assert_eq!(t.client.get_claimable_distribution(&partner, &token), 33);
+    // Rounding dust carries over to the next distribution.
+    assert_eq!(t.client.get_undistributed_fees(&token), 1);
+
+    assert_eq!(t.client.claim_distribution(&staker, &token), 66);
+    assert_eq!(t.token_client().balance(&staker), 66);
+    assert_eq!(t.client.get_claimable_distribution(&staker, &token), 0);
+    assert_contract_error(
+        t.client.try_claim_distribution(&staker, &token),
+        GovernanceError::NothingToClaim,
+    );
+
+    // Only fees collected since the last run are distributed again.
+    let t = t.with_paid_invoice(20_000);
+    assert_eq!(t.client.get_undistributed_fees(&token), 201);
+    assert_eq!(t.client.distribute_fees(&token), 201);
+    assert_eq!(t.client.get_claimable_distribution(&staker, &token), 134);
+    assert_eq!(t.client.get_claimable_distribution(&partner, &token), 100);
+    assert_eq!(t.client.get_collected_fees(&token), 300);
+}
+
+#[test]
+fn test_distribution_shares_validation() {
+    let t = ShadeTestEnv::new().with_token(100);
+    let recipient = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_distribute_fees(&t.token()),
+        GovernanceError::InvalidDistributionWeights,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_distribution_shares(&t.admin, &vec![&t.env]),
+        GovernanceError::InvalidDistributionWeights,
+    );
+    assert_contract_error(
+        t.client.try_set_distribution_shares(
+            &t.admin,
+            &vec![
+                &t.env,
+                DistributionShare {
+                    recipient: recipient.clone(),
+                    weight: 0,
+                },
+            ],
+        ),
+        GovernanceError::InvalidDistributionWeights,
+    );
+    assert_contract_error(
+        t.client.try_set_distribution_shares(
+            &recipient,
+            &vec![
+                &t.env,
+                DistributionShare {
+                    recipient: recipient.clone(),
+                    weight: 1,
+                },
+            ],
+        ),
+        ContractError::NotAuthorized,
+    );
+}
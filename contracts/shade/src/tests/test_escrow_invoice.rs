@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{EscrowHold, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, String};
+
+const PERIOD: u64 = 7 * 86_400;
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn pay_escrow_invoice(t: &ShadeTestEnv) -> (u64, Address) {
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Marketplace order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client
+        .set_invoice_escrow(&t.merchant(), &invoice_id, &Some(PERIOD));
+
+    let buyer = Address::generate(&t.env);
+    t.mint(&buyer, 1_000);
+    let invoice = t.client.pay_invoice(&buyer, &invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    (invoice_id, buyer)
+}
+
+#[test]
+fn test_buyer_confirmation_releases_funds() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let (invoice_id, buyer) = pay_escrow_invoice(&t);
+
+    assert_eq!(
+        t.client.get_escrow_hold(&invoice_id),
+        Some(EscrowHold {
+            amount: 990,
+            release_at: t.env.ledger().timestamp() + PERIOD,
+        })
+    );
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+
+    assert_contract_error(
+        t.client.try_confirm_delivery(&t.merchant(), &invoice_id),
+        ContractError::NotAuthorized,
+    );
+    t.client.confirm_delivery(&buyer, &invoice_id);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.client.get_escrow_hold(&invoice_id), None);
+    assert_contract_error(
+        t.client.try_confirm_delivery(&buyer, &invoice_id),
+        InvoiceError::EscrowNotHeld,
+    );
+}
+
+#[test]
+fn test_escrow_releases_after_timeout() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let (invoice_id, _) = pay_escrow_invoice(&t);
+
+    assert_contract_error(
+        t.client.try_release_escrow(&invoice_id),
+        InvoiceError::EscrowLocked,
+    );
+    t.env
+        .ledger()
+        .set_timestamp(t.env.ledger().timestamp() + PERIOD);
+    t.client.release_escrow(&invoice_id);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+}
+
+#[test]
+fn test_refund_is_paid_from_escrow_hold() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let (invoice_id, buyer) = pay_escrow_invoice(&t);
+
+    t.client.refund_invoice(&t.merchant(), &invoice_id);
+    assert_eq!(t.token_client().balance(&buyer), 1_000);
+    assert_eq!(t.client.get_escrow_hold(&invoice_id), None);
+    assert_eq!(
+        t.client.get_invoice_status(&invoice_id),
+        InvoiceStatus::Refunded
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, Symbol, TryIntoVal};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_exempt_merchant_pays_no_fee() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.client.set_fee_exempt(&t.admin, &t.merchant(), &true);
+    assert!(t.client.is_fee_exempt(&t.merchant()));
+
+    let t = t.with_paid_invoice(1_000);
+
+    let events = t.env.events().all();
+    let waived = events.iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "fee_waived_event")
+    });
+    assert!(waived);
+
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 0);
+}
+
+#[test]
+fn test_fee_applies_once_exemption_is_removed() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.client.set_fee_exempt(&t.admin, &t.merchant(), &true);
+    t.client.set_fee_exempt(&t.admin, &t.merchant(), &false);
+    assert!(!t.client.is_fee_exempt(&t.merchant()));
+
+    let t = t.with_paid_invoice(1_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+}
+
+#[test]
+fn test_only_admin_sets_fee_exemptions() {
+    let t = ShadeTestEnv::new();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_set_fee_exempt(&outsider, &outsider, &true),
+        ContractError::NotAuthorized,
+    );
+    assert!(!t.client.is_fee_exempt(&outsider));
+}
@@ -15,6 +15,7 @@ fn setup_with_accepted_token(env: &Env) -> (Address, ShadeClient<'_>, Address) {
 
     let admin = Address::generate(env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     let token_admin = Address::generate(env);
     let token = env
@@ -34,21 +35,21 @@ fn assert_fee_set_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(event_contract_id, contract_id.clone());
-    assert_eq!(topics.len(), 1);
+    assert_eq!(topics.len(), 2);
 
     let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
     assert_eq!(event_name, Symbol::new(env, "fee_set_event"));
 
+    let token_in_event: Address = topics.get(1).unwrap().try_into_val(env).unwrap();
+
     let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
-    let token_val = data_map.get(Symbol::new(env, "token")).unwrap();
     let fee_val = data_map.get(Symbol::new(env, "fee")).unwrap();
     let timestamp_val = data_map.get(Symbol::new(env, "timestamp")).unwrap();
 
-    let token_in_event: Address = token_val.try_into_val(env).unwrap();
     let fee_in_event: i128 = fee_val.try_into_val(env).unwrap();
     let timestamp_in_event: u64 = timestamp_val.try_into_val(env).unwrap();
 
@@ -108,6 +109,23 @@ fn test_set_fee_unauthorized() {
     assert!(matches!(result, Err(Ok(err)) if err == expected_error));
 }
 
+#[test]
+fn test_set_fee_rejects_out_of_range_bps() {
+    let env = Env::default();
+    let (admin, client, token) = setup_with_accepted_token(&env);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::InvalidAmount as u32);
+
+    let result = client.try_set_fee(&admin, &token, &-1);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    let result = client.try_set_fee(&admin, &token, &10_001);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    client.set_fee(&admin, &token, &10_000);
+    assert_eq!(client.get_fee(&token), 10_000);
+}
+
 #[test]
 fn test_update_fee() {
     let env = Env::default();
@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::{Address as _, IssuerFlags};
+use soroban_sdk::{token, Address, Bytes, BytesN, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+// Freezing a holder needs an issuer with AUTH_REVOCABLE set.
+fn with_revocable_token<'a>(mut t: ShadeTestEnv<'a>) -> ShadeTestEnv<'a> {
+    let sac = t
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&t.env));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    t.client.add_accepted_token(&t.admin, &sac.address());
+    t.token = Some(sac.address());
+    t
+}
+
+fn freeze(t: &ShadeTestEnv, holder: &Address) {
+    token::StellarAssetClient::new(&t.env, &t.token()).set_authorized(holder, &false);
+}
+
+#[test]
+fn test_frozen_payer_gets_asset_frozen() {
+    let t = with_revocable_token(ShadeTestEnv::new()).with_merchant();
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    freeze(&t, &payer);
+
+    let hash = BytesN::from_array(&t.env, &[1; 32]);
+    let expires_at = t.env.ledger().timestamp() + 3_600;
+    assert_contract_error(
+        t.client
+            .try_create_payment_link(&payer, &t.token(), &1_000, &hash, &expires_at),
+        ComplianceError::AssetFrozen,
+    );
+    assert_eq!(t.token_client().balance(&payer), 1_000);
+}
+
+#[test]
+fn test_frozen_recipient_gets_transfer_blocked() {
+    let t = with_revocable_token(ShadeTestEnv::new()).with_merchant();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Frozen merchant"),
+        &1_000,
+        &t.token(),
+    );
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let secret = Bytes::from_array(&t.env, &[2; 4]);
+    let hash: BytesN<32> = t.env.crypto().sha256(&secret).into();
+    let expires_at = t.env.ledger().timestamp() + 3_600;
+    let link_id = t
+        .client
+        .create_payment_link(&payer, &t.token(), &1_000, &hash, &expires_at);
+    t.commit_claim(&t.merchant(), link_id, &secret);
+
+    freeze(&t, &t.merchant());
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &secret, &Some(invoice_id)),
+        ComplianceError::TransferBlocked,
+    );
+    assert_eq!(t.token_client().balance(&t.client.address), 1_000);
+}
+
+#[test]
+fn test_clawback_assets_rejected_when_enabled() {
+    let t = ShadeTestEnv::new();
+    let token = t
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&t.env))
+        .address();
+
+    t.client.set_clawback_asset(&t.admin, &token, &true);
+    assert!(t.client.is_clawback_asset(&token));
+
+    t.client.set_reject_clawback_assets(&t.admin, &true);
+    assert!(t.client.rejects_clawback_assets());
+    assert_contract_error(
+        t.client.try_add_accepted_token(&t.admin, &token),
+        ComplianceError::ClawbackAssetRejected,
+    );
+
+    t.client.set_reject_clawback_assets(&t.admin, &false);
+    t.client.add_accepted_token(&t.admin, &token);
+    assert!(t.client.is_accepted_token(&token));
+}
+
+#[test]
+fn test_only_admin_sets_clawback_policy() {
+    let t = ShadeTestEnv::new();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_set_reject_clawback_assets(&outsider, &true),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_set_clawback_asset(&outsider, &outsider, &true),
+        ContractError::NotAuthorized,
+    );
+    assert!(!t.client.rejects_clawback_assets());
+}
@@ -0,0 +1,216 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::{GiftCardStatus, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, String};
+
+const NOW: u64 = 1_000;
+const EXPIRES_AT: u64 = 5_000;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+
+    let buyer = Address::generate(&t.env);
+    t.mint(&buyer, 10_000);
+
+    (t, buyer)
+}
+
+fn create_invoice(t: &ShadeTestEnv, amount: i128) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Gift card order"),
+        &amount,
+        &t.token(),
+    )
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_issue_gift_card_escrows_funds() {
+    let (t, buyer) = setup_test();
+
+    let card_id = t
+        .client
+        .issue_gift_card(&buyer, &1, &t.token(), &1_000, &EXPIRES_AT, &None);
+
+    let card = t.client.get_gift_card(&card_id);
+    assert_eq!(card.holder, Some(buyer.clone()));
+    assert_eq!(card.balance, 1_000);
+    assert_eq!(card.status, GiftCardStatus::Active);
+    assert_eq!(t.token_client().balance(&t.client.address), 1_000);
+}
+
+#[test]
+fn test_redeem_gift_card_pays_invoice() {
+    let (t, buyer) = setup_test();
+    let card_id = t
+        .client
+        .issue_gift_card(&buyer, &1, &t.token(), &1_000, &EXPIRES_AT, &None);
+    let invoice_id = create_invoice(&t, 600);
+
+    t.client.redeem_gift_card(&buyer, &card_id, &invoice_id);
+
+    let invoice = t.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(buyer.clone()));
+    assert_eq!(invoice.date_paid, Some(NOW));
+    assert_eq!(t.client.get_gift_card(&card_id).balance, 400);
+    assert_eq!(t.token_client().balance(&t.merchant()), 594);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 6);
+
+    assert_contract_error(
+        t.client.try_redeem_gift_card(&buyer, &card_id, &invoice_id),
+        ContractError::InvoiceNotPending,
+    );
+}
+
+#[test]
+fn test_redeem_depletes_card() {
+    let (t, buyer) = setup_test();
+    let card_id = t
+        .client
+        .issue_gift_card(&buyer, &1, &t.token(), &500, &EXPIRES_AT, &None);
+
+    t.client
+        .redeem_gift_card(&buyer, &card_id, &create_invoice(&t, 500));
+    assert_eq!(
+        t.client.get_gift_card(&card_id).status,
+        GiftCardStatus::Depleted
+    );
+}
+
+#[test]
+fn test_redeem_rejects_insufficient_balance_and_other_merchant() {
+    let (t, buyer) = setup_test();
+    let card_id = t
+        .client
+        .issue_gift_card(&buyer, &1, &t.token(), &500, &EXPIRES_AT, &None);
+
+    assert_contract_error(
+        t.client
+            .try_redeem_gift_card(&buyer, &card_id, &create_invoice(&t, 501)),
+        ContractError::InsufficientGiftCardBalance,
+    );
+
+    let other_merchant = Address::generate(&t.env);
+    let other_id = t.client.register_merchant(&other_merchant);
+    t.client.approve_merchant(&t.admin, &other_id);
+    let other_invoice = t.client.create_invoice(
+        &other_merchant,
+        &String::from_str(&t.env, "Elsewhere"),
+        &100,
+        &t.token(),
+    );
+    assert_contract_error(
+        t.client
+            .try_redeem_gift_card(&buyer, &card_id, &other_invoice),
+        ContractError::GiftCardNotApplicable,
+    );
+}
+
+#[test]
+fn test_claim_hash_card_requires_secret() {
+    let (t, buyer) = setup_test();
+    let secret = Bytes::from_slice(&t.env, b"open sesame");
+    let claim_hash: BytesN<32> = t.env.crypto().sha256(&secret).into();
+
+    let card_id = t.client.issue_gift_card(
+        &buyer,
+        &1,
+        &t.token(),
+        &1_000,
+        &EXPIRES_AT,
+        &Some(claim_hash),
+    );
+    assert_eq!(t.client.get_gift_card(&card_id).holder, None);
+
+    let recipient = Address::generate(&t.env);
+    let invoice_id = create_invoice(&t, 100);
+    assert_contract_error(
+        t.client.try_redeem_gift_card(&buyer, &card_id, &invoice_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_claim_gift_card(&recipient, &card_id, &Bytes::from_slice(&t.env, b"wrong")),
+        ContractError::InvalidClaimSecret,
+    );
+
+    t.client.claim_gift_card(&recipient, &card_id, &secret);
+    assert_eq!(
+        t.client.get_gift_card(&card_id).holder,
+        Some(recipient.clone())
+    );
+
+    t.client.redeem_gift_card(&recipient, &card_id, &invoice_id);
+    assert_eq!(t.client.get_gift_card(&card_id).balance, 900);
+}
+
+#[test]
+fn test_expire_gift_card_refunds_buyer() {
+    let (t, buyer) = setup_test();
+    let card_id = t
+        .client
+        .issue_gift_card(&buyer, &1, &t.token(), &1_000, &EXPIRES_AT, &None);
+    t.client
+        .redeem_gift_card(&buyer, &card_id, &create_invoice(&t, 300));
+
+    assert_contract_error(
+        t.client.try_expire_gift_card(&card_id),
+        ContractError::GiftCardNotExpired,
+    );
+
+    t.env.ledger().set_timestamp(EXPIRES_AT);
+    assert_contract_error(
+        t.client
+            .try_redeem_gift_card(&buyer, &card_id, &create_invoice(&t, 100)),
+        ContractError::GiftCardExpired,
+    );
+
+    t.client.expire_gift_card(&card_id);
+
+    let card = t.client.get_gift_card(&card_id);
+    assert_eq!(card.status, GiftCardStatus::Expired);
+    assert_eq!(card.balance, 0);
+    assert_eq!(t.token_client().balance(&buyer), 10_000 - 1_000 + 700);
+    assert_contract_error(
+        t.client.try_expire_gift_card(&card_id),
+        ContractError::GiftCardNotActive,
+    );
+}
+
+#[test]
+fn test_issue_gift_card_validation() {
+    let (t, buyer) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_issue_gift_card(&buyer, &1, &t.token(), &0, &EXPIRES_AT, &None),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client
+            .try_issue_gift_card(&buyer, &1, &t.token(), &100, &NOW, &None),
+        ContractError::GiftCardExpired,
+    );
+    assert_contract_error(
+        t.client
+            .try_issue_gift_card(&buyer, &9, &t.token(), &100, &EXPIRES_AT, &None),
+        ContractError::MerchantNotFound,
+    );
+    assert_contract_error(
+        t.client.try_get_gift_card(&9),
+        ContractError::GiftCardNotFound,
+    );
+}
@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+use crate::components::governance::GOVERNANCE_TIMELOCK;
+use crate::errors::{ContractError, GovernanceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{ParameterChange, ProposalStatus};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{vec, Address, Symbol, TryIntoVal};
+
+struct GovernanceTest<'a> {
+    t: ShadeTestEnv<'a>,
+    members: [Address; 3],
+}
+
+fn setup_test<'a>() -> GovernanceTest<'a> {
+    let t = ShadeTestEnv::new().with_token(100);
+    let members = [
+        Address::generate(&t.env),
+        Address::generate(&t.env),
+        Address::generate(&t.env),
+    ];
+    t.client.set_council(
+        &t.admin,
+        &vec![
+            &t.env,
+            members[0].clone(),
+            members[1].clone(),
+            members[2].clone(),
+        ],
+        &2,
+    );
+
+    GovernanceTest { t, members }
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn advance_time(t: &ShadeTestEnv, seconds: u64) {
+    t.env
+        .ledger()
+        .with_mut(|ledger| ledger.timestamp += seconds);
+}
+
+#[test]
+fn test_approved_proposal_executes_after_timelock() {
+    let g = setup_test();
+    let t = &g.t;
+    let change = ParameterChange::Fee(t.token(), 250);
+
+    let proposal_id = t.client.propose_change(&g.members[0], &change);
+    assert_eq!(t.client.get_proposal(&proposal_id).executable_at, None);
+    assert_contract_error(
+        t.client.try_execute_proposal(&proposal_id),
+        GovernanceError::ProposalNotExecutable,
+    );
+
+    t.client.vote_on_proposal(&g.members[1], &proposal_id);
+    let executable_at = t.env.ledger().timestamp() + GOVERNANCE_TIMELOCK;
+    assert_eq!(
+        t.client.get_proposal(&proposal_id).executable_at,
+        Some(executable_at)
+    );
+    assert_contract_error(
+        t.client.try_execute_proposal(&proposal_id),
+        GovernanceError::ProposalNotExecutable,
+    );
+
+    advance_time(t, GOVERNANCE_TIMELOCK);
+    t.client.execute_proposal(&proposal_id);
+
+    let events = t.env.events().all();
+    let (_, topics, _) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(event_name, Symbol::new(&t.env, "proposal_status_event"));
+
+    assert_eq!(t.client.get_fee(&t.token()), 250);
+    assert_eq!(
+        t.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Executed
+    );
+    assert_contract_error(
+        t.client.try_execute_proposal(&proposal_id),
+        GovernanceError::ProposalNotExecutable,
+    );
+}
+
+#[test]
+fn test_only_council_members_propose_and_vote_once() {
+    let g = setup_test();
+    let t = &g.t;
+    let outsider = Address::generate(&t.env);
+    let change = ParameterChange::DefaultInvoiceExpiry(Some(3_600));
+
+    assert_contract_error(
+        t.client.try_propose_change(&outsider, &change),
+        GovernanceError::NotCouncilMember,
+    );
+
+    let proposal_id = t.client.propose_change(&g.members[0], &change);
+    assert_contract_error(
+        t.client.try_vote_on_proposal(&outsider, &proposal_id),
+        GovernanceError::NotCouncilMember,
+    );
+    assert_contract_error(
+        t.client.try_vote_on_proposal(&g.members[0], &proposal_id),
+        GovernanceError::AlreadyVoted,
+    );
+    assert_contract_error(
+        t.client.try_get_proposal(&99),
+        GovernanceError::ProposalNotFound,
+    );
+
+    t.client.vote_on_proposal(&g.members[2], &proposal_id);
+    advance_time(t, GOVERNANCE_TIMELOCK);
+    t.client.execute_proposal(&proposal_id);
+    assert_eq!(t.client.get_default_invoice_expiry(), Some(3_600));
+}
+
+#[test]
+fn test_removed_members_votes_no_longer_count() {
+    let g = setup_test();
+    let t = &g.t;
+
+    let proposal_id = t
+        .client
+        .propose_change(&g.members[0], &ParameterChange::Fee(t.token(), 500));
+    t.client.vote_on_proposal(&g.members[1], &proposal_id);
+
+    t.client.set_council(
+        &t.admin,
+        &vec![&t.env, g.members[0].clone(), g.members[2].clone()],
+        &2,
+    );
+    advance_time(t, GOVERNANCE_TIMELOCK);
+    assert_contract_error(
+        t.client.try_execute_proposal(&proposal_id),
+        GovernanceError::ProposalNotExecutable,
+    );
+
+    t.client.vote_on_proposal(&g.members[2], &proposal_id);
+    t.client.execute_proposal(&proposal_id);
+    assert_eq!(t.client.get_fee(&t.token()), 500);
+}
+
+#[test]
+fn test_admin_can_cancel_and_validates_council() {
+    let g = setup_test();
+    let t = &g.t;
+
+    assert_contract_error(
+        t.client
+            .try_set_council(&t.admin, &vec![&t.env, g.members[0].clone()], &2),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_set_council(&g.members[0], &vec![&t.env], &0),
+        ContractError::NotAuthorized,
+    );
+
+    let proposal_id = t
+        .client
+        .propose_change(&g.members[0], &ParameterChange::Fee(t.token(), 500));
+    t.client.vote_on_proposal(&g.members[1], &proposal_id);
+    t.client.cancel_proposal(&t.admin, &proposal_id);
+    assert_eq!(
+        t.client.get_proposal(&proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+
+    advance_time(t, GOVERNANCE_TIMELOCK);
+    assert_contract_error(
+        t.client.try_execute_proposal(&proposal_id),
+        GovernanceError::ProposalNotExecutable,
+    );
+    assert_eq!(t.client.get_fee(&t.token()), 100);
+}
@@ -1,9 +1,10 @@
 #![cfg(test)]
 
 use crate::shade::{Shade, ShadeClient};
-use crate::types::InvoiceStatus;
+use crate::testutils::ShadeTestEnv;
+use crate::types::{InvoiceBalance, InvoiceStatus};
 use soroban_sdk::testutils::{Address as _, Events as _};
-use soroban_sdk::{Address, Env, Map, String, Symbol, TryIntoVal, Val};
+use soroban_sdk::{vec, Address, Env, Map, String, Symbol, TryIntoVal, Val, Vec};
 
 fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
     let env = Env::default();
@@ -12,6 +13,8 @@ fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
     let client = ShadeClient::new(&env, &contract_id);
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
+    client.set_permissionless_registration(&admin, &true);
     (env, client, contract_id, admin)
 }
 
@@ -24,22 +27,19 @@ fn assert_latest_invoice_event(
     expected_token: &Address,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0, "No events captured for invoice!");
+    assert!(!events.is_empty(), "No events captured for invoice!");
 
-    let (event_contract_id, _topics, data) = events.get(events.len() - 1).unwrap();
+    let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(&event_contract_id, contract_id);
+    assert_eq!(topics.len(), 4);
 
-    let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
+    let invoice_id_in_event: u64 = topics.get(1).unwrap().try_into_val(env).unwrap();
+    let merchant_in_event: Address = topics.get(2).unwrap().try_into_val(env).unwrap();
+    let token_in_event: Address = topics.get(3).unwrap().try_into_val(env).unwrap();
 
-    let invoice_id_val = data_map.get(Symbol::new(env, "invoice_id")).unwrap();
-    let merchant_val = data_map.get(Symbol::new(env, "merchant")).unwrap();
+    let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
     let amount_val = data_map.get(Symbol::new(env, "amount")).unwrap();
-    let token_val = data_map.get(Symbol::new(env, "token")).unwrap();
-
-    let invoice_id_in_event: u64 = invoice_id_val.try_into_val(env).unwrap();
-    let merchant_in_event: Address = merchant_val.try_into_val(env).unwrap();
     let amount_in_event: i128 = amount_val.try_into_val(env).unwrap();
-    let token_in_event: Address = token_val.try_into_val(env).unwrap();
 
     assert_eq!(invoice_id_in_event, expected_invoice_id);
     assert_eq!(merchant_in_event, expected_merchant.clone());
@@ -141,3 +141,127 @@ fn test_create_invoice_invalid_amount() {
 
     client.create_invoice(&merchant, &description, &amount, &token);
 }
+
+#[test]
+fn test_get_invoices_by_ids() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+
+    let token = Address::generate(&env);
+    for amount in [100_i128, 200, 300] {
+        client.create_invoice(
+            &merchant,
+            &String::from_str(&env, "Invoice"),
+            &amount,
+            &token,
+        );
+    }
+
+    let invoices = client.get_invoices_by_ids(&vec![&env, 3, 999, 1]);
+    assert_eq!(invoices.len(), 2);
+    assert_eq!(invoices.get(0).unwrap().id, 3);
+    assert_eq!(invoices.get(0).unwrap().amount, 300);
+    assert_eq!(invoices.get(1).unwrap().id, 1);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #13)")]
+#[test]
+fn test_get_invoices_by_ids_too_many() {
+    let (env, client, _contract_id, _admin) = setup_test();
+
+    let mut ids = Vec::new(&env);
+    for id in 0..=100 {
+        ids.push_back(id);
+    }
+    client.get_invoices_by_ids(&ids);
+}
+
+#[test]
+fn test_get_invoice_status_and_balance() {
+    let t = ShadeTestEnv::new()
+        .with_token(0)
+        .with_merchant()
+        .with_paid_invoice(1_000);
+    let paid_id = t.paid_invoices.get(0).unwrap();
+    let pending_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Pending"),
+        &400,
+        &t.token(),
+    );
+
+    assert_eq!(
+        t.client.get_invoice_status(&pending_id),
+        InvoiceStatus::Pending
+    );
+    assert_eq!(
+        t.client.get_invoice_balance(&pending_id),
+        InvoiceBalance {
+            status: InvoiceStatus::Pending,
+            amount: 400,
+            paid: 0,
+            refunded: 0,
+        }
+    );
+
+    assert_eq!(t.client.get_invoice_status(&paid_id), InvoiceStatus::Paid);
+    assert_eq!(
+        t.client.get_invoice_balance(&paid_id),
+        InvoiceBalance {
+            status: InvoiceStatus::Paid,
+            amount: 1_000,
+            paid: 1_000,
+            refunded: 0,
+        }
+    );
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #8)")]
+#[test]
+fn test_get_invoice_status_not_found() {
+    let t = ShadeTestEnv::new();
+    t.client.get_invoice_status(&99);
+}
+
+#[test]
+fn test_pay_invoice_on_behalf_records_beneficiary() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let funder = Address::generate(&t.env);
+    let beneficiary = Address::generate(&t.env);
+    t.mint(&funder, 1_000);
+
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "School fees"),
+        &1_000,
+        &t.token(),
+    );
+    let invoice = t
+        .client
+        .pay_invoice_on_behalf(&funder, &beneficiary, &invoice_id);
+
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(beneficiary.clone()));
+    assert_eq!(t.token_client().balance(&funder), 0);
+    assert_eq!(t.token_client().balance(&beneficiary), 0);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #34)")]
+#[test]
+fn test_pay_invoice_on_behalf_rejects_paid_invoice() {
+    let t = ShadeTestEnv::new()
+        .with_token(0)
+        .with_merchant()
+        .with_paid_invoice(1_000);
+    let funder = Address::generate(&t.env);
+    t.mint(&funder, 1_000);
+
+    t.client.pay_invoice_on_behalf(
+        &funder,
+        &Address::generate(&t.env),
+        &t.paid_invoices.get(0).unwrap(),
+    );
+}
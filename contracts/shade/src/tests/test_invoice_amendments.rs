@@ -0,0 +1,220 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Consulting"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_amendments_are_recorded_in_order() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    assert!(t.client.get_invoice_history(&invoice_id).is_empty());
+
+    t.env.ledger().set_timestamp(100);
+    t.client.amend_invoice(
+        &t.merchant(),
+        &invoice_id,
+        &Some(1_200),
+        &None,
+        &None,
+        &None,
+    );
+
+    let delegate = Address::generate(&t.env);
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &delegate, &true);
+    t.env.ledger().set_timestamp(200);
+    let description = String::from_str(&t.env, "Consulting, March");
+    t.client.amend_invoice(
+        &delegate,
+        &invoice_id,
+        &None,
+        &Some(description.clone()),
+        &None,
+        &None,
+    );
+
+    let invoice = t.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.amount, 1_200);
+    assert_eq!(invoice.description, description);
+    assert_eq!(t.client.get_invoice_balance(&invoice_id).amount, 1_200);
+
+    let history = t.client.get_invoice_history(&invoice_id);
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    assert_eq!(first.actor, t.merchant());
+    assert_eq!(first.timestamp, 100);
+    assert_eq!((first.old_amount, first.new_amount), (1_000, 1_200));
+    assert_eq!(first.old_description, first.new_description);
+    let second = history.get(1).unwrap();
+    assert_eq!(second.actor, delegate);
+    assert_eq!(second.timestamp, 200);
+    assert_eq!((second.old_amount, second.new_amount), (1_200, 1_200));
+    assert_eq!(
+        second.old_description,
+        String::from_str(&t.env, "Consulting")
+    );
+    assert_eq!(second.new_description, description);
+}
+
+#[test]
+fn test_amend_rejects_outsiders_and_settled_invoices() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+
+    let stranger = Address::generate(&t.env);
+    assert_contract_error(
+        t.client
+            .try_amend_invoice(&stranger, &invoice_id, &Some(500), &None, &None, &None),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_amend_invoice(&t.merchant(), &invoice_id, &None, &None, &None, &None),
+        InvoiceError::InvalidAmendment,
+    );
+    assert_contract_error(
+        t.client
+            .try_amend_invoice(&t.merchant(), &invoice_id, &Some(0), &None, &None, &None),
+        ContractError::InvalidAmount,
+    );
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+    assert_contract_error(
+        t.client
+            .try_amend_invoice(&t.merchant(), &invoice_id, &Some(500), &None, &None, &None),
+        ContractError::InvoiceNotPending,
+    );
+    assert!(t.client.get_invoice_history(&invoice_id).is_empty());
+}
+
+#[test]
+fn test_amount_is_locked_by_milestones() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    t.client
+        .set_invoice_milestones(&t.merchant(), &invoice_id, &vec![&t.env, 400, 600]);
+
+    assert_contract_error(
+        t.client.try_amend_invoice(
+            &t.merchant(),
+            &invoice_id,
+            &Some(1_500),
+            &None,
+            &None,
+            &None,
+        ),
+        InvoiceError::InvalidMilestones,
+    );
+}
+
+#[test]
+fn test_raising_amount_above_threshold_requires_approval() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.client
+        .set_invoice_approval_threshold(&t.merchant(), &t.token(), &Some(5_000));
+    let invoice_id = create_invoice(&t);
+    assert!(!t.client.is_invoice_awaiting_approval(&invoice_id));
+
+    t.client.amend_invoice(
+        &t.merchant(),
+        &invoice_id,
+        &Some(8_000),
+        &None,
+        &None,
+        &None,
+    );
+    assert!(t.client.is_invoice_awaiting_approval(&invoice_id));
+}
+
+#[test]
+fn test_amend_moves_expiry_and_switches_token() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.env.ledger().set_timestamp(1_000);
+    let invoice_id = create_invoice(&t);
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), None);
+
+    t.client.amend_invoice(
+        &t.merchant(),
+        &invoice_id,
+        &None,
+        &None,
+        &Some(5_000),
+        &None,
+    );
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), Some(5_000));
+    assert_eq!(t.client.list_expiring_invoices(&10_000, &0).len(), 1);
+
+    t.client.amend_invoice(
+        &t.merchant(),
+        &invoice_id,
+        &None,
+        &None,
+        &Some(50_000),
+        &None,
+    );
+    assert_eq!(t.client.list_expiring_invoices(&10_000, &0).len(), 0);
+    assert_contract_error(
+        t.client.try_amend_invoice(
+            &t.merchant(),
+            &invoice_id,
+            &None,
+            &None,
+            &Some(1_000),
+            &None,
+        ),
+        InvoiceError::InvoiceExpired,
+    );
+
+    let other_token = t
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&t.env))
+        .address();
+    assert_contract_error(
+        t.client.try_amend_invoice(
+            &t.merchant(),
+            &invoice_id,
+            &None,
+            &None,
+            &None,
+            &Some(other_token.clone()),
+        ),
+        ContractError::TokenNotAccepted,
+    );
+    t.client.add_accepted_token(&t.admin, &other_token);
+    t.client.amend_invoice(
+        &t.merchant(),
+        &invoice_id,
+        &None,
+        &None,
+        &None,
+        &Some(other_token.clone()),
+    );
+    assert_eq!(t.client.get_invoice(&invoice_id).token, other_token);
+
+    let last = t.client.get_invoice_history(&invoice_id).get(2).unwrap();
+    assert_eq!(last.old_token, t.token());
+    assert_eq!(last.new_token, other_token);
+    assert_eq!(last.old_expires_at, Some(50_000));
+    assert_eq!(last.new_expires_at, Some(50_000));
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let backend = Address::generate(&t.env);
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &backend, &true);
+    t.client
+        .set_invoice_approval_threshold(&t.merchant(), &t.token(), &Some(1_000));
+    (t, backend)
+}
+
+fn funded_payer(t: &ShadeTestEnv, amount: i128) -> Address {
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, amount);
+    payer
+}
+
+#[test]
+fn test_large_delegate_invoice_needs_merchant_approval() {
+    let (t, backend) = setup_test();
+    let description = String::from_str(&t.env, "Wholesale order");
+
+    let small = t.client.create_delegated_invoice(
+        &backend,
+        &t.merchant(),
+        &description,
+        &1_000,
+        &t.token(),
+    );
+    assert!(!t.client.is_invoice_awaiting_approval(&small));
+
+    let large = t.client.create_delegated_invoice(
+        &backend,
+        &t.merchant(),
+        &description,
+        &5_000,
+        &t.token(),
+    );
+    assert!(t.client.is_invoice_awaiting_approval(&large));
+    let payer = funded_payer(&t, 5_000);
+    assert_contract_error(
+        t.client.try_pay_invoice(&payer, &large),
+        InvoiceError::InvoiceAwaitingApproval,
+    );
+
+    assert_contract_error(
+        t.client.try_approve_invoice(&backend, &large),
+        ContractError::NotAuthorized,
+    );
+    t.client.approve_invoice(&t.merchant(), &large);
+    assert!(!t.client.is_invoice_awaiting_approval(&large));
+
+    let invoice = t.client.pay_invoice(&payer, &large);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+}
+
+#[test]
+fn test_large_merchant_invoice_needs_delegate_approval() {
+    let (t, backend) = setup_test();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Retainer"),
+        &5_000,
+        &t.token(),
+    );
+
+    assert_contract_error(
+        t.client.try_approve_invoice(&t.merchant(), &invoice_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_approve_invoice(&Address::generate(&t.env), &invoice_id),
+        ContractError::NotAuthorized,
+    );
+
+    t.client.approve_invoice(&backend, &invoice_id);
+    assert_contract_error(
+        t.client.try_approve_invoice(&backend, &invoice_id),
+        InvoiceError::InvoiceNotAwaitingApproval,
+    );
+}
+
+#[test]
+fn test_approval_threshold_settings() {
+    let (t, _) = setup_test();
+    assert_eq!(
+        t.client
+            .get_invoice_approval_threshold(&t.merchant_id(), &t.token()),
+        Some(1_000)
+    );
+
+    assert_contract_error(
+        t.client
+            .try_set_invoice_approval_threshold(&t.merchant(), &t.token(), &Some(0)),
+        ContractError::InvalidAmount,
+    );
+
+    t.client
+        .set_invoice_approval_threshold(&t.merchant(), &t.token(), &None);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Retainer"),
+        &5_000,
+        &t.token(),
+    );
+    assert!(!t.client.is_invoice_awaiting_approval(&invoice_id));
+}
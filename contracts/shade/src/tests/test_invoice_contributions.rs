@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{InvoiceExpiryPolicy, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Team dinner"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+fn funded_payer(t: &ShadeTestEnv) -> Address {
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    payer
+}
+
+#[test]
+fn test_split_bill_settles_once_covered() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let invoice_id = create_invoice(&t);
+    t.client
+        .set_invoice_multi_payer(&t.merchant(), &invoice_id, &true);
+    assert!(t.client.is_invoice_multi_payer(&invoice_id));
+
+    let alice = funded_payer(&t);
+    let bob = funded_payer(&t);
+    let invoice = t.client.contribute_to_invoice(&alice, &invoice_id, &600);
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(t.client.get_invoice_contribution(&invoice_id, &alice), 600);
+
+    assert_contract_error(
+        t.client.try_contribute_to_invoice(&bob, &invoice_id, &500),
+        ContractError::InvalidAmount,
+    );
+    let invoice = t.client.contribute_to_invoice(&bob, &invoice_id, &400);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(bob.clone()));
+
+    assert!(t.client.get_invoice_contributions(&invoice_id).is_empty());
+    let shares = t.client.get_invoice_payer_shares(&invoice_id);
+    assert_eq!(shares.get(alice.clone()), Some(600));
+    assert_eq!(shares.get(bob.clone()), Some(400));
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.token_client().balance(&alice), 400);
+    assert_eq!(t.token_client().balance(&bob), 600);
+}
+
+#[test]
+fn test_single_payer_invoice_is_locked_to_first_contributor() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let alice = funded_payer(&t);
+    let bob = funded_payer(&t);
+
+    t.client.contribute_to_invoice(&alice, &invoice_id, &300);
+    assert_contract_error(
+        t.client.try_contribute_to_invoice(&bob, &invoice_id, &300),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_invoice_multi_payer(&t.merchant(), &invoice_id, &true),
+        InvoiceError::InvoiceHasContributions,
+    );
+
+    let invoice = t.client.contribute_to_invoice(&alice, &invoice_id, &700);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+}
+
+#[test]
+fn test_contributions_are_withdrawable_after_expiry() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let expires_at = t.env.ledger().timestamp() + 3_600;
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::After(3_600));
+    let invoice_id = create_invoice(&t);
+    t.client
+        .set_invoice_multi_payer(&t.merchant(), &invoice_id, &true);
+
+    let alice = funded_payer(&t);
+    t.client.contribute_to_invoice(&alice, &invoice_id, &600);
+    assert_contract_error(
+        t.client.try_withdraw_contribution(&alice, &invoice_id),
+        ContractError::InvoiceNotPending,
+    );
+
+    t.env.ledger().set_timestamp(expires_at);
+    assert_eq!(t.client.withdraw_contribution(&alice, &invoice_id), 600);
+    assert_eq!(t.token_client().balance(&alice), 1_000);
+    assert_contract_error(
+        t.client.try_withdraw_contribution(&alice, &invoice_id),
+        InvoiceError::NoContribution,
+    );
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_delegate_creates_invoices_for_merchant() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let backend = Address::generate(&t.env);
+    let description = String::from_str(&t.env, "Order #42");
+
+    assert_contract_error(
+        t.client.try_create_delegated_invoice(
+            &backend,
+            &t.merchant(),
+            &description,
+            &500,
+            &t.token(),
+        ),
+        ContractError::NotAuthorized,
+    );
+
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &backend, &true);
+    assert!(t.client.is_invoice_delegate(&t.merchant_id(), &backend));
+
+    let invoice_id =
+        t.client
+            .create_delegated_invoice(&backend, &t.merchant(), &description, &500, &t.token());
+    let invoice = t.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.merchant_id, t.merchant_id());
+    assert_eq!(invoice.amount, 500);
+
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &backend, &false);
+    assert!(!t.client.is_invoice_delegate(&t.merchant_id(), &backend));
+    assert_contract_error(
+        t.client.try_create_delegated_invoice(
+            &backend,
+            &t.merchant(),
+            &description,
+            &500,
+            &t.token(),
+        ),
+        ContractError::NotAuthorized,
+    );
+}
+
+#[test]
+fn test_delegation_is_scoped_to_one_merchant() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let other = Address::generate(&t.env);
+    let other_id = t.client.register_merchant(&other);
+    t.client.approve_merchant(&t.admin, &other_id);
+
+    let backend = Address::generate(&t.env);
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &backend, &true);
+
+    assert!(!t.client.is_invoice_delegate(&other_id, &backend));
+    assert_contract_error(
+        t.client.try_create_delegated_invoice(
+            &backend,
+            &other,
+            &String::from_str(&t.env, "Not yours"),
+            &500,
+            &t.token(),
+        ),
+        ContractError::NotAuthorized,
+    );
+}
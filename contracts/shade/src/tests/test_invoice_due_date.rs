@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{Address, String, Symbol, TryIntoVal};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Net 30"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_overdue_invoice_can_still_be_paid() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let due_at = t.env.ledger().timestamp() + 30 * 86_400;
+    t.client
+        .set_invoice_due_date(&t.merchant(), &invoice_id, &Some(due_at));
+    assert_eq!(t.client.get_invoice_due_date(&invoice_id), Some(due_at));
+
+    assert_contract_error(
+        t.client.try_mark_overdue(&invoice_id),
+        InvoiceError::InvoiceNotOverdue,
+    );
+
+    t.env.ledger().set_timestamp(due_at);
+    t.client.mark_overdue(&invoice_id);
+
+    let events = t.env.events().all();
+    let (_, topics, _) = events.get(events.len() - 1).unwrap();
+    let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(name, Symbol::new(&t.env, "invoice_overdue_event"));
+    assert_eq!(
+        t.client.get_invoice_status(&invoice_id),
+        InvoiceStatus::Overdue
+    );
+    assert_eq!(t.client.get_protocol_stats().overdue_invoices, 1);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice = t.client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(t.client.get_protocol_stats().overdue_invoices, 0);
+}
+
+#[test]
+fn test_due_date_rules() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let now = t.env.ledger().timestamp();
+
+    assert_contract_error(
+        t.client
+            .try_set_invoice_due_date(&t.merchant(), &invoice_id, &Some(now)),
+        InvoiceError::InvalidDueDate,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_invoice_due_date(&Address::generate(&t.env), &invoice_id, &Some(now + 60)),
+        ContractError::NotAuthorized,
+    );
+
+    t.client
+        .set_invoice_due_date(&t.merchant(), &invoice_id, &Some(now + 60));
+    t.client
+        .set_invoice_due_date(&t.merchant(), &invoice_id, &None);
+    t.env.ledger().set_timestamp(now + 120);
+    assert_contract_error(
+        t.client.try_mark_overdue(&invoice_id),
+        InvoiceError::InvoiceNotOverdue,
+    );
+}
+
+#[test]
+fn test_paid_invoice_is_never_marked_overdue() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let due_at = t.env.ledger().timestamp() + 60;
+    t.client
+        .set_invoice_due_date(&t.merchant(), &invoice_id, &Some(due_at));
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    t.env.ledger().set_timestamp(due_at);
+    assert_contract_error(
+        t.client.try_mark_overdue(&invoice_id),
+        ContractError::InvoiceNotPending,
+    );
+}
@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{InvoiceExpiryPolicy, InvoiceStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, String};
+
+const NOW: u64 = 1_000;
+const DAY: u64 = 86_400;
+
+fn setup_test<'a>() -> ShadeTestEnv<'a> {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+    t
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv, expires_at: Option<u64>) -> u64 {
+    t.client.create_invoice_with_expiry(
+        &t.merchant(),
+        &String::from_str(&t.env, "Invoice"),
+        &100,
+        &t.token(),
+        &expires_at,
+    )
+}
+
+fn pay_with_link(
+    t: &ShadeTestEnv,
+    invoice_id: u64,
+) -> Result<(), Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 100);
+    let secret = Bytes::from_slice(&t.env, b"secret");
+    let claim_hash: BytesN<32> = t.env.crypto().sha256(&secret).into();
+    let expires_at = t.env.ledger().timestamp() + DAY;
+    let link_id = t
+        .client
+        .create_payment_link(&payer, &t.token(), &100, &claim_hash, &expires_at);
+
+    t.commit_claim(&t.merchant(), link_id, &secret);
+    t.client
+        .try_claim_payment_link(&t.merchant(), &link_id, &secret, &Some(invoice_id))
+        .map(|result| result.unwrap())
+}
+
+#[test]
+fn test_expired_invoice_cannot_be_paid() {
+    let t = setup_test();
+    let invoice_id = create_invoice(&t, Some(NOW + DAY));
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), Some(NOW + DAY));
+
+    t.env.ledger().set_timestamp(NOW + DAY);
+    assert_contract_error(pay_with_link(&t, invoice_id), InvoiceError::InvoiceExpired);
+    assert_eq!(
+        t.client.get_invoice(&invoice_id).status,
+        InvoiceStatus::Pending
+    );
+
+    assert_contract_error(
+        t.client.try_create_invoice_with_expiry(
+            &t.merchant(),
+            &String::from_str(&t.env, "Invoice"),
+            &100,
+            &t.token(),
+            &Some(NOW + DAY),
+        ),
+        InvoiceError::InvoiceExpired,
+    );
+}
+
+#[test]
+fn test_default_and_merchant_expiry_policies() {
+    let t = setup_test();
+
+    // Without any policy, invoices never expire.
+    let invoice_id = create_invoice(&t, None);
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), None);
+
+    t.client.set_default_invoice_expiry(&t.admin, &Some(DAY));
+    let invoice_id = create_invoice(&t, None);
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), Some(NOW + DAY));
+
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::After(7 * DAY));
+    let invoice_id = create_invoice(&t, None);
+    assert_eq!(
+        t.client.get_invoice_expiry(&invoice_id),
+        Some(NOW + 7 * DAY)
+    );
+
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::Never);
+    let invoice_id = create_invoice(&t, None);
+    assert_eq!(t.client.get_invoice_expiry(&invoice_id), None);
+
+    t.env.ledger().set_timestamp(NOW + 30 * DAY);
+    pay_with_link(&t, invoice_id).unwrap();
+
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::Default);
+    assert_eq!(
+        t.client.get_merchant_invoice_expiry(&t.merchant_id()),
+        InvoiceExpiryPolicy::Default
+    );
+}
+
+#[test]
+fn test_expiry_policy_authorization() {
+    let t = setup_test();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_set_default_invoice_expiry(&outsider, &Some(DAY)),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_merchant_invoice_expiry(&outsider, &InvoiceExpiryPolicy::Never),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_set_default_invoice_expiry(&t.admin, &Some(0)),
+        ContractError::InvalidAmount,
+    );
+}
+
+#[test]
+fn test_list_expiring_invoices_within_window() {
+    let t = setup_test();
+    let later = create_invoice(&t, Some(NOW + DAY + 3_600));
+    let soon = create_invoice(&t, Some(NOW + 3_600));
+    create_invoice(&t, Some(NOW + 5 * DAY));
+    t.client
+        .set_merchant_invoice_expiry(&t.merchant(), &InvoiceExpiryPolicy::Never);
+    create_invoice(&t, None);
+
+    let expiring = t.client.list_expiring_invoices(&(2 * DAY), &0);
+    assert_eq!(expiring.len(), 2);
+    assert_eq!(expiring.get(0).unwrap().id, soon);
+    assert_eq!(expiring.get(1).unwrap().id, later);
+    assert_eq!(t.client.list_expiring_invoices(&7_200, &0).len(), 1);
+
+    pay_with_link(&t, soon).unwrap();
+    let expiring = t.client.list_expiring_invoices(&(2 * DAY), &0);
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().id, later);
+
+    t.env.ledger().set_timestamp(NOW + DAY + 3_600);
+    assert_eq!(t.client.list_expiring_invoices(&DAY, &0).len(), 0);
+}
+
+#[test]
+fn test_list_expiring_invoices_pages() {
+    let t = setup_test();
+    let first = create_invoice(&t, Some(NOW + 3_600));
+    for offset in 1..22 {
+        create_invoice(&t, Some(NOW + 3_600 + offset));
+    }
+
+    let page = t.client.list_expiring_invoices(&DAY, &0);
+    assert_eq!(page.len(), 20);
+    assert_eq!(page.get(0).unwrap().id, first);
+    assert_eq!(t.client.list_expiring_invoices(&DAY, &1).len(), 2);
+    assert_eq!(t.client.list_expiring_invoices(&DAY, &2).len(), 0);
+}
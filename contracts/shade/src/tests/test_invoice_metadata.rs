@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{map, Address, Map, String, Symbol};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Order"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_merchant_sets_and_clears_metadata() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    assert!(t.client.get_invoice_metadata(&invoice_id).is_empty());
+
+    let metadata = map![
+        &t.env,
+        (
+            Symbol::new(&t.env, "order_id"),
+            String::from_str(&t.env, "ORD-1001")
+        ),
+        (
+            Symbol::new(&t.env, "sku"),
+            String::from_str(&t.env, "WID-42")
+        ),
+    ];
+    t.client
+        .set_invoice_metadata(&t.merchant(), &invoice_id, &metadata);
+    assert_eq!(t.client.get_invoice_metadata(&invoice_id), metadata);
+
+    t.client
+        .set_invoice_metadata(&t.merchant(), &invoice_id, &Map::new(&t.env));
+    assert!(t.client.get_invoice_metadata(&invoice_id).is_empty());
+}
+
+#[test]
+fn test_delegate_may_set_metadata_but_outsider_may_not() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let delegate = Address::generate(&t.env);
+    t.client
+        .delegate_invoice_creation(&t.merchant(), &delegate, &true);
+
+    let metadata = map![
+        &t.env,
+        (
+            Symbol::new(&t.env, "customer"),
+            String::from_str(&t.env, "C-77")
+        ),
+    ];
+    t.client
+        .set_invoice_metadata(&delegate, &invoice_id, &metadata);
+    assert_eq!(t.client.get_invoice_metadata(&invoice_id), metadata);
+
+    assert_contract_error(
+        t.client
+            .try_set_invoice_metadata(&Address::generate(&t.env), &invoice_id, &metadata),
+        ContractError::NotAuthorized,
+    );
+}
+
+#[test]
+fn test_oversized_metadata_is_rejected() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+
+    let mut too_many = Map::new(&t.env);
+    for i in 0..21u8 {
+        let key = [b'k', b'a' + i];
+        too_many.set(
+            Symbol::new(&t.env, core::str::from_utf8(&key).unwrap()),
+            String::from_str(&t.env, "v"),
+        );
+    }
+    assert_contract_error(
+        t.client
+            .try_set_invoice_metadata(&t.merchant(), &invoice_id, &too_many),
+        InvoiceError::InvalidInvoiceMetadata,
+    );
+
+    let too_long = map![
+        &t.env,
+        (
+            Symbol::new(&t.env, "note"),
+            String::from_bytes(&t.env, &[b'x'; 257])
+        ),
+    ];
+    assert_contract_error(
+        t.client
+            .try_set_invoice_metadata(&t.merchant(), &invoice_id, &too_long),
+        InvoiceError::InvalidInvoiceMetadata,
+    );
+}
@@ -0,0 +1,424 @@
+#![cfg(test)]
+
+use crate::components::refund::REFUND_WINDOW;
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{DistributionShare, InvoiceStatus, InvoiceTax, PaymentRoute, Role};
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{vec, Address, String, Symbol, TryIntoVal};
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, MerchantAccountClient<'a>, Address, u64) {
+    setup_test_with_fee(0)
+}
+
+fn setup_test_with_fee<'a>(
+    fee: i128,
+) -> (ShadeTestEnv<'a>, MerchantAccountClient<'a>, Address, u64) {
+    let t = ShadeTestEnv::new().with_token(fee).with_merchant();
+
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    t.mint(&account_id, 5_000);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Refundable order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    (t, account, payer, invoice_id)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_partial_refunds_require_merchant_or_role() {
+    let (t, account, payer, invoice_id) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_refund_invoice_partial(&payer, &invoice_id, &300),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.token_client().balance(&payer), 0);
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &300);
+    let manager = Address::generate(&t.env);
+    t.client.grant_role(&t.admin, &manager, &Role::Manager);
+    t.client.refund_invoice_partial(&manager, &invoice_id, &200);
+
+    assert_eq!(t.token_client().balance(&payer), 500);
+    assert_eq!(t.token_client().balance(&account.address), 5_500);
+    assert_eq!(t.client.get_invoice_refunded_amount(&invoice_id), 500);
+    assert_eq!(
+        t.client.get_invoice_status(&invoice_id),
+        InvoiceStatus::Paid
+    );
+
+    assert_contract_error(
+        t.client
+            .try_refund_invoice_partial(&t.merchant(), &invoice_id, &600),
+        ContractError::InvalidAmount,
+    );
+    assert_eq!(t.client.refund_invoice(&t.admin, &invoice_id), 500);
+    assert_eq!(t.token_client().balance(&payer), 1_000);
+    assert_eq!(
+        t.client.get_invoice_status(&invoice_id),
+        InvoiceStatus::Refunded
+    );
+}
+
+#[test]
+fn test_refund_requires_paid_invoice() {
+    let (t, _, _, _) = setup_test();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Unpaid"),
+        &1_000,
+        &t.token(),
+    );
+
+    assert_contract_error(
+        t.client
+            .try_refund_invoice_partial(&t.merchant(), &invoice_id, &100),
+        InvoiceError::InvoiceNotPaid,
+    );
+}
+
+#[test]
+fn test_split_bill_refund_follows_payer_shares() {
+    let (t, _, _, _) = setup_test();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Team dinner"),
+        &1_000,
+        &t.token(),
+    );
+    t.client
+        .set_invoice_multi_payer(&t.merchant(), &invoice_id, &true);
+    let alice = Address::generate(&t.env);
+    let bob = Address::generate(&t.env);
+    t.mint(&alice, 600);
+    t.mint(&bob, 400);
+    t.client.contribute_to_invoice(&alice, &invoice_id, &600);
+    t.client.contribute_to_invoice(&bob, &invoice_id, &400);
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &500);
+    assert_eq!(t.token_client().balance(&alice), 300);
+    assert_eq!(t.token_client().balance(&bob), 200);
+}
+
+#[test]
+fn test_merchant_redirects_refund_to_new_address() {
+    let (t, _, payer, invoice_id) = setup_test();
+    let replacement = Address::generate(&t.env);
+
+    let manager = Address::generate(&t.env);
+    t.client.grant_role(&t.admin, &manager, &Role::Manager);
+    assert_contract_error(
+        t.client
+            .try_refund_invoice_to(&manager, &invoice_id, &400, &replacement),
+        ContractError::NotAuthorized,
+    );
+
+    t.client
+        .refund_invoice_to(&t.merchant(), &invoice_id, &400, &replacement);
+    assert_eq!(t.token_client().balance(&replacement), 400);
+    assert_eq!(t.token_client().balance(&payer), 0);
+    assert_eq!(t.client.get_invoice_refunded_amount(&invoice_id), 400);
+}
+
+#[test]
+fn test_refund_returns_protocol_fee_when_enabled() {
+    let (t, account, payer, invoice_id) = setup_test_with_fee(100);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    t.client.set_refund_protocol_fees(&t.admin, &true);
+    assert!(t.client.refunds_protocol_fees());
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &500);
+    let events = t.env.events().all();
+    let fee_refunded = events.iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "fee_refunded_event")
+    });
+    assert!(fee_refunded);
+    assert_eq!(t.token_client().balance(&payer), 500);
+    assert_eq!(t.token_client().balance(&account.address), 5_495);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 5);
+
+    t.client.refund_invoice(&t.merchant(), &invoice_id);
+    assert_eq!(t.token_client().balance(&payer), 1_000);
+    assert_eq!(t.token_client().balance(&account.address), 5_000);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 0);
+}
+
+#[test]
+fn test_distributed_fees_are_not_refunded() {
+    let (t, account, payer, invoice_id) = setup_test_with_fee(100);
+    t.client.set_refund_protocol_fees(&t.admin, &true);
+    let shares = vec![
+        &t.env,
+        DistributionShare {
+            recipient: Address::generate(&t.env),
+            weight: 1,
+        },
+    ];
+    t.client.set_distribution_shares(&t.admin, &shares);
+    assert_eq!(t.client.distribute_fees(&t.token()), 10);
+
+    assert_contract_error(
+        t.client.try_refund_invoice(&t.merchant(), &invoice_id),
+        InvoiceError::InsufficientCollectedFees,
+    );
+    assert_eq!(t.token_client().balance(&payer), 0);
+    assert_eq!(t.token_client().balance(&account.address), 5_990);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+}
+
+#[test]
+fn test_protocol_fee_is_kept_by_default() {
+    let (t, account, payer, invoice_id) = setup_test_with_fee(100);
+
+    // The account only received the 990 left after the fee; the merchant
+    // covers the rest from its own address.
+    t.mint(&t.merchant(), 10);
+    t.client.refund_invoice(&t.merchant(), &invoice_id);
+    assert_eq!(t.token_client().balance(&payer), 1_000);
+    assert_eq!(t.token_client().balance(&account.address), 5_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_contract_error(
+        t.client.try_set_refund_protocol_fees(&payer, &true),
+        ContractError::NotAuthorized,
+    );
+}
+
+#[test]
+fn test_final_sale_invoice_rejects_refunds() {
+    let (t, _, payer, _) = setup_test();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Clearance item"),
+        &1_000,
+        &t.token(),
+    );
+    assert!(t.client.is_invoice_refundable(&invoice_id));
+    t.client
+        .set_invoice_refundable(&t.merchant(), &invoice_id, &false);
+    assert!(!t.client.is_invoice_refundable(&invoice_id));
+
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+    assert_contract_error(
+        t.client
+            .try_set_invoice_refundable(&t.merchant(), &invoice_id, &true),
+        ContractError::InvoiceNotPending,
+    );
+    assert_contract_error(
+        t.client.try_refund_invoice(&t.merchant(), &invoice_id),
+        InvoiceError::InvoiceNotRefundable,
+    );
+    assert_contract_error(
+        t.client
+            .try_refund_invoice_partial(&t.admin, &invoice_id, &100),
+        InvoiceError::InvoiceNotRefundable,
+    );
+}
+
+#[test]
+fn test_invoice_proceeds_stay_earmarked_for_the_refund_window() {
+    let (t, account, _, invoice_id) = setup_test();
+    assert_eq!(account.get_invoice_funds(&invoice_id, &t.token()), 1_000);
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &300);
+    assert_eq!(account.get_invoice_funds(&invoice_id, &t.token()), 700);
+
+    assert_contract_error(
+        t.client.try_release_invoice_funds(&invoice_id),
+        InvoiceError::RefundWindowOpen,
+    );
+    let paid_at = t.env.ledger().timestamp();
+    t.env.ledger().set_timestamp(paid_at + REFUND_WINDOW);
+    t.client.release_invoice_funds(&invoice_id);
+    assert_eq!(account.get_invoice_funds(&invoice_id, &t.token()), 0);
+    assert_eq!(account.get_earmarked_balance(&t.token()), 0);
+}
+
+#[test]
+fn test_refund_without_linked_account_comes_from_merchant() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Unlinked order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &400);
+    assert_eq!(t.token_client().balance(&payer), 400);
+    assert_eq!(t.token_client().balance(&t.merchant()), 600);
+}
+
+#[test]
+fn test_account_is_only_charged_what_it_received() {
+    let (t, account, _, _) = setup_test();
+    let tax_recipient = Address::generate(&t.env);
+    let partner = Address::generate(&t.env);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 2_000);
+
+    // 10% of the taxed invoice goes to the tax recipient, not the account.
+    let taxed = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Taxed order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.set_invoice_tax(
+        &t.merchant(),
+        &taxed,
+        &Some(InvoiceTax {
+            tax_bps: 1_000,
+            tax_recipient: tax_recipient.clone(),
+        }),
+    );
+    t.client.pay_invoice(&payer, &taxed);
+    assert_eq!(account.get_invoice_funds(&taxed, &t.token()), 900);
+
+    t.mint(&t.merchant(), 100);
+    t.client.refund_invoice(&t.merchant(), &taxed);
+    assert_eq!(t.token_client().balance(&payer), 2_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+
+    // Routed proceeds never reach the account either.
+    t.client.set_payment_routes(
+        &t.merchant(),
+        &vec![
+            &t.env,
+            PaymentRoute {
+                recipient: partner.clone(),
+                share_bps: 10_000,
+            },
+        ],
+    );
+    let routed = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Routed order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &routed);
+    let account_balance = t.token_client().balance(&account.address);
+
+    t.mint(&t.merchant(), 1_000);
+    t.client.refund_invoice(&t.merchant(), &routed);
+    assert_eq!(t.token_client().balance(&account.address), account_balance);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+    assert_eq!(t.token_client().balance(&partner), 1_000);
+}
+
+// 10% fee: 10_000 paid up front leaves 1_000 of fee reserves, and the
+// merchant has moved its proceeds out so it couldn't cover a refund itself.
+fn setup_subsidy_test<'a>() -> (ShadeTestEnv<'a>, Address, u64) {
+    let t = ShadeTestEnv::new()
+        .with_token(1_000)
+        .with_merchant()
+        .with_paid_invoice(10_000);
+    t.client.set_refund_subsidy(&t.admin, &true);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Subsidized order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    let sink = Address::generate(&t.env);
+    t.token_client().transfer(
+        &t.merchant(),
+        &sink,
+        &t.token_client().balance(&t.merchant()),
+    );
+
+    (t, payer, invoice_id)
+}
+
+#[test]
+fn test_subsidized_refund_is_netted_from_later_payouts() {
+    let (t, payer, invoice_id) = setup_subsidy_test();
+    assert_eq!(t.client.get_collected_fees(&t.token()), 1_100);
+
+    t.client
+        .refund_invoice_partial(&t.merchant(), &invoice_id, &600);
+    assert_eq!(t.token_client().balance(&payer), 600);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 500);
+    assert_eq!(
+        t.client.get_merchant_debt(&t.merchant_id(), &t.token()),
+        600
+    );
+
+    let next = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Next order"),
+        &1_000,
+        &t.token(),
+    );
+    let preview = t.client.preview_payment(&next, &1_000);
+    assert_eq!(preview.merchant_net, 900);
+    assert_eq!(preview.splits.get(0).unwrap().amount, 300);
+
+    let next_payer = Address::generate(&t.env);
+    t.mint(&next_payer, 1_000);
+    t.client.pay_invoice(&next_payer, &next);
+    assert_eq!(t.token_client().balance(&t.merchant()), 300);
+    assert_eq!(t.client.get_merchant_debt(&t.merchant_id(), &t.token()), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 1_200);
+}
+
+#[test]
+fn test_refund_subsidy_only_covers_refunds_inside_the_window() {
+    let (t, _, invoice_id) = setup_subsidy_test();
+
+    assert_contract_error(
+        t.client.try_set_refund_subsidy(&t.merchant(), &false),
+        ContractError::NotAuthorized,
+    );
+    assert!(t.client.subsidizes_refunds());
+
+    // Past the window the merchant has to fund the refund again.
+    t.env
+        .ledger()
+        .set_timestamp(t.env.ledger().timestamp() + REFUND_WINDOW);
+    assert!(t
+        .client
+        .try_refund_invoice_partial(&t.merchant(), &invoice_id, &600)
+        .is_err());
+    assert_eq!(t.client.get_merchant_debt(&t.merchant_id(), &t.token()), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 1_100);
+}
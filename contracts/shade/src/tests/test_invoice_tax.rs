@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceTax;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, String, Symbol, TryIntoVal};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Consulting incl. VAT"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_tax_portion_is_routed_to_tax_recipient() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let tax_recipient = Address::generate(&t.env);
+    let tax = InvoiceTax {
+        tax_bps: 2_000,
+        tax_recipient: tax_recipient.clone(),
+    };
+    t.client
+        .set_invoice_tax(&t.merchant(), &invoice_id, &Some(tax.clone()));
+    assert_eq!(t.client.get_invoice_tax(&invoice_id), Some(tax));
+
+    let preview = t.client.preview_payment(&invoice_id, &1_000);
+    assert_eq!(preview.tax, 200);
+    assert_eq!(preview.fee, 8);
+    assert_eq!(preview.merchant_net, 792);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    let events = t.env.events().all();
+    let collected = events.iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "tax_collected_event")
+    });
+    assert!(collected);
+
+    assert_eq!(t.token_client().balance(&tax_recipient), 200);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 8);
+    assert_eq!(t.token_client().balance(&t.merchant()), 792);
+}
+
+#[test]
+fn test_tax_config_validation() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let tax_recipient = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_set_invoice_tax(
+            &t.merchant(),
+            &invoice_id,
+            &Some(InvoiceTax {
+                tax_bps: 10_001,
+                tax_recipient: tax_recipient.clone(),
+            }),
+        ),
+        InvoiceError::InvalidTaxConfig,
+    );
+    assert_contract_error(
+        t.client.try_set_invoice_tax(
+            &t.merchant(),
+            &invoice_id,
+            &Some(InvoiceTax {
+                tax_bps: 0,
+                tax_recipient: tax_recipient.clone(),
+            }),
+        ),
+        InvoiceError::InvalidTaxConfig,
+    );
+    assert_contract_error(
+        t.client.try_set_invoice_tax(
+            &tax_recipient,
+            &invoice_id,
+            &Some(InvoiceTax {
+                tax_bps: 2_000,
+                tax_recipient: tax_recipient.clone(),
+            }),
+        ),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.client.get_invoice_tax(&invoice_id), None);
+}
@@ -0,0 +1,167 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{InvoiceStatus, LateFeePolicy};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{Address, Map, String, Symbol, TryIntoVal, Val};
+
+const DAY: u64 = 86_400;
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice_due(t: &ShadeTestEnv, due_in: u64) -> (u64, u64) {
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Net 30"),
+        &1_000,
+        &t.token(),
+    );
+    let due_at = t.env.ledger().timestamp() + due_in;
+    t.client
+        .set_invoice_due_date(&t.merchant(), &invoice_id, &Some(due_at));
+    (invoice_id, due_at)
+}
+
+#[test]
+fn test_flat_late_fee_is_collected_with_principal() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let (invoice_id, due_at) = create_invoice_due(&t, 30 * DAY);
+    t.client
+        .set_invoice_late_fee(&t.merchant(), &invoice_id, &Some(LateFeePolicy::Flat(50)));
+
+    assert_eq!(t.client.get_amount_due(&invoice_id), 1_000);
+    t.env.ledger().set_timestamp(due_at + 1);
+    assert_eq!(t.client.get_amount_due(&invoice_id), 1_050);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_050);
+    let invoice = t.client.pay_invoice(&payer, &invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+
+    let events = t.env.events().all();
+    let collected = events.iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "late_fee_collected_event")
+    });
+    assert!(collected);
+
+    assert_eq!(t.token_client().balance(&payer), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_040);
+}
+
+#[test]
+fn test_paid_on_behalf_event_reports_late_fee_inclusive_amount() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let (invoice_id, due_at) = create_invoice_due(&t, DAY);
+    t.client
+        .set_invoice_late_fee(&t.merchant(), &invoice_id, &Some(LateFeePolicy::Flat(50)));
+    t.env.ledger().set_timestamp(due_at + 1);
+
+    let funder = Address::generate(&t.env);
+    t.mint(&funder, 1_050);
+    t.client
+        .pay_invoice_on_behalf(&funder, &Address::generate(&t.env), &invoice_id);
+
+    let events = t.env.events().all();
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+            name == Symbol::new(&t.env, "invoice_paid_on_behalf_event")
+        })
+        .unwrap();
+    let data: Map<Symbol, Val> = data.try_into_val(&t.env).unwrap();
+    let amount: i128 = data
+        .get(Symbol::new(&t.env, "amount"))
+        .unwrap()
+        .try_into_val(&t.env)
+        .unwrap();
+    assert_eq!(amount, 1_050);
+    assert_eq!(t.token_client().balance(&funder), 0);
+}
+
+#[test]
+fn test_bps_late_fee_accrues_per_started_day_and_is_capped() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let (invoice_id, due_at) = create_invoice_due(&t, DAY);
+    t.client.set_invoice_late_fee(
+        &t.merchant(),
+        &invoice_id,
+        &Some(LateFeePolicy::BpsPerDay(100)),
+    );
+
+    t.env.ledger().set_timestamp(due_at + 1);
+    assert_eq!(t.client.get_amount_due(&invoice_id), 1_010);
+    t.env.ledger().set_timestamp(due_at + 2 * DAY + 1);
+    assert_eq!(t.client.get_amount_due(&invoice_id), 1_030);
+
+    let preview = t.client.preview_payment(&invoice_id, &1_030);
+    assert_eq!(preview.late_fee, 30);
+    assert_eq!(preview.amount, 1_030);
+    assert!(preview.blockers.is_empty());
+
+    t.env.ledger().set_timestamp(due_at + 500 * DAY);
+    assert_eq!(t.client.get_amount_due(&invoice_id), 2_000);
+}
+
+#[test]
+fn test_late_fee_policy_validation() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "No due date"),
+        &1_000,
+        &t.token(),
+    );
+    assert_contract_error(
+        t.client.try_set_invoice_late_fee(
+            &t.merchant(),
+            &invoice_id,
+            &Some(LateFeePolicy::Flat(50)),
+        ),
+        InvoiceError::InvalidLateFeePolicy,
+    );
+
+    let (invoice_id, due_at) = create_invoice_due(&t, DAY);
+    assert_contract_error(
+        t.client.try_set_invoice_late_fee(
+            &t.merchant(),
+            &invoice_id,
+            &Some(LateFeePolicy::Flat(0)),
+        ),
+        InvoiceError::InvalidLateFeePolicy,
+    );
+    assert_contract_error(
+        t.client.try_set_invoice_late_fee(
+            &t.merchant(),
+            &invoice_id,
+            &Some(LateFeePolicy::BpsPerDay(10_001)),
+        ),
+        InvoiceError::InvalidLateFeePolicy,
+    );
+    let outsider = Address::generate(&t.env);
+    assert_contract_error(
+        t.client
+            .try_set_invoice_late_fee(&outsider, &invoice_id, &Some(LateFeePolicy::Flat(50))),
+        ContractError::NotAuthorized,
+    );
+
+    t.env.ledger().set_timestamp(due_at);
+    assert_contract_error(
+        t.client.try_set_invoice_late_fee(
+            &t.merchant(),
+            &invoice_id,
+            &Some(LateFeePolicy::Flat(50)),
+        ),
+        InvoiceError::InvalidLateFeePolicy,
+    );
+    assert_eq!(t.client.get_invoice_late_fee(&invoice_id), None);
+}
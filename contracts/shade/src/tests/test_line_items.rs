@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use crate::errors::InvoiceError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::LineItem;
+use soroban_sdk::{vec, String, Vec};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn line_item(t: &ShadeTestEnv, description: &str, quantity: u32, unit_price: i128) -> LineItem {
+    LineItem {
+        description: String::from_str(&t.env, description),
+        quantity,
+        unit_price,
+    }
+}
+
+#[test]
+fn test_itemized_invoice_stores_line_items() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let items = vec![
+        &t.env,
+        line_item(&t, "Widget", 3, 250),
+        line_item(&t, "Shipping", 1, 50),
+    ];
+
+    let invoice_id = t.client.create_itemized_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Order #1001"),
+        &items,
+        &800,
+        &t.token(),
+    );
+
+    assert_eq!(t.client.get_invoice(&invoice_id).amount, 800);
+    assert_eq!(t.client.get_invoice_line_items(&invoice_id), items);
+}
+
+#[test]
+fn test_itemized_invoice_total_must_match_items() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let description = String::from_str(&t.env, "Order #1002");
+
+    assert_contract_error(
+        t.client.try_create_itemized_invoice(
+            &t.merchant(),
+            &description,
+            &vec![&t.env, line_item(&t, "Widget", 3, 250)],
+            &800,
+            &t.token(),
+        ),
+        InvoiceError::InvalidLineItems,
+    );
+    assert_contract_error(
+        t.client.try_create_itemized_invoice(
+            &t.merchant(),
+            &description,
+            &vec![&t.env, line_item(&t, "Widget", 0, 250)],
+            &0,
+            &t.token(),
+        ),
+        InvoiceError::InvalidLineItems,
+    );
+    assert_contract_error(
+        t.client.try_create_itemized_invoice(
+            &t.merchant(),
+            &description,
+            &Vec::new(&t.env),
+            &800,
+            &t.token(),
+        ),
+        InvoiceError::InvalidLineItems,
+    );
+}
+
+#[test]
+fn test_plain_invoice_has_no_line_items() {
+    let t = ShadeTestEnv::new()
+        .with_token(0)
+        .with_merchant()
+        .with_paid_invoice(1_000);
+    let invoice_id = t.paid_invoices.get(0).unwrap();
+
+    assert!(t.client.get_invoice_line_items(&invoice_id).is_empty());
+}
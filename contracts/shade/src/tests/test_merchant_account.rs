@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use crate::errors::{AccountError, ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use shared::account::StatementEntryKind;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, String};
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, MerchantAccountClient<'a>) {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.mint(&account_id, 5_000);
+
+    (t, account)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_withdraw_through_linked_account() {
+    let (t, account) = setup_test();
+    let recipient = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient),
+        AccountError::MerchantAccountNotLinked,
+    );
+
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    assert_eq!(
+        t.client.get_merchant_account(&t.merchant_id()),
+        Some(account.address.clone())
+    );
+
+    t.client
+        .withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient);
+    assert_eq!(t.token_client().balance(&recipient), 1_000);
+    assert_eq!(t.token_client().balance(&account.address), 4_000);
+    assert_eq!(account.get_statement_count(), 1);
+}
+
+#[test]
+fn test_link_rejects_accounts_not_managed_by_shade() {
+    let (t, _) = setup_test();
+
+    let other_id = t.env.register(MerchantAccount, ());
+    let other = MerchantAccountClient::new(&t.env, &other_id);
+    other.initialize(&t.merchant(), &Address::generate(&t.env), &t.merchant_id());
+
+    assert_contract_error(
+        t.client.try_link_merchant_account(&t.merchant(), &other_id),
+        AccountError::InvalidMerchantAccount,
+    );
+    assert_eq!(t.client.get_merchant_account(&t.merchant_id()), None);
+}
+
+#[test]
+fn test_withdraw_enforces_protocol_checks() {
+    let (t, account) = setup_test();
+    let recipient = Address::generate(&t.env);
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+
+    t.client
+        .set_velocity_limit(&t.admin, &t.token(), &1_500, &3_600);
+    t.client
+        .withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient);
+    assert_contract_error(
+        t.client
+            .try_withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient),
+        ComplianceError::LimitExceeded,
+    );
+    t.client.remove_velocity_limit(&t.admin, &t.token());
+
+    account.pause_account();
+    assert_contract_error(
+        t.client
+            .try_withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient),
+        AccountError::MerchantAccountRestricted,
+    );
+    account.unpause_account();
+
+    t.client
+        .set_merchant_status(&t.admin, &t.merchant_id(), &false);
+    assert_contract_error(
+        t.client
+            .try_withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.token_client().balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_payouts_go_to_linked_account_and_stop_while_paused() {
+    let (t, account) = setup_test();
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 2_000);
+
+    let first = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "First"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &first);
+    assert_eq!(t.token_client().balance(&account.address), 6_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+
+    let second = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Second"),
+        &500,
+        &t.token(),
+    );
+    account.pause_account();
+    assert_contract_error(
+        t.client.try_pay_invoice(&payer, &second),
+        AccountError::MerchantAccountPaused,
+    );
+    assert_contract_error(
+        t.client.try_pay_invoice_with_tip(&payer, &second, &100),
+        AccountError::MerchantAccountPaused,
+    );
+
+    account.unpause_account();
+    t.client.pay_invoice_with_tip(&payer, &second, &100);
+    assert_eq!(t.token_client().balance(&account.address), 6_600);
+}
+
+#[test]
+fn test_invoice_income_appears_on_account_statement() {
+    let (t, account) = setup_test();
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Order"),
+        &1_000,
+        &t.token(),
+    );
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    let statement = account.get_statement(&0);
+    assert_eq!(statement.len(), 1);
+    let income = statement.get(0).unwrap();
+    assert_eq!(income.kind, StatementEntryKind::InvoicePayment);
+    assert_eq!(income.token, t.token());
+    assert_eq!(income.amount, 1_000);
+    assert_eq!(income.counterparty, t.client.address);
+}
+
+#[test]
+fn test_admin_restricts_account_and_approves_withdrawals() {
+    let (t, account) = setup_test();
+    t.client
+        .link_merchant_account(&t.merchant(), &account.address);
+    let recipient = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_set_merchant_account_restricted(&t.merchant(), &t.merchant_id(), &true),
+        ContractError::NotAuthorized,
+    );
+    t.client
+        .set_merchant_account_restricted(&t.admin, &t.merchant_id(), &true);
+    assert!(account.get_account_info().restricted);
+    assert_contract_error(
+        t.client
+            .try_withdraw_merchant_funds(&t.merchant(), &t.token(), &1_000, &recipient),
+        AccountError::MerchantAccountRestricted,
+    );
+
+    let request_id = account.request_withdrawal(&t.token(), &1_000, &recipient);
+    t.client
+        .approve_merchant_withdrawal(&t.admin, &t.merchant_id(), &request_id);
+    assert_eq!(t.token_client().balance(&recipient), 1_000);
+    assert_eq!(account.get_withdrawal_request(&request_id), None);
+}
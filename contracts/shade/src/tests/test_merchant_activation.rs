@@ -15,11 +15,11 @@ fn assert_latest_merchant_status_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(event_contract_id, contract_id.clone());
-    assert_eq!(topics.len(), 1);
+    assert_eq!(topics.len(), 2);
 
     let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
     assert_eq!(
@@ -27,12 +27,12 @@ fn assert_latest_merchant_status_event(
         Symbol::new(env, "merchant_status_changed_event")
     );
 
+    let merchant_id_in_event: u64 = topics.get(1).unwrap().try_into_val(env).unwrap();
+
     let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
-    let merchant_id_val = data_map.get(Symbol::new(env, "merchant_id")).unwrap();
     let active_val = data_map.get(Symbol::new(env, "active")).unwrap();
     let timestamp_val = data_map.get(Symbol::new(env, "timestamp")).unwrap();
 
-    let merchant_id_in_event: u64 = merchant_id_val.try_into_val(env).unwrap();
     let active_in_event: bool = active_val.try_into_val(env).unwrap();
     let timestamp_in_event: u64 = timestamp_val.try_into_val(env).unwrap();
 
@@ -51,6 +51,7 @@ fn test_successful_activation() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     let merchant = Address::generate(&env);
     client.register_merchant(&merchant);
@@ -92,6 +93,7 @@ fn test_successful_deactivation() {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     let merchant = Address::generate(&env);
     client.register_merchant(&merchant);
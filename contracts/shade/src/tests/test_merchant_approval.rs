@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::Role;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, String, Symbol, TryIntoVal};
+
+#[test]
+fn test_registered_merchant_needs_approval_to_invoice() {
+    let t = ShadeTestEnv::new();
+    assert!(!t.client.is_permissionless_registration());
+
+    let merchant = Address::generate(&t.env);
+    let merchant_id = t.client.register_merchant(&merchant);
+    assert!(!t.client.is_merchant_approved(&merchant_id));
+
+    let description = String::from_str(&t.env, "Invoice");
+    let token = Address::generate(&t.env);
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ComplianceError::MerchantNotApproved as u32);
+    let result = t
+        .client
+        .try_create_invoice(&merchant, &description, &100, &token);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    let manager = Address::generate(&t.env);
+    t.client.grant_role(&t.admin, &manager, &Role::Manager);
+    t.client.approve_merchant(&manager, &merchant_id);
+
+    let events = t.env.events().all();
+    let (event_contract_id, topics, _) = events.get(events.len() - 1).unwrap();
+    assert_eq!(event_contract_id, t.client.address);
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(event_name, Symbol::new(&t.env, "merchant_approved_event"));
+    let merchant_id_in_event: u64 = topics.get(1).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(merchant_id_in_event, merchant_id);
+
+    assert!(t.client.is_merchant_approved(&merchant_id));
+    t.client
+        .create_invoice(&merchant, &description, &100, &token);
+}
+
+#[test]
+fn test_only_admin_or_manager_approves() {
+    let t = ShadeTestEnv::new();
+
+    let merchant = Address::generate(&t.env);
+    let merchant_id = t.client.register_merchant(&merchant);
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+    let result = t.client.try_approve_merchant(&merchant, &merchant_id);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert!(!t.client.is_merchant_approved(&merchant_id));
+}
+
+#[test]
+fn test_permissionless_registration_skips_approval() {
+    let t = ShadeTestEnv::new();
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+    let outsider = Address::generate(&t.env);
+    let result = t
+        .client
+        .try_set_permissionless_registration(&outsider, &true);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    t.client.set_permissionless_registration(&t.admin, &true);
+    assert!(t.client.is_permissionless_registration());
+
+    let merchant = Address::generate(&t.env);
+    let merchant_id = t.client.register_merchant(&merchant);
+    assert!(t.client.is_merchant_approved(&merchant_id));
+
+    // Turning approval back on only affects merchants registered afterwards.
+    t.client.set_permissionless_registration(&t.admin, &false);
+    assert!(t.client.is_merchant_approved(&merchant_id));
+    let later_id = t.client.register_merchant(&Address::generate(&t.env));
+    assert!(!t.client.is_merchant_approved(&later_id));
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::components::bond::BOND_COOLING_OFF;
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::MerchantBond;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::Address;
+
+const BOND: i128 = 1_000;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(0);
+    t.client.set_registration_bond(
+        &t.admin,
+        &Some(MerchantBond {
+            token: t.token(),
+            amount: BOND,
+        }),
+    );
+
+    // Registers inside each test, once the bond is in place.
+    let merchant = Address::generate(&t.env);
+    t.mint(&merchant, 5_000);
+
+    (t, merchant)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_registration_stakes_bond_and_offboarding_returns_it() {
+    let (t, merchant) = setup_test();
+
+    let merchant_id = t.client.register_merchant(&merchant);
+    assert_eq!(t.token_client().balance(&merchant), 4_000);
+    assert_eq!(t.token_client().balance(&t.client.address), BOND);
+    assert_eq!(
+        t.client.get_merchant_bond(&merchant_id).unwrap().amount,
+        BOND
+    );
+
+    assert_contract_error(
+        t.client.try_withdraw_bond(&merchant),
+        ComplianceError::BondLocked,
+    );
+
+    t.client.offboard_merchant(&merchant);
+    assert!(!t.client.is_merchant_active(&merchant_id));
+    let release_at = t.env.ledger().timestamp() + BOND_COOLING_OFF;
+    assert_eq!(
+        t.client.get_merchant_bond_release_at(&merchant_id),
+        Some(release_at)
+    );
+    assert_contract_error(
+        t.client.try_withdraw_bond(&merchant),
+        ComplianceError::BondLocked,
+    );
+
+    t.env.ledger().set_timestamp(release_at);
+    t.client.withdraw_bond(&merchant);
+    assert!(t.client.get_merchant_bond(&merchant_id).is_none());
+    assert_eq!(t.client.get_merchant_bond_release_at(&merchant_id), None);
+    assert_eq!(t.token_client().balance(&merchant), 5_000);
+    assert_eq!(t.token_client().balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_bond_can_be_slashed_after_offboarding() {
+    let (t, merchant) = setup_test();
+    let merchant_id = t.client.register_merchant(&merchant);
+    let victim = Address::generate(&t.env);
+
+    t.client.offboard_merchant(&merchant);
+    t.client
+        .slash_merchant_bond(&t.admin, &merchant_id, &400, &victim);
+    assert_eq!(t.token_client().balance(&victim), 400);
+
+    let release_at = t.client.get_merchant_bond_release_at(&merchant_id).unwrap();
+    t.env.ledger().set_timestamp(release_at);
+    t.client.withdraw_bond(&merchant);
+    assert_eq!(t.token_client().balance(&merchant), 4_600);
+    assert_eq!(t.token_client().balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_slashed_bond_pays_recipient() {
+    let (t, merchant) = setup_test();
+    let merchant_id = t.client.register_merchant(&merchant);
+    let victim = Address::generate(&t.env);
+
+    t.client
+        .slash_merchant_bond(&t.admin, &merchant_id, &400, &victim);
+    assert_eq!(t.token_client().balance(&victim), 400);
+    assert_eq!(
+        t.client.get_merchant_bond(&merchant_id).unwrap().amount,
+        600
+    );
+
+    assert_contract_error(
+        t.client
+            .try_slash_merchant_bond(&t.admin, &merchant_id, &601, &victim),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client
+            .try_slash_merchant_bond(&merchant, &merchant_id, &100, &victim),
+        ContractError::NotAuthorized,
+    );
+
+    t.client
+        .slash_merchant_bond(&t.admin, &merchant_id, &600, &victim);
+    assert!(t.client.get_merchant_bond(&merchant_id).is_none());
+    assert_contract_error(
+        t.client
+            .try_slash_merchant_bond(&t.admin, &merchant_id, &1, &victim),
+        ComplianceError::BondNotFound,
+    );
+}
+
+#[test]
+fn test_deactivated_merchant_cannot_reclaim_bond() {
+    let (t, merchant) = setup_test();
+    let merchant_id = t.client.register_merchant(&merchant);
+
+    t.client.set_merchant_status(&t.admin, &merchant_id, &false);
+    assert_contract_error(
+        t.client.try_offboard_merchant(&merchant),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.token_client().balance(&t.client.address), BOND);
+}
+
+#[test]
+fn test_registration_bond_config_changes_apply_to_new_merchants_only() {
+    let (t, merchant) = setup_test();
+    let merchant_id = t.client.register_merchant(&merchant);
+
+    t.client.set_registration_bond(&t.admin, &None);
+    assert!(t.client.get_registration_bond().is_none());
+    let unbonded_id = t.client.register_merchant(&Address::generate(&t.env));
+    assert!(t.client.get_merchant_bond(&unbonded_id).is_none());
+    assert_eq!(
+        t.client.get_merchant_bond(&merchant_id).unwrap().amount,
+        BOND
+    );
+
+    assert_contract_error(
+        t.client.try_set_registration_bond(
+            &t.admin,
+            &Some(MerchantBond {
+                token: t.token().clone(),
+                amount: 0,
+            }),
+        ),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_set_registration_bond(&merchant, &None),
+        ContractError::NotAuthorized,
+    );
+}
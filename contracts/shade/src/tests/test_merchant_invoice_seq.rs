@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv, merchant: &Address) -> u64 {
+    t.client.create_invoice(
+        merchant,
+        &String::from_str(&t.env, "Numbered invoice"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_merchant_sequence_ignores_other_merchants() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let other = Address::generate(&t.env);
+    let other_id = t.client.register_merchant(&other);
+    t.client.approve_merchant(&t.admin, &other_id);
+
+    let first = create_invoice(&t, &t.merchant());
+    let other_first = create_invoice(&t, &other);
+    let second = create_invoice(&t, &t.merchant());
+
+    assert_eq!(t.client.get_invoice_merchant_seq(&first), 1);
+    assert_eq!(t.client.get_invoice_merchant_seq(&second), 2);
+    assert_eq!(t.client.get_invoice_merchant_seq(&other_first), 1);
+
+    assert_eq!(
+        t.client
+            .get_invoice_by_merchant_seq(&t.merchant_id(), &2)
+            .id,
+        second
+    );
+    assert_eq!(
+        t.client.get_invoice_by_merchant_seq(&other_id, &1).id,
+        other_first
+    );
+}
+
+#[test]
+fn test_unknown_merchant_sequence_is_not_found() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    create_invoice(&t, &t.merchant());
+
+    assert_contract_error(
+        t.client
+            .try_get_invoice_by_merchant_seq(&t.merchant_id(), &0),
+        ContractError::InvoiceNotFound,
+    );
+    assert_contract_error(
+        t.client
+            .try_get_invoice_by_merchant_seq(&t.merchant_id(), &2),
+        ContractError::InvoiceNotFound,
+    );
+}
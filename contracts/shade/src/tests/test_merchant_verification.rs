@@ -15,6 +15,7 @@ fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
 
     let admin = Address::generate(&env);
     client.initialize(&admin);
+    client.set_legacy_event_format(&admin, &false);
 
     (env, client, contract_id, admin)
 }
@@ -27,21 +28,21 @@ fn assert_latest_merchant_verified_event(
     expected_timestamp: u64,
 ) {
     let events = env.events().all();
-    assert!(events.len() > 0);
+    assert!(!events.is_empty());
 
     let (event_contract_id, topics, data) = events.get(events.len() - 1).unwrap();
     assert_eq!(&event_contract_id, contract_id);
-    assert_eq!(topics.len(), 1);
+    assert_eq!(topics.len(), 2);
 
     let event_name: Symbol = topics.get(0).unwrap().try_into_val(env).unwrap();
     assert_eq!(event_name, Symbol::new(env, "merchant_verified_event"));
 
+    let merchant_id_in_event: u64 = topics.get(1).unwrap().try_into_val(env).unwrap();
+
     let data_map: Map<Symbol, Val> = data.try_into_val(env).unwrap();
-    let merchant_id_val = data_map.get(Symbol::new(env, "merchant_id")).unwrap();
     let status_val = data_map.get(Symbol::new(env, "status")).unwrap();
     let timestamp_val = data_map.get(Symbol::new(env, "timestamp")).unwrap();
 
-    let merchant_id_in_event: u64 = merchant_id_val.try_into_val(env).unwrap();
     let status_in_event: bool = status_val.try_into_val(env).unwrap();
     let timestamp_in_event: u64 = timestamp_val.try_into_val(env).unwrap();
 
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+use crate::components::core::CONTRACT_VERSION;
+use crate::components::migration::{ContractInfoV1, SCHEMA_VERSION};
+use crate::shade::{Shade, ShadeClient};
+use crate::types::DataKey;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    (env, client, admin)
+}
+
+// Rewrites storage to look like a deployment made before ContractInfo
+// carried a version, at the given schema version (0 = unversioned).
+fn make_legacy_deployment(env: &Env, client: &ShadeClient, admin: &Address, version: u32) {
+    env.as_contract(&client.address, || {
+        if version == 0 {
+            env.storage().persistent().remove(&DataKey::SchemaVersion);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::SchemaVersion, &version);
+        }
+        env.storage().persistent().set(
+            &DataKey::ContractInfo,
+            &ContractInfoV1 {
+                admin: admin.clone(),
+                timestamp: 42,
+            },
+        );
+    });
+}
+
+#[test]
+fn test_initialize_sets_current_schema_version() {
+    let (_env, client, _admin) = setup_test();
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_legacy_deployment() {
+    let (env, client, admin) = setup_test();
+    make_legacy_deployment(&env, &client, &admin, 0);
+    assert_eq!(client.get_schema_version(), 0);
+
+    client.migrate(&admin, &0, &Vec::new(&env));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_migrate_wrong_from_version() {
+    let (env, client, admin) = setup_test();
+    make_legacy_deployment(&env, &client, &admin, 0);
+
+    client.migrate(&admin, &SCHEMA_VERSION, &Vec::new(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_migrate_already_current() {
+    let (env, client, admin) = setup_test();
+    client.migrate(&admin, &SCHEMA_VERSION, &Vec::new(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_migrate_non_admin() {
+    let (env, client, admin) = setup_test();
+    make_legacy_deployment(&env, &client, &admin, 0);
+
+    client.migrate(&Address::generate(&env), &0, &Vec::new(&env));
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #14)")]
+#[test]
+fn test_migrate_runs_once_per_version() {
+    let (env, client, admin) = setup_test();
+    make_legacy_deployment(&env, &client, &admin, 0);
+
+    client.migrate(&admin, &0, &Vec::new(&env));
+    client.migrate(&admin, &0, &Vec::new(&env));
+}
+
+#[test]
+fn test_migrate_v1_adds_version_to_contract_info() {
+    let (env, client, admin) = setup_test();
+    make_legacy_deployment(&env, &client, &admin, 1);
+
+    client.migrate(&admin, &1, &Vec::new(&env));
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.admin, admin);
+    assert_eq!(info.timestamp, 42);
+    assert_eq!(info.version, CONTRACT_VERSION);
+}
@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::MilestoneStatus;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_milestone_invoice(t: &ShadeTestEnv) -> u64 {
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Website build"),
+        &1_000,
+        &t.token(),
+    );
+    t.client
+        .set_invoice_milestones(&t.merchant(), &invoice_id, &vec![&t.env, 300, 300, 400]);
+    invoice_id
+}
+
+#[test]
+fn test_milestones_release_as_payer_approves() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let invoice_id = create_milestone_invoice(&t);
+    let client = Address::generate(&t.env);
+    t.mint(&client, 1_000);
+    t.client.pay_invoice(&client, &invoice_id);
+
+    let milestones = t.client.get_invoice_milestones(&invoice_id);
+    assert_eq!(milestones.get(0).unwrap().release_amount, 297);
+    assert_eq!(milestones.get(2).unwrap().release_amount, 396);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+
+    assert_contract_error(
+        t.client.try_approve_milestone(&client, &invoice_id, &0),
+        InvoiceError::InvalidMilestoneState,
+    );
+    t.client
+        .request_milestone_release(&t.merchant(), &invoice_id, &0);
+    t.client.approve_milestone(&client, &invoice_id, &0);
+    assert_eq!(t.token_client().balance(&t.merchant()), 297);
+
+    t.client
+        .request_milestone_release(&t.merchant(), &invoice_id, &1);
+    t.client.dispute_milestone(&client, &invoice_id, &1);
+    assert_eq!(
+        t.client
+            .get_invoice_milestones(&invoice_id)
+            .get(1)
+            .unwrap()
+            .status,
+        MilestoneStatus::Disputed
+    );
+    t.client
+        .request_milestone_release(&t.merchant(), &invoice_id, &1);
+    t.client.approve_milestone(&client, &invoice_id, &1);
+
+    t.client
+        .request_milestone_release(&t.merchant(), &invoice_id, &2);
+    t.client.approve_milestone(&client, &invoice_id, &2);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.client.get_escrow_hold(&invoice_id), None);
+}
+
+#[test]
+fn test_milestone_validation_and_roles() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Mismatched"),
+        &1_000,
+        &t.token(),
+    );
+    assert_contract_error(
+        t.client
+            .try_set_invoice_milestones(&t.merchant(), &invoice_id, &vec![&t.env, 500, 400]),
+        InvoiceError::InvalidMilestones,
+    );
+
+    let invoice_id = create_milestone_invoice(&t);
+    let client = Address::generate(&t.env);
+    t.mint(&client, 1_000);
+    t.client.pay_invoice(&client, &invoice_id);
+
+    assert_contract_error(
+        t.client
+            .try_request_milestone_release(&client, &invoice_id, &0),
+        ContractError::NotAuthorized,
+    );
+    t.client
+        .request_milestone_release(&t.merchant(), &invoice_id, &0);
+    assert_contract_error(
+        t.client
+            .try_approve_milestone(&t.merchant(), &invoice_id, &0),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_release_escrow(&invoice_id),
+        InvoiceError::EscrowLocked,
+    );
+}
@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    // Stands in for the network's native XLM Stellar Asset Contract.
+    let issuer = Address::generate(&env);
+    let native_token = env.register_stellar_asset_contract_v2(issuer).address();
+
+    (env, client, admin, native_token)
+}
+
+#[test]
+fn test_native_token_unset_by_default() {
+    let (_env, client, _admin, _native_token) = setup_test();
+    assert_eq!(client.get_native_token(), None);
+}
+
+#[test]
+fn test_set_native_token_marks_it_accepted() {
+    let (_env, client, admin, native_token) = setup_test();
+
+    client.set_native_token(&admin, &native_token);
+
+    assert_eq!(client.get_native_token(), Some(native_token.clone()));
+    assert!(client.is_accepted_token(&native_token));
+}
+
+#[test]
+fn test_fee_can_be_set_for_native_token() {
+    let (_env, client, admin, native_token) = setup_test();
+
+    client.set_native_token(&admin, &native_token);
+    client.set_fee(&admin, &native_token, &100);
+
+    assert_eq!(client.get_fee(&native_token), 100);
+}
+
+#[test]
+fn test_non_admin_cannot_set_native_token() {
+    let (env, client, _admin, native_token) = setup_test();
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+    let result = client.try_set_native_token(&Address::generate(&env), &native_token);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert_eq!(client.get_native_token(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_set_native_token_rejects_non_token_address() {
+    let (env, client, admin, _native_token) = setup_test();
+    client.set_native_token(&admin, &Address::generate(&env));
+}
@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{OracleAsset, PriceData};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+const MAX_PRICE_AGE: u64 = 300;
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, asset: OracleAsset, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&asset, &PriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, asset: OracleAsset) -> Option<PriceData> {
+        env.storage().instance().get(&asset)
+    }
+}
+
+fn setup_test() -> (
+    Env,
+    ShadeClient<'static>,
+    Address,
+    MockOracleClient<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(&env, &oracle_id);
+    client.add_trusted_contract(&admin, &oracle_id);
+    client.set_oracle(&admin, &oracle_id, &MAX_PRICE_AGE);
+
+    (env, client, admin, oracle)
+}
+
+fn assert_price_error(client: &ShadeClient, token: &Address, error: ContractError) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    let result = client.try_get_token_price(token);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_set_oracle_records_config() {
+    let (_env, client, _admin, oracle) = setup_test();
+
+    let config = client.get_oracle().unwrap();
+    assert_eq!(config.address, oracle.address);
+    assert_eq!(config.max_price_age, MAX_PRICE_AGE);
+}
+
+#[test]
+fn test_get_token_price_returns_fresh_price() {
+    let (env, client, _admin, oracle) = setup_test();
+
+    let token = Address::generate(&env);
+    oracle.set_price(
+        &OracleAsset::Stellar(token.clone()),
+        &1_250_000,
+        &(10_000 - MAX_PRICE_AGE),
+    );
+
+    assert_eq!(client.get_token_price(&token), 1_250_000);
+}
+
+#[test]
+fn test_get_token_price_rejects_stale_price() {
+    let (env, client, _admin, oracle) = setup_test();
+
+    let token = Address::generate(&env);
+    oracle.set_price(
+        &OracleAsset::Stellar(token.clone()),
+        &1_250_000,
+        &(10_000 - MAX_PRICE_AGE - 1),
+    );
+
+    assert_price_error(&client, &token, ContractError::OraclePriceStale);
+}
+
+#[test]
+fn test_get_token_price_without_quote() {
+    let (env, client, _admin, _oracle) = setup_test();
+    assert_price_error(
+        &client,
+        &Address::generate(&env),
+        ContractError::OraclePriceUnavailable,
+    );
+}
+
+#[test]
+fn test_get_token_price_with_unreachable_oracle() {
+    let (env, client, admin, _oracle) = setup_test();
+
+    let unreachable = Address::generate(&env);
+    client.add_trusted_contract(&admin, &unreachable);
+    client.set_oracle(&admin, &unreachable, &MAX_PRICE_AGE);
+    assert_price_error(
+        &client,
+        &Address::generate(&env),
+        ContractError::OraclePriceUnavailable,
+    );
+}
+
+#[test]
+fn test_get_token_price_without_oracle() {
+    let env = Env::default();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    client.mock_all_auths().initialize(&Address::generate(&env));
+
+    assert_eq!(client.get_oracle(), None);
+    assert_price_error(
+        &client,
+        &Address::generate(&env),
+        ContractError::OracleNotConfigured,
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_set_oracle() {
+    let (env, client, _admin, oracle) = setup_test();
+
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+    let result = client.try_set_oracle(&Address::generate(&env), &oracle.address, &0);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_set_oracle_requires_trusted_contract() {
+    let (env, client, admin, _oracle) = setup_test();
+
+    let untrusted = env.register(MockOracle, ());
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::ContractNotTrusted as u32);
+    let result = client.try_set_oracle(&admin, &untrusted, &MAX_PRICE_AGE);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_removed_oracle_is_no_longer_consulted() {
+    let (env, client, admin, oracle) = setup_test();
+
+    let token = Address::generate(&env);
+    oracle.set_price(&OracleAsset::Stellar(token.clone()), &1_250_000, &10_000);
+    assert_eq!(client.get_token_price(&token), 1_250_000);
+
+    client.remove_trusted_contract(&admin, &oracle.address);
+    assert!(!client.is_trusted_contract(&oracle.address));
+    assert_price_error(&client, &token, ContractError::ContractNotTrusted);
+}
+
+#[test]
+fn test_non_admin_cannot_manage_trusted_contracts() {
+    let (env, client, admin, oracle) = setup_test();
+
+    let non_admin = Address::generate(&env);
+    let expected_error =
+        soroban_sdk::Error::from_contract_error(ContractError::NotAuthorized as u32);
+
+    let result = client.try_add_trusted_contract(&non_admin, &Address::generate(&env));
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+
+    let result = client.try_remove_trusted_contract(&non_admin, &oracle.address);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+    assert!(client.is_trusted_contract(&oracle.address));
+
+    client.remove_trusted_contract(&admin, &oracle.address);
+    assert!(!client.is_trusted_contract(&oracle.address));
+}
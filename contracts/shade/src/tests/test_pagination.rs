@@ -0,0 +1,391 @@
+#![cfg(test)]
+
+use crate::components::invoice as invoice_component;
+use crate::shade::{Shade, ShadeClient};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{DataKey, InvoiceFilter, InvoiceStatus, MerchantFilter};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, String, Vec};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_permissionless_registration(&admin, &true);
+    (env, client, admin)
+}
+
+fn empty_invoice_filter() -> InvoiceFilter {
+    InvoiceFilter {
+        status: None,
+        merchant: None,
+        min_amount: None,
+        max_amount: None,
+    }
+}
+
+fn create_invoices(env: &Env, client: &ShadeClient, merchant: &Address, count: u32) {
+    let token = Address::generate(env);
+    for i in 0..count {
+        client.create_invoice(
+            merchant,
+            &String::from_str(env, "Invoice"),
+            &(100 + i as i128),
+            &token,
+        );
+    }
+}
+
+#[test]
+fn test_get_invoices_pages_with_cursor() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 5);
+
+    let (first_page, cursor) = client.get_invoices(&empty_invoice_filter(), &0, &2);
+    assert_eq!(page_ids(&env, &first_page), vec![&env, 1, 2]);
+    assert_eq!(cursor, Some(2));
+
+    let (second_page, cursor) = client.get_invoices(&empty_invoice_filter(), &2, &2);
+    assert_eq!(page_ids(&env, &second_page), vec![&env, 3, 4]);
+    assert_eq!(cursor, Some(4));
+
+    let (last_page, cursor) = client.get_invoices(&empty_invoice_filter(), &4, &2);
+    assert_eq!(page_ids(&env, &last_page), vec![&env, 5]);
+    assert_eq!(cursor, None);
+
+    let (page, cursor) = client.get_invoices(&empty_invoice_filter(), &5, &2);
+    assert!(page.is_empty());
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_get_invoices_limit_zero_reads_a_full_page() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 3);
+
+    let (page, cursor) = client.get_invoices(&empty_invoice_filter(), &0, &0);
+    assert_eq!(page_ids(&env, &page), vec![&env, 1, 2, 3]);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_get_invoices_unfiltered_merges_status_buckets() {
+    let (env, client, _admin) = setup_test();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 103);
+    env.as_contract(&client.address, || {
+        invoice_component::set_invoice_status(&env, 2, InvoiceStatus::Paid);
+        invoice_component::set_invoice_status(&env, 101, InvoiceStatus::Cancelled);
+    });
+
+    // Invoices filtered out by amount still use up the page's budget.
+    let mut filter = empty_invoice_filter();
+    filter.max_amount = Some(101);
+    let (page, cursor) = client.get_invoices(&filter, &0, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 1, 2]);
+    assert_eq!(cursor, Some(2));
+    let (page, cursor) = client.get_invoices(&filter, &2, &5);
+    assert!(page.is_empty());
+    assert_eq!(cursor, Some(7));
+
+    let (page, cursor) = client.get_invoices(&empty_invoice_filter(), &99, &3);
+    assert_eq!(page_ids(&env, &page), vec![&env, 100, 101, 102]);
+    assert_eq!(page.get(1).unwrap().status, InvoiceStatus::Cancelled);
+    assert_eq!(cursor, Some(102));
+}
+
+#[test]
+fn test_get_invoices_by_merchant_uses_merchant_index() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant_a = Address::generate(&env);
+    let merchant_b = Address::generate(&env);
+    client.register_merchant(&merchant_a);
+    client.register_merchant(&merchant_b);
+
+    create_invoices(&env, &client, &merchant_a, 2);
+    create_invoices(&env, &client, &merchant_b, 3);
+    create_invoices(&env, &client, &merchant_a, 2);
+
+    let mut filter = empty_invoice_filter();
+    filter.merchant = Some(merchant_a.clone());
+
+    let (page, _) = client.get_invoices(&filter, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 2);
+    assert_eq!(page.get(2).unwrap().id, 6);
+
+    let (next_page, _) = client.get_invoices(&filter, &6, &3);
+    assert_eq!(next_page.len(), 1);
+    assert_eq!(next_page.get(0).unwrap().id, 7);
+
+    filter.min_amount = Some(101);
+    let (filtered, _) = client.get_invoices(&filter, &0, &10);
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered.get(0).unwrap().id, 2);
+    assert_eq!(filtered.get(1).unwrap().id, 7);
+}
+
+#[test]
+fn test_get_invoices_unknown_merchant_returns_empty() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 2);
+
+    let mut filter = empty_invoice_filter();
+    filter.merchant = Some(Address::generate(&env));
+    assert_eq!(client.get_invoices(&filter, &0, &10).0.len(), 0);
+}
+
+#[test]
+fn test_get_merchants_pages_with_cursor() {
+    let (env, client, admin) = setup_test();
+
+    for _ in 0..4 {
+        client.register_merchant(&Address::generate(&env));
+    }
+    client.set_merchant_status(&admin, &2, &false);
+
+    let filter = MerchantFilter {
+        is_active: None,
+        is_verified: None,
+    };
+    let (page, cursor) = client.get_merchants(&filter, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(2).unwrap().id, 3);
+    assert_eq!(cursor, Some(3));
+
+    let (page, cursor) = client.get_merchants(&filter, &3, &3);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 4);
+    assert_eq!(cursor, None);
+
+    let active_only = MerchantFilter {
+        is_active: Some(true),
+        is_verified: None,
+    };
+    let (page, cursor) = client.get_merchants(&active_only, &0, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(1).unwrap().id, 3);
+    assert_eq!(cursor, None);
+
+    // The inactive merchant still counts against the page's budget.
+    let (page, cursor) = client.get_merchants(&active_only, &0, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(cursor, Some(2));
+
+    let (page, cursor) = client.get_merchants(&filter, &u64::MAX, &3);
+    assert!(page.is_empty());
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_merchant_index_spans_multiple_buckets() {
+    let (env, client, _admin) = setup_test();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 105);
+
+    let mut filter = empty_invoice_filter();
+    filter.merchant = Some(merchant.clone());
+
+    let (page, _) = client.get_invoices(&filter, &98, &5);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().id, 99);
+    assert_eq!(page.get(4).unwrap().id, 103);
+}
+
+#[test]
+fn test_get_invoices_by_status_follows_transitions() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 5);
+
+    env.as_contract(&client.address, || {
+        invoice_component::set_invoice_status(&env, 2, InvoiceStatus::Paid);
+        invoice_component::set_invoice_status(&env, 4, InvoiceStatus::Paid);
+        invoice_component::set_invoice_status(&env, 5, InvoiceStatus::Cancelled);
+    });
+
+    let mut filter = empty_invoice_filter();
+    filter.status = Some(InvoiceStatus::Paid as u32);
+    let (paid, _) = client.get_invoices(&filter, &0, &10);
+    assert_eq!(paid.len(), 2);
+    assert_eq!(paid.get(0).unwrap().id, 2);
+    assert_eq!(paid.get(1).unwrap().id, 4);
+    assert_eq!(paid.get(1).unwrap().status, InvoiceStatus::Paid);
+
+    let (paid_after_cursor, _) = client.get_invoices(&filter, &2, &10);
+    assert_eq!(paid_after_cursor.len(), 1);
+    assert_eq!(paid_after_cursor.get(0).unwrap().id, 4);
+
+    filter.status = Some(InvoiceStatus::Pending as u32);
+    let (pending, _) = client.get_invoices(&filter, &0, &10);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().id, 1);
+    assert_eq!(pending.get(1).unwrap().id, 3);
+
+    let pending_bucket: Vec<u64> = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::InvoicesByStatus(InvoiceStatus::Pending as u32, 0))
+            .unwrap()
+    });
+    assert_eq!(pending_bucket.len(), 2);
+}
+
+#[test]
+fn test_status_index_respects_limit() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 4);
+
+    let mut filter = empty_invoice_filter();
+    filter.status = Some(InvoiceStatus::Pending as u32);
+    let (page, _) = client.get_invoices(&filter, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 2);
+    assert_eq!(page.get(1).unwrap().id, 3);
+}
+
+fn page_ids(env: &Env, page: &Vec<crate::types::Invoice>) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for invoice in page.iter() {
+        ids.push_back(invoice.id);
+    }
+    ids
+}
+
+#[test]
+fn test_get_invoices_bounds_scan_and_returns_cursor() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    create_invoices(&env, &client, &merchant, 5);
+
+    let mut filter = empty_invoice_filter();
+    filter.min_amount = Some(102);
+
+    // The first two invoices are examined but neither matches.
+    let (page, cursor) = client.get_invoices(&filter, &0, &2);
+    assert!(page.is_empty());
+    assert_eq!(cursor, Some(2));
+
+    let (page, cursor) = client.get_invoices(&filter, &2, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 3, 4]);
+    assert_eq!(cursor, Some(4));
+
+    let (page, cursor) = client.get_invoices(&filter, &4, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 5]);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_get_invoices_follows_merchant_and_status_indexes() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant_a = Address::generate(&env);
+    let merchant_b = Address::generate(&env);
+    client.register_merchant(&merchant_a);
+    client.register_merchant(&merchant_b);
+    create_invoices(&env, &client, &merchant_a, 2);
+    create_invoices(&env, &client, &merchant_b, 3);
+    create_invoices(&env, &client, &merchant_a, 1);
+
+    let mut filter = empty_invoice_filter();
+    filter.merchant = Some(merchant_a);
+    let (page, cursor) = client.get_invoices(&filter, &0, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 1, 2]);
+    assert_eq!(cursor, Some(2));
+    let (page, cursor) = client.get_invoices(&filter, &2, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 6]);
+    assert_eq!(cursor, None);
+
+    env.as_contract(&client.address, || {
+        invoice_component::set_invoice_status(&env, 3, InvoiceStatus::Paid);
+        invoice_component::set_invoice_status(&env, 6, InvoiceStatus::Paid);
+    });
+    let mut filter = empty_invoice_filter();
+    filter.status = Some(InvoiceStatus::Paid as u32);
+    let (page, cursor) = client.get_invoices(&filter, &0, &1);
+    assert_eq!(page_ids(&env, &page), vec![&env, 3]);
+    assert_eq!(cursor, Some(3));
+    let (page, cursor) = client.get_invoices(&filter, &3, &5);
+    assert_eq!(page_ids(&env, &page), vec![&env, 6]);
+    assert_eq!(cursor, None);
+}
+
+#[test]
+fn test_get_merchant_invoices_by_offset() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant_a = Address::generate(&env);
+    let merchant_b = Address::generate(&env);
+    let merchant_a_id = client.register_merchant(&merchant_a);
+    client.register_merchant(&merchant_b);
+    create_invoices(&env, &client, &merchant_a, 2);
+    create_invoices(&env, &client, &merchant_b, 2);
+    create_invoices(&env, &client, &merchant_a, 2);
+
+    let page = client.get_merchant_invoices(&merchant_a_id, &1, &2);
+    assert_eq!(page_ids(&env, &page), vec![&env, 2, 5]);
+
+    let page = client.get_merchant_invoices(&merchant_a_id, &3, &10);
+    assert_eq!(page_ids(&env, &page), vec![&env, 6]);
+
+    assert!(client
+        .get_merchant_invoices(&merchant_a_id, &4, &10)
+        .is_empty());
+    assert!(client.get_merchant_invoices(&99, &0, &10).is_empty());
+}
+
+#[test]
+fn test_get_payer_invoices_in_payment_order() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 3_000);
+
+    let mut invoice_ids = Vec::new(&t.env);
+    for _ in 0..3 {
+        invoice_ids.push_back(t.client.create_invoice(
+            &t.merchant(),
+            &String::from_str(&t.env, "Invoice"),
+            &1_000,
+            &t.token(),
+        ));
+    }
+    t.client.pay_invoice(&payer, &invoice_ids.get(2).unwrap());
+    t.client.pay_invoice(&payer, &invoice_ids.get(0).unwrap());
+
+    let page = t.client.get_payer_invoices(&payer, &0, &10);
+    assert_eq!(page_ids(&t.env, &page), vec![&t.env, 3, 1]);
+    let page = t.client.get_payer_invoices(&payer, &1, &10);
+    assert_eq!(page_ids(&t.env, &page), vec![&t.env, 1]);
+    assert!(t
+        .client
+        .get_payer_invoices(&Address::generate(&t.env), &0, &10)
+        .is_empty());
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Symbol, TryIntoVal};
+
+#[contract]
+struct TicketMinter;
+
+#[contractimpl]
+impl TicketMinter {
+    pub fn on_payment(env: Env, invoice_id: u64, payer: Address, amount: i128, token: Address) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("last"), &(invoice_id, payer, amount, token));
+    }
+
+    pub fn last_payment(env: Env) -> Option<(u64, Address, i128, Address)> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+#[contract]
+struct BrokenHook;
+
+#[contractimpl]
+impl BrokenHook {
+    pub fn on_payment(
+        _env: Env,
+        _invoice_id: u64,
+        _payer: Address,
+        _amount: i128,
+        _token: Address,
+    ) {
+        panic!("out of tickets");
+    }
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Concert ticket"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+fn hook_failed(t: &ShadeTestEnv) -> bool {
+    t.env.events().all().iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "payment_hook_failed_event")
+    })
+}
+
+#[test]
+fn test_hook_is_called_after_payment() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let hook_id = t.env.register(TicketMinter, ());
+    let hook = TicketMinterClient::new(&t.env, &hook_id);
+    t.client.add_trusted_contract(&t.admin, &hook_id);
+    t.client
+        .set_payment_hook(&t.merchant(), &Some(hook_id.clone()));
+    assert_eq!(
+        t.client.get_payment_hook(&t.merchant_id()),
+        Some(hook_id.clone())
+    );
+
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+
+    assert_eq!(
+        hook.last_payment(),
+        Some((invoice_id, payer, 1_000, t.token()))
+    );
+}
+
+#[test]
+fn test_failing_hook_does_not_revert_payment() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let hook_id = t.env.register(BrokenHook, ());
+    t.client.add_trusted_contract(&t.admin, &hook_id);
+    t.client.set_payment_hook(&t.merchant(), &Some(hook_id));
+
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    let invoice = t.client.pay_invoice(&payer, &invoice_id);
+
+    assert!(hook_failed(&t));
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+}
+
+#[test]
+fn test_hook_must_be_trusted() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let hook_id = t.env.register(TicketMinter, ());
+    let hook = TicketMinterClient::new(&t.env, &hook_id);
+
+    assert_contract_error(
+        t.client
+            .try_set_payment_hook(&t.merchant(), &Some(hook_id.clone())),
+        ContractError::ContractNotTrusted,
+    );
+
+    t.client.add_trusted_contract(&t.admin, &hook_id);
+    t.client
+        .set_payment_hook(&t.merchant(), &Some(hook_id.clone()));
+    t.client.remove_trusted_contract(&t.admin, &hook_id);
+
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+    t.client.pay_invoice(&payer, &invoice_id);
+    assert!(hook_failed(&t));
+    assert_eq!(hook.last_payment(), None);
+
+    t.client.set_payment_hook(&t.merchant(), &None);
+    assert_eq!(t.client.get_payment_hook(&t.merchant_id()), None);
+}
@@ -0,0 +1,303 @@
+#![cfg(test)]
+
+use crate::components::payment_link;
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{InvoiceStatus, OverpaymentPolicy, PaymentLinkStatus};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String};
+
+const NOW: u64 = 1_000;
+const EXPIRES_AT: u64 = 5_000;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 10_000);
+
+    (t, payer)
+}
+
+fn link_secret(env: &Env) -> Bytes {
+    Bytes::from_slice(env, b"link secret")
+}
+
+fn claim_hash(env: &Env) -> BytesN<32> {
+    env.crypto().sha256(&link_secret(env)).into()
+}
+
+fn create_link(t: &ShadeTestEnv, payer: &Address, amount: i128) -> u64 {
+    t.client
+        .create_payment_link(payer, &t.token(), &amount, &claim_hash(&t.env), &EXPIRES_AT)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_create_payment_link_escrows_funds() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+
+    let link = t.client.get_payment_link(&link_id);
+    assert_eq!(link.payer, payer);
+    assert_eq!(link.amount, 1_000);
+    assert_eq!(link.status, PaymentLinkStatus::Open);
+    assert_eq!(t.token_client().balance(&t.client.address), 1_000);
+}
+
+#[test]
+fn test_claim_payment_link_into_invoice() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Linked order"),
+        &1_000,
+        &t.token(),
+    );
+
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+    t.client.claim_payment_link(
+        &t.merchant(),
+        &link_id,
+        &link_secret(&t.env),
+        &Some(invoice_id),
+    );
+
+    let invoice = t.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(invoice.payer, Some(payer.clone()));
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(
+        t.client.get_payment_link(&link_id).status,
+        PaymentLinkStatus::Claimed
+    );
+}
+
+#[test]
+fn test_claim_payment_link_directly() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+    t.client
+        .claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None);
+
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None),
+        ContractError::PaymentLinkNotOpen,
+    );
+}
+
+#[test]
+fn test_claim_payment_link_rejects_wrong_secret_and_mismatched_invoice() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+
+    assert_contract_error(
+        t.client.try_claim_payment_link(
+            &t.merchant(),
+            &link_id,
+            &Bytes::from_slice(&t.env, b"guess"),
+            &None,
+        ),
+        ContractError::InvalidClaimSecret,
+    );
+
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Different amount"),
+        &999,
+        &t.token(),
+    );
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+    assert_contract_error(
+        t.client.try_claim_payment_link(
+            &t.merchant(),
+            &link_id,
+            &link_secret(&t.env),
+            &Some(invoice_id),
+        ),
+        ContractError::PaymentLinkNotApplicable,
+    );
+}
+
+#[test]
+fn test_refund_payment_link_after_expiry() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+
+    assert_contract_error(
+        t.client.try_refund_payment_link(&link_id),
+        ContractError::PaymentLinkNotExpired,
+    );
+
+    t.env.ledger().set_timestamp(EXPIRES_AT);
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None),
+        ContractError::PaymentLinkExpired,
+    );
+
+    t.client.refund_payment_link(&link_id);
+    assert_eq!(t.token_client().balance(&payer), 10_000);
+    assert_eq!(
+        t.client.get_payment_link(&link_id).status,
+        PaymentLinkStatus::Refunded
+    );
+}
+
+#[test]
+fn test_create_payment_link_validation() {
+    let (t, payer) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_create_payment_link(&payer, &t.token(), &0, &claim_hash(&t.env), &EXPIRES_AT),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_create_payment_link(
+            &payer,
+            &Address::generate(&t.env),
+            &100,
+            &claim_hash(&t.env),
+            &EXPIRES_AT,
+        ),
+        ContractError::TokenNotAccepted,
+    );
+    assert_contract_error(
+        t.client.try_get_payment_link(&3),
+        ContractError::PaymentLinkNotFound,
+    );
+}
+
+#[test]
+fn test_overpaid_link_follows_merchant_policy() {
+    let (t, payer) = setup_test();
+    let merchant_id = t.merchant_id();
+    assert_eq!(
+        t.client.get_overpayment_policy(&merchant_id),
+        OverpaymentPolicy::Reject
+    );
+
+    t.client
+        .set_overpayment_policy(&t.merchant(), &OverpaymentPolicy::RefundPayer);
+    let link_id = create_link(&t, &payer, 1_200);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Refund surplus"),
+        &1_000,
+        &t.token(),
+    );
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+    t.client.claim_payment_link(
+        &t.merchant(),
+        &link_id,
+        &link_secret(&t.env),
+        &Some(invoice_id),
+    );
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.token_client().balance(&payer), 9_000);
+
+    t.client
+        .set_overpayment_policy(&t.merchant(), &OverpaymentPolicy::CreditMerchant);
+    let link_id = create_link(&t, &payer, 1_200);
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Keep surplus"),
+        &1_000,
+        &t.token(),
+    );
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+    t.client.claim_payment_link(
+        &t.merchant(),
+        &link_id,
+        &link_secret(&t.env),
+        &Some(invoice_id),
+    );
+    assert_eq!(t.token_client().balance(&t.merchant()), 990 + 1_188);
+    assert_eq!(t.token_client().balance(&t.client.address), 22);
+}
+
+#[test]
+fn test_claim_is_bound_to_an_earlier_commitment() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None),
+        ContractError::InvalidClaimSecret,
+    );
+
+    let commitment = payment_link::claim_commitment(&t.env, &t.merchant(), &link_secret(&t.env));
+    t.client
+        .commit_payment_link_claim(&t.merchant(), &link_id, &commitment);
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None),
+        ContractError::InvalidClaimSecret,
+    );
+    // Copying the claimant's commitment doesn't help; it is bound to them.
+    let copier = Address::generate(&t.env);
+    t.client
+        .commit_payment_link_claim(&copier, &link_id, &commitment);
+
+    t.env
+        .ledger()
+        .set_sequence_number(t.env.ledger().sequence() + 1);
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&copier, &link_id, &link_secret(&t.env), &None),
+        ContractError::InvalidClaimSecret,
+    );
+
+    // Someone who reads the secret from the pending claim can only commit
+    // in the current ledger, which is too late to reveal alongside it.
+    let front_runner = Address::generate(&t.env);
+    let stolen = payment_link::claim_commitment(&t.env, &front_runner, &link_secret(&t.env));
+    t.client
+        .commit_payment_link_claim(&front_runner, &link_id, &stolen);
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&front_runner, &link_id, &link_secret(&t.env), &None),
+        ContractError::InvalidClaimSecret,
+    );
+
+    t.client
+        .claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+}
+
+#[test]
+fn test_blocked_claimant_cannot_claim() {
+    let (t, payer) = setup_test();
+    let link_id = create_link(&t, &payer, 1_000);
+    t.commit_claim(&t.merchant(), link_id, &link_secret(&t.env));
+
+    let admin = t.client.get_admin();
+    t.client.block_payer(&admin, &t.merchant());
+    assert_contract_error(
+        t.client
+            .try_claim_payment_link(&t.merchant(), &link_id, &link_secret(&t.env), &None),
+        ComplianceError::PayerBlocked,
+    );
+    let commitment = payment_link::claim_commitment(&t.env, &t.merchant(), &link_secret(&t.env));
+    assert_contract_error(
+        t.client
+            .try_commit_payment_link_claim(&t.merchant(), &link_id, &commitment),
+        ComplianceError::PayerBlocked,
+    );
+}
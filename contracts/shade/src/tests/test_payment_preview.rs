@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::testutils::ShadeTestEnv;
+use crate::types::{PaymentBlocker, PaymentRoute, PayoutSplit};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, String};
+
+fn create_invoice(t: &ShadeTestEnv, amount: i128, expires_at: Option<u64>) -> u64 {
+    t.client.create_invoice_with_expiry(
+        &t.merchant(),
+        &String::from_str(&t.env, "Preview invoice"),
+        &amount,
+        &t.token(),
+        &expires_at,
+    )
+}
+
+#[test]
+fn test_preview_quotes_fee_and_routing_splits() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let main = Address::generate(&t.env);
+    let tax = Address::generate(&t.env);
+    t.client.set_payment_routes(
+        &t.merchant(),
+        &vec![
+            &t.env,
+            PaymentRoute {
+                recipient: main.clone(),
+                share_bps: 7_000,
+            },
+            PaymentRoute {
+                recipient: tax.clone(),
+                share_bps: 3_000,
+            },
+        ],
+    );
+    let invoice_id = create_invoice(&t, 1_000, None);
+
+    let preview = t.client.preview_payment(&invoice_id, &1_000);
+    assert_eq!(preview.amount, 1_000);
+    assert_eq!(preview.fee, 10);
+    assert_eq!(preview.merchant_net, 990);
+    assert_eq!(
+        preview.splits,
+        vec![
+            &t.env,
+            PayoutSplit {
+                recipient: main,
+                amount: 693,
+            },
+            PayoutSplit {
+                recipient: tax,
+                amount: 297,
+            },
+        ]
+    );
+    assert!(preview.blockers.is_empty());
+    assert_eq!(t.client.get_collected_fees(&t.token()), 0);
+}
+
+#[test]
+fn test_preview_reports_every_blocker() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let expires_at = t.env.ledger().timestamp() + 60;
+    let invoice_id = create_invoice(&t, 1_000, Some(expires_at));
+
+    t.env.ledger().set_timestamp(expires_at);
+    t.client.remove_accepted_token(&t.admin, &t.token());
+    t.client.pause(&t.admin);
+
+    let preview = t.client.preview_payment(&invoice_id, &900);
+    assert_eq!(
+        preview.blockers,
+        vec![
+            &t.env,
+            PaymentBlocker::ContractPaused,
+            PaymentBlocker::InvoiceExpired,
+            PaymentBlocker::TokenNotAccepted,
+            PaymentBlocker::AmountMismatch,
+        ]
+    );
+    assert_eq!(
+        preview.splits,
+        vec![
+            &t.env,
+            PayoutSplit {
+                recipient: t.merchant(),
+                amount: 1_000,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_preview_flags_paid_invoice() {
+    let t = ShadeTestEnv::new()
+        .with_token(0)
+        .with_merchant()
+        .with_paid_invoice(1_000);
+    let invoice_id = t.paid_invoices.get(0).unwrap();
+
+    let preview = t.client.preview_payment(&invoice_id, &1_000);
+    assert_eq!(
+        preview.blockers,
+        vec![&t.env, PaymentBlocker::InvoiceNotPending]
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::PaymentRoute;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{vec, Address, Symbol, TryIntoVal, Vec};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn route(recipient: &Address, share_bps: u32) -> PaymentRoute {
+    PaymentRoute {
+        recipient: recipient.clone(),
+        share_bps,
+    }
+}
+
+#[test]
+fn test_invoice_proceeds_follow_payment_routes() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let main = Address::generate(&t.env);
+    let tax = Address::generate(&t.env);
+    let partner = Address::generate(&t.env);
+    let routes = vec![
+        &t.env,
+        route(&main, 9_000),
+        route(&tax, 700),
+        route(&partner, 300),
+    ];
+    t.client.set_payment_routes(&t.merchant(), &routes);
+    assert_eq!(t.client.get_payment_routes(&t.merchant_id()), routes);
+
+    let t = t.with_paid_invoice(1_000);
+
+    let routed = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+            name == Symbol::new(&t.env, "payment_routed_event")
+        })
+        .count();
+    assert_eq!(routed, 3);
+
+    // 990 net of the 1% fee; the rounding unit goes to the first route.
+    assert_eq!(t.token_client().balance(&main), 892);
+    assert_eq!(t.token_client().balance(&tax), 69);
+    assert_eq!(t.token_client().balance(&partner), 29);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+}
+
+#[test]
+fn test_payment_routes_must_sum_to_whole() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let main = Address::generate(&t.env);
+    let tax = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_set_payment_routes(
+            &t.merchant(),
+            &vec![&t.env, route(&main, 9_000), route(&tax, 900)],
+        ),
+        ContractError::InvalidPaymentRoutes,
+    );
+    assert_contract_error(
+        t.client.try_set_payment_routes(
+            &t.merchant(),
+            &vec![&t.env, route(&main, 10_000), route(&tax, 0)],
+        ),
+        ContractError::InvalidPaymentRoutes,
+    );
+    assert_contract_error(
+        t.client.try_set_payment_routes(
+            &Address::generate(&t.env),
+            &vec![&t.env, route(&main, 10_000)],
+        ),
+        ContractError::MerchantNotFound,
+    );
+}
+
+#[test]
+fn test_clearing_routes_pays_merchant_directly() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let main = Address::generate(&t.env);
+    t.client
+        .set_payment_routes(&t.merchant(), &vec![&t.env, route(&main, 10_000)]);
+    t.client
+        .set_payment_routes(&t.merchant(), &Vec::new(&t.env));
+    assert!(t.client.get_payment_routes(&t.merchant_id()).is_empty());
+
+    let t = t.with_paid_invoice(1_000);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_000);
+    assert_eq!(t.token_client().balance(&main), 0);
+}
+
+#[test]
+fn test_routes_pay_out_while_linked_account_is_paused() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.client.link_merchant_account(&t.merchant(), &account_id);
+    account.pause_account();
+
+    let main = Address::generate(&t.env);
+    t.client
+        .set_payment_routes(&t.merchant(), &vec![&t.env, route(&main, 10_000)]);
+
+    let t = t.with_paid_invoice(1_000);
+    assert_eq!(t.token_client().balance(&main), 1_000);
+    assert_eq!(t.token_client().balance(&account_id), 0);
+}
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::Events as _;
+use soroban_sdk::{BytesN, String, Symbol, TryIntoVal};
+
+fn description_hash(t: &ShadeTestEnv, description: &str) -> BytesN<32> {
+    let description = String::from_str(&t.env, description);
+    t.env.crypto().sha256(&description.to_bytes()).into()
+}
+
+#[test]
+fn test_private_invoice_stores_only_description_hash() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let hash = description_hash(&t, "2x consultation for Jane Doe");
+
+    let invoice_id = t
+        .client
+        .create_private_invoice(&t.merchant(), &hash, &1_000, &t.token());
+
+    let events = t.env.events().all();
+    let (_, topics, data) = events.get(events.len() - 1).unwrap();
+    let event_name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+    assert_eq!(
+        event_name,
+        Symbol::new(&t.env, "invoice_description_hash_event")
+    );
+    let event_data: soroban_sdk::Map<Symbol, soroban_sdk::Val> = data.try_into_val(&t.env).unwrap();
+    let event_hash: BytesN<32> = event_data
+        .get(Symbol::new(&t.env, "description_hash"))
+        .unwrap()
+        .try_into_val(&t.env)
+        .unwrap();
+    assert_eq!(event_hash, hash);
+
+    let invoice = t.client.get_invoice(&invoice_id);
+    assert_eq!(invoice.description, String::from_str(&t.env, ""));
+    assert_eq!(invoice.amount, 1_000);
+    assert_eq!(
+        t.client.get_invoice_description_hash(&invoice_id),
+        Some(hash)
+    );
+
+    assert!(t.client.verify_invoice_description(
+        &invoice_id,
+        &String::from_str(&t.env, "2x consultation for Jane Doe")
+    ));
+    assert!(!t.client.verify_invoice_description(
+        &invoice_id,
+        &String::from_str(&t.env, "3x consultation for Jane Doe")
+    ));
+}
+
+#[test]
+fn test_public_invoice_verifies_against_plaintext() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Public invoice"),
+        &1_000,
+        &t.token(),
+    );
+
+    assert_eq!(t.client.get_invoice_description_hash(&invoice_id), None);
+    assert!(t
+        .client
+        .verify_invoice_description(&invoice_id, &String::from_str(&t.env, "Public invoice")));
+    assert!(!t
+        .client
+        .verify_invoice_description(&invoice_id, &String::from_str(&t.env, "Other")));
+}
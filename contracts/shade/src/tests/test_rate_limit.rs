@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceRateLimit;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, String};
+
+const NOW: u64 = 1_000;
+const WINDOW: u64 = 3_600;
+
+fn setup_test<'a>() -> ShadeTestEnv<'a> {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+    t.client.set_permissionless_registration(&t.admin, &true);
+    t
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn try_create_invoice(
+    t: &ShadeTestEnv,
+    merchant: &Address,
+) -> Result<u64, Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    t.client
+        .try_create_invoice(
+            merchant,
+            &String::from_str(&t.env, "Invoice"),
+            &100,
+            &t.token(),
+        )
+        .map(|invoice_id| invoice_id.unwrap())
+}
+
+#[test]
+fn test_default_rate_limit_resets_each_window() {
+    let t = setup_test();
+    let limit = InvoiceRateLimit {
+        max_invoices: 2,
+        window: WINDOW,
+    };
+    t.client
+        .set_default_invoice_rate_limit(&t.admin, &Some(limit.clone()));
+    assert_eq!(t.client.get_invoice_rate_limit(&1), Some(limit));
+
+    assert!(try_create_invoice(&t, &t.merchant()).is_ok());
+    assert!(try_create_invoice(&t, &t.merchant()).is_ok());
+    assert_contract_error(
+        try_create_invoice(&t, &t.merchant()),
+        ComplianceError::RateLimited,
+    );
+
+    // Each merchant has its own allowance.
+    let other = Address::generate(&t.env);
+    t.client.register_merchant(&other);
+    assert!(try_create_invoice(&t, &other).is_ok());
+
+    t.env.ledger().set_timestamp(NOW + WINDOW);
+    assert!(try_create_invoice(&t, &t.merchant()).is_ok());
+}
+
+#[test]
+fn test_merchant_override_takes_precedence() {
+    let t = setup_test();
+    t.client.set_default_invoice_rate_limit(
+        &t.admin,
+        &Some(InvoiceRateLimit {
+            max_invoices: 1,
+            window: WINDOW,
+        }),
+    );
+    let override_limit = InvoiceRateLimit {
+        max_invoices: 3,
+        window: WINDOW,
+    };
+    t.client
+        .set_merchant_invoice_rate_limit(&t.admin, &1, &Some(override_limit.clone()));
+    assert_eq!(t.client.get_invoice_rate_limit(&1), Some(override_limit));
+
+    for _ in 0..3 {
+        assert!(try_create_invoice(&t, &t.merchant()).is_ok());
+    }
+    assert_contract_error(
+        try_create_invoice(&t, &t.merchant()),
+        ComplianceError::RateLimited,
+    );
+
+    // Clearing both limits lifts the cap entirely.
+    t.client
+        .set_merchant_invoice_rate_limit(&t.admin, &1, &None);
+    t.client.set_default_invoice_rate_limit(&t.admin, &None);
+    assert!(t.client.get_invoice_rate_limit(&1).is_none());
+    assert!(try_create_invoice(&t, &t.merchant()).is_ok());
+}
+
+#[test]
+fn test_only_admin_sets_rate_limits() {
+    let t = setup_test();
+    let limit = Some(InvoiceRateLimit {
+        max_invoices: 1,
+        window: WINDOW,
+    });
+
+    assert_contract_error(
+        t.client
+            .try_set_default_invoice_rate_limit(&t.merchant(), &limit),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_merchant_invoice_rate_limit(&t.merchant(), &1, &limit),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_merchant_invoice_rate_limit(&t.admin, &9, &limit),
+        ContractError::MerchantNotFound,
+    );
+    assert_contract_error(
+        t.client.try_set_default_invoice_rate_limit(
+            &t.admin,
+            &Some(InvoiceRateLimit {
+                max_invoices: 0,
+                window: WINDOW,
+            }),
+        ),
+        ContractError::InvalidAmount,
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, String};
+
+const MONTH: u64 = 30 * 86_400;
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_schedule(t: &ShadeTestEnv, max_invoices: Option<u32>) -> u64 {
+    t.client.create_recurring_schedule(
+        &t.merchant(),
+        &String::from_str(&t.env, "Monthly retainer"),
+        &1_000,
+        &t.token(),
+        &MONTH,
+        &max_invoices,
+    )
+}
+
+#[test]
+fn test_due_invoices_are_generated_each_period() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let start = t.env.ledger().timestamp();
+    let schedule_id = create_schedule(&t, Some(3));
+
+    let generated = t.client.generate_due_invoices(&schedule_id);
+    assert_eq!(generated.len(), 1);
+    let invoice = t.client.get_invoice(&generated.get(0).unwrap());
+    assert_eq!(invoice.status, InvoiceStatus::Pending);
+    assert_eq!(invoice.merchant_id, t.merchant_id());
+    assert_eq!(invoice.amount, 1_000);
+    assert!(t.client.generate_due_invoices(&schedule_id).is_empty());
+    t.env.ledger().set_timestamp(start + MONTH - 1);
+    assert!(t.client.generate_due_invoices(&schedule_id).is_empty());
+
+    // Catching up after a long gap stops once the schedule is used up.
+    t.env.ledger().set_timestamp(start + 10 * MONTH);
+    assert_eq!(t.client.generate_due_invoices(&schedule_id).len(), 2);
+    let schedule = t.client.get_recurring_schedule(&schedule_id);
+    assert_eq!(schedule.remaining, Some(0));
+    assert!(!schedule.active);
+}
+
+#[test]
+fn test_cancelled_schedule_stops_generating() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let schedule_id = create_schedule(&t, None);
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_cancel_recurring_schedule(&outsider, &schedule_id),
+        ContractError::NotAuthorized,
+    );
+    t.client
+        .cancel_recurring_schedule(&t.merchant(), &schedule_id);
+
+    t.env
+        .ledger()
+        .set_timestamp(t.env.ledger().timestamp() + 2 * MONTH);
+    assert!(t.client.generate_due_invoices(&schedule_id).is_empty());
+    assert_contract_error(
+        t.client.try_create_recurring_schedule(
+            &t.merchant(),
+            &String::from_str(&t.env, "Broken"),
+            &1_000,
+            &t.token(),
+            &0,
+            &None,
+        ),
+        InvoiceError::InvalidRecurringSchedule,
+    );
+}
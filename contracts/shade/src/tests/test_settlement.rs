@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::errors::{ContractError, InvoiceError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::{Role, SettlementStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, String};
+
+fn setup_test<'a>(paid_amounts: &[i128]) -> (ShadeTestEnv<'a>, Address) {
+    let mut t = ShadeTestEnv::new().with_token(0).with_merchant();
+    for amount in paid_amounts {
+        t = t.with_paid_invoice(*amount);
+    }
+
+    let operator = Address::generate(&t.env);
+    t.client.grant_role(&t.admin, &operator, &Role::Operator);
+
+    (t, operator)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_settlement_batch_lifecycle() {
+    let (t, operator) = setup_test(&[300, 700]);
+    let first = t.paid_invoices.get(0).unwrap();
+    let second = t.paid_invoices.get(1).unwrap();
+    let external_ref = BytesN::from_array(&t.env, &[7; 32]);
+
+    let batch_id = t.client.create_settlement_batch(
+        &operator,
+        &1,
+        &t.token(),
+        &vec![&t.env, first, second],
+        &external_ref,
+    );
+
+    let batch = t.client.get_settlement_batch(&batch_id);
+    assert_eq!(batch.merchant_id, 1);
+    assert_eq!(batch.total, 1_000);
+    assert_eq!(batch.invoice_ids, vec![&t.env, first, second]);
+    assert_eq!(batch.external_ref, external_ref);
+    assert_eq!(batch.status, SettlementStatus::Pending);
+    assert_eq!(
+        t.client.get_invoice_settlement_batch(&first),
+        Some(batch_id)
+    );
+
+    // Settling requires the batch to have been exported first.
+    assert_contract_error(
+        t.client.try_mark_settlement_settled(&operator, &batch_id),
+        InvoiceError::InvalidSettlementStatus,
+    );
+
+    t.client.mark_settlement_exported(&operator, &batch_id);
+    assert_eq!(
+        t.client.get_settlement_batch(&batch_id).status,
+        SettlementStatus::Exported
+    );
+
+    t.client.mark_settlement_settled(&operator, &batch_id);
+    assert_eq!(
+        t.client.get_settlement_batch(&batch_id).status,
+        SettlementStatus::Settled
+    );
+    assert_contract_error(
+        t.client.try_mark_settlement_exported(&operator, &batch_id),
+        InvoiceError::InvalidSettlementStatus,
+    );
+}
+
+#[test]
+fn test_settlement_batch_rejects_ineligible_invoices() {
+    let (t, operator) = setup_test(&[500]);
+    let paid = t.paid_invoices.get(0).unwrap();
+    let pending = t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Unpaid"),
+        &500,
+        &t.token(),
+    );
+    let external_ref = BytesN::from_array(&t.env, &[1; 32]);
+
+    assert_contract_error(
+        t.client.try_create_settlement_batch(
+            &operator,
+            &1,
+            &t.token(),
+            &vec![&t.env, paid, pending],
+            &external_ref,
+        ),
+        InvoiceError::InvoiceNotSettleable,
+    );
+    assert_contract_error(
+        t.client.try_create_settlement_batch(
+            &operator,
+            &2,
+            &t.token(),
+            &vec![&t.env, paid],
+            &external_ref,
+        ),
+        InvoiceError::InvoiceNotSettleable,
+    );
+    assert_contract_error(
+        t.client.try_create_settlement_batch(
+            &operator,
+            &1,
+            &t.token(),
+            &vec![&t.env],
+            &external_ref,
+        ),
+        ContractError::InvalidAmount,
+    );
+
+    t.client.create_settlement_batch(
+        &operator,
+        &1,
+        &t.token(),
+        &vec![&t.env, paid],
+        &external_ref,
+    );
+    assert_contract_error(
+        t.client.try_create_settlement_batch(
+            &operator,
+            &1,
+            &t.token(),
+            &vec![&t.env, paid],
+            &external_ref,
+        ),
+        InvoiceError::InvoiceAlreadyBatched,
+    );
+}
+
+#[test]
+fn test_settlement_batch_requires_operator() {
+    let (t, operator) = setup_test(&[500]);
+    let paid = t.paid_invoices.get(0).unwrap();
+    let outsider = Address::generate(&t.env);
+    let external_ref = BytesN::from_array(&t.env, &[1; 32]);
+
+    assert_contract_error(
+        t.client.try_create_settlement_batch(
+            &outsider,
+            &1,
+            &t.token(),
+            &vec![&t.env, paid],
+            &external_ref,
+        ),
+        ContractError::NotAuthorized,
+    );
+
+    let batch_id = t.client.create_settlement_batch(
+        &operator,
+        &1,
+        &t.token(),
+        &vec![&t.env, paid],
+        &external_ref,
+    );
+    assert_contract_error(
+        t.client.try_mark_settlement_exported(&outsider, &batch_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_get_settlement_batch(&99),
+        InvoiceError::SettlementBatchNotFound,
+    );
+}
@@ -0,0 +1,39 @@
+#![cfg(test)]
+
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::String;
+
+#[test]
+fn test_protocol_stats_track_invoices_and_volume() {
+    let t = ShadeTestEnv::new().with_token(100);
+
+    let stats = t.client.get_protocol_stats();
+    assert_eq!(stats.merchants, 0);
+    assert_eq!(stats.invoices, 0);
+    assert_eq!(stats.tokens.len(), 1);
+    assert_eq!(stats.tokens.get(0).unwrap().gross_volume, 0);
+
+    let t = t
+        .with_merchant()
+        .with_paid_invoice(1_000)
+        .with_paid_invoice(500);
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Unpaid"),
+        &200,
+        &t.token(),
+    );
+
+    let stats = t.client.get_protocol_stats();
+    assert_eq!(stats.merchants, 1);
+    assert_eq!(stats.invoices, 3);
+    assert_eq!(stats.pending_invoices, 1);
+    assert_eq!(stats.paid_invoices, 2);
+    assert_eq!(stats.cancelled_invoices, 0);
+    assert_eq!(stats.refunded_invoices, 0);
+
+    let token_stats = stats.tokens.get(0).unwrap();
+    assert_eq!(token_stats.token, t.token());
+    assert_eq!(token_stats.gross_volume, 1_500);
+    assert_eq!(token_stats.fees, 15);
+}
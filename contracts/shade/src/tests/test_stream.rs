@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use crate::errors::ContractError;
+use crate::testutils::ShadeTestEnv;
+use crate::types::StreamStatus;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, Env};
+
+const START: u64 = 1_000;
+const END: u64 = 2_000;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.env.ledger().set_timestamp(START);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 10_000);
+
+    (t, payer)
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().set_timestamp(timestamp);
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: ContractError,
+) {
+    let expected_error = soroban_sdk::Error::from_contract_error(error as u32);
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+#[test]
+fn test_create_stream_escrows_deposit() {
+    let (t, payer) = setup_test();
+
+    let stream_id =
+        t.client
+            .create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &START, &END);
+
+    let stream = t.client.get_stream(&stream_id);
+    assert_eq!(stream_id, 1);
+    assert_eq!(stream.payer, payer);
+    assert_eq!(stream.amount, 1_000);
+    assert_eq!(stream.withdrawn, 0);
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(t.token_client().balance(&payer), 9_000);
+    assert_eq!(t.token_client().balance(&t.client.address), 1_000);
+}
+
+#[test]
+fn test_withdraw_pays_vested_amount_minus_fee() {
+    let (t, payer) = setup_test();
+    let stream_id =
+        t.client
+            .create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &START, &END);
+
+    set_time(&t.env, START + 500);
+    assert_eq!(t.client.get_withdrawable_amount(&stream_id), 500);
+
+    let net = t.client.withdraw_from_stream(&t.merchant(), &stream_id);
+    assert_eq!(net, 495);
+    assert_eq!(t.token_client().balance(&t.merchant()), 495);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 5);
+    assert_eq!(t.client.get_withdrawable_amount(&stream_id), 0);
+
+    set_time(&t.env, END + 10);
+    t.client.withdraw_from_stream(&t.merchant(), &stream_id);
+
+    let stream = t.client.get_stream(&stream_id);
+    assert_eq!(stream.withdrawn, 1_000);
+    assert_eq!(stream.status, StreamStatus::Completed);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+}
+
+#[test]
+fn test_cancel_stream_splits_vested_and_unvested() {
+    let (t, payer) = setup_test();
+    let stream_id =
+        t.client
+            .create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &START, &END);
+
+    set_time(&t.env, START + 250);
+    t.client.cancel_stream(&payer, &stream_id);
+
+    let stream = t.client.get_stream(&stream_id);
+    assert_eq!(stream.status, StreamStatus::Cancelled);
+    assert_eq!(stream.withdrawn, 250);
+    assert_eq!(t.token_client().balance(&t.merchant()), 248);
+    assert_eq!(t.token_client().balance(&payer), 9_750);
+    assert_eq!(t.client.get_withdrawable_amount(&stream_id), 0);
+
+    set_time(&t.env, END);
+    assert_contract_error(
+        t.client.try_withdraw_from_stream(&t.merchant(), &stream_id),
+        ContractError::StreamNotActive,
+    );
+}
+
+#[test]
+fn test_create_stream_rejects_invalid_schedule() {
+    let (t, payer) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &END, &START),
+        ContractError::InvalidStreamSchedule,
+    );
+    assert_contract_error(
+        t.client.try_create_stream(
+            &payer,
+            &t.merchant_id(),
+            &t.token(),
+            &1_000,
+            &(START - 1),
+            &END,
+        ),
+        ContractError::InvalidStreamSchedule,
+    );
+}
+
+#[test]
+fn test_create_stream_rejects_invalid_amount_and_token() {
+    let (t, payer) = setup_test();
+
+    assert_contract_error(
+        t.client
+            .try_create_stream(&payer, &t.merchant_id(), &t.token(), &0, &START, &END),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_create_stream(
+            &payer,
+            &t.merchant_id(),
+            &Address::generate(&t.env),
+            &1_000,
+            &START,
+            &END,
+        ),
+        ContractError::TokenNotAccepted,
+    );
+    assert_contract_error(
+        t.client
+            .try_create_stream(&payer, &2, &t.token(), &1_000, &START, &END),
+        ContractError::MerchantNotFound,
+    );
+}
+
+#[test]
+fn test_only_stream_parties_can_withdraw_or_cancel() {
+    let (t, payer) = setup_test();
+    let stream_id =
+        t.client
+            .create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &START, &END);
+    let stranger = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client.try_withdraw_from_stream(&stranger, &stream_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_withdraw_from_stream(&payer, &stream_id),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client.try_cancel_stream(&stranger, &stream_id),
+        ContractError::NotAuthorized,
+    );
+}
+
+#[test]
+fn test_get_stream_not_found() {
+    let (t, _) = setup_test();
+    assert_contract_error(t.client.try_get_stream(&7), ContractError::StreamNotFound);
+}
+
+#[test]
+fn test_stream_payouts_go_to_linked_merchant_account() {
+    let (t, payer) = setup_test();
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.client.link_merchant_account(&t.merchant(), &account_id);
+
+    let stream_id =
+        t.client
+            .create_stream(&payer, &t.merchant_id(), &t.token(), &1_000, &START, &END);
+
+    set_time(&t.env, START + 500);
+    t.client.withdraw_from_stream(&t.merchant(), &stream_id);
+    assert_eq!(t.token_client().balance(&account_id), 495);
+
+    set_time(&t.env, START + 750);
+    t.client.cancel_stream(&payer, &stream_id);
+    assert_eq!(t.token_client().balance(&account_id), 743);
+    assert_eq!(t.token_client().balance(&t.merchant()), 0);
+}
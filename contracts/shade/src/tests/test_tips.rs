@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use crate::errors::{AccountError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use crate::types::InvoiceStatus;
+use account::account::{MerchantAccount, MerchantAccountClient};
+use account::types::DataKey as AccountDataKey;
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{Address, String, Symbol, TryIntoVal};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn create_invoice(t: &ShadeTestEnv) -> u64 {
+    t.client.create_invoice(
+        &t.merchant(),
+        &String::from_str(&t.env, "Dinner for two"),
+        &1_000,
+        &t.token(),
+    )
+}
+
+#[test]
+fn test_tip_is_paid_on_top_of_invoice() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_200);
+
+    let invoice = t.client.pay_invoice_with_tip(&payer, &invoice_id, &200);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    let events = t.env.events().all();
+    let tipped = events.iter().any(|(_, topics, _)| {
+        let name: Symbol = topics.get(0).unwrap().try_into_val(&t.env).unwrap();
+        name == Symbol::new(&t.env, "invoice_tip_paid_event")
+    });
+    assert!(tipped);
+    assert_eq!(t.client.get_invoice_tip(&invoice_id), 200);
+
+    assert_eq!(t.token_client().balance(&payer), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 12);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_188);
+}
+
+#[test]
+fn test_exempt_tips_reach_merchant_in_full() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    t.client.set_tips_fee_exempt(&t.admin, &true);
+    assert!(t.client.tips_fee_exempt());
+
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_200);
+    t.client.pay_invoice_with_tip(&payer, &invoice_id, &200);
+
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_eq!(t.token_client().balance(&t.merchant()), 1_190);
+}
+
+#[test]
+fn test_tip_validation() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+
+    assert_contract_error(
+        t.client.try_pay_invoice_with_tip(&payer, &invoice_id, &-1),
+        ContractError::InvalidAmount,
+    );
+    assert_contract_error(
+        t.client.try_set_tips_fee_exempt(&payer, &true),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.client.get_invoice_tip(&invoice_id), 0);
+}
+
+#[test]
+fn test_zero_tip_settles_invoice_without_tip_transfer() {
+    let t = ShadeTestEnv::new().with_token(100).with_merchant();
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_000);
+
+    let invoice = t.client.pay_invoice_with_tip(&payer, &invoice_id, &0);
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+    assert_eq!(t.client.get_invoice_tip(&invoice_id), 0);
+    assert_eq!(t.client.get_collected_fees(&t.token()), 10);
+    assert_eq!(t.token_client().balance(&t.merchant()), 990);
+}
+
+#[test]
+fn test_tips_rejected_while_merchant_account_is_paused_or_restricted() {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    let account_id = t.env.register(MerchantAccount, ());
+    let account = MerchantAccountClient::new(&t.env, &account_id);
+    account.initialize(&t.merchant(), &t.client.address, &t.merchant_id());
+    t.client.link_merchant_account(&t.merchant(), &account_id);
+
+    let invoice_id = create_invoice(&t);
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 1_200);
+
+    account.pause_account();
+    assert_contract_error(
+        t.client.try_pay_invoice_with_tip(&payer, &invoice_id, &200),
+        AccountError::MerchantAccountPaused,
+    );
+    account.unpause_account();
+
+    t.env.as_contract(&account_id, || {
+        t.env
+            .storage()
+            .persistent()
+            .set(&AccountDataKey::Restricted, &true);
+    });
+    assert_contract_error(
+        t.client.try_pay_invoice_with_tip(&payer, &invoice_id, &200),
+        AccountError::MerchantAccountRestricted,
+    );
+    assert_eq!(t.token_client().balance(&payer), 1_200);
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use crate::components::migration;
+use crate::errors::{ContractError, GovernanceError};
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Vec};
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn open_payment_link(t: &ShadeTestEnv, amount: i128) {
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, amount);
+    let claim_hash: BytesN<32> = t
+        .env
+        .crypto()
+        .sha256(&Bytes::from_slice(&t.env, b"secret"))
+        .into();
+    t.client
+        .create_payment_link(&payer, &t.token(), &amount, &claim_hash, &3_600);
+}
+
+#[test]
+fn test_recover_only_sweeps_untracked_balance() {
+    let t = ShadeTestEnv::new()
+        .with_token(100)
+        .with_merchant()
+        .with_paid_invoice(1_000);
+    open_payment_link(&t, 500);
+    assert_eq!(t.client.get_recoverable_balance(&t.token()), 0);
+
+    t.mint(&t.client.address, 300);
+    assert_eq!(t.client.get_recoverable_balance(&t.token()), 300);
+
+    let recipient = Address::generate(&t.env);
+    assert_contract_error(
+        t.client
+            .try_recover_tokens(&t.admin, &t.token(), &301, &recipient),
+        GovernanceError::InsufficientRecoverableBalance,
+    );
+
+    t.client
+        .recover_tokens(&t.admin, &t.token(), &300, &recipient);
+    assert_eq!(t.token_client().balance(&recipient), 300);
+    // Collected fees and the open payment link stay in the contract.
+    assert_eq!(t.token_client().balance(&t.client.address), 510);
+    assert_eq!(t.client.get_recoverable_balance(&t.token()), 0);
+}
+
+#[test]
+fn test_only_admin_recovers_tokens() {
+    let t = ShadeTestEnv::new().with_token(0);
+    t.mint(&t.client.address, 300);
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_recover_tokens(&outsider, &t.token(), &300, &outsider),
+        ContractError::NotAuthorized,
+    );
+    assert_eq!(t.token_client().balance(&t.client.address), 300);
+}
+
+#[test]
+fn test_migration_treats_existing_balance_as_owed() {
+    let t = ShadeTestEnv::new().with_token(0);
+    t.mint(&t.client.address, 700);
+    t.env.as_contract(&t.client.address, || {
+        migration::set_schema_version(&t.env, 2);
+    });
+
+    t.client.migrate(&t.admin, &2, &Vec::new(&t.env));
+    assert_eq!(t.client.get_recoverable_balance(&t.token()), 0);
+
+    t.mint(&t.client.address, 50);
+    assert_eq!(t.client.get_recoverable_balance(&t.token()), 50);
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use crate::components::ttl::{DAY_IN_LEDGERS, PERSISTENT_BUMP_AMOUNT};
+use crate::shade::{Shade, ShadeClient};
+use crate::types::{DataKey, InvoiceFilter, InvoiceStatus, StorageKey};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup_test() -> (Env, ShadeClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Shade, ());
+    let client = ShadeClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_permissionless_registration(&admin, &true);
+    (env, client, admin)
+}
+
+fn persistent_ttl(env: &Env, contract_id: &Address, key: &DataKey) -> u32 {
+    env.as_contract(contract_id, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_invoice_and_merchant_ttl_extended_on_write() {
+    let (env, client, _admin) = setup_test();
+
+    let merchant = Address::generate(&env);
+    client.register_merchant(&merchant);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(&env, "Invoice"),
+        &100,
+        &Address::generate(&env),
+    );
+
+    assert_eq!(
+        persistent_ttl(&env, &client.address, &DataKey::Invoice(invoice_id)),
+        PERSISTENT_BUMP_AMOUNT
+    );
+    assert_eq!(
+        persistent_ttl(&env, &client.address, &DataKey::Merchant(1)),
+        PERSISTENT_BUMP_AMOUNT
+    );
+}
+
+// Moves past the extension threshold so a fresh extend_ttl is observable.
+fn age_entries(env: &Env) {
+    env.ledger()
+        .with_mut(|ledger| ledger.sequence_number += 2 * DAY_IN_LEDGERS);
+}
+
+fn create_invoice(env: &Env, client: &ShadeClient<'_>) -> (Address, u64) {
+    let merchant = Address::generate(env);
+    client.register_merchant(&merchant);
+    let invoice_id = client.create_invoice(
+        &merchant,
+        &String::from_str(env, "Invoice"),
+        &100,
+        &Address::generate(env),
+    );
+    (merchant, invoice_id)
+}
+
+#[test]
+fn test_singletons_counters_and_indexes_extended_on_write() {
+    let (env, client, admin) = setup_test();
+    create_invoice(&env, &client);
+    client.set_legacy_event_format(&admin, &false);
+
+    for key in [
+        DataKey::Admin,
+        DataKey::ContractInfo,
+        DataKey::MerchantCount,
+        DataKey::InvoiceCount,
+        DataKey::MerchantInvoiceCount(1),
+        DataKey::MerchantInvoices(1, 0),
+        DataKey::InvoicesByStatus(InvoiceStatus::Pending as u32, 0),
+        DataKey::LegacyEventFormat,
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &client.address, &key),
+            PERSISTENT_BUMP_AMOUNT
+        );
+    }
+}
+
+#[test]
+fn test_singletons_counters_and_indexes_extended_on_read() {
+    let (env, client, _admin) = setup_test();
+    let (merchant, _) = create_invoice(&env, &client);
+    age_entries(&env);
+
+    client.get_contract_info();
+    client.get_admin();
+    client.get_counts();
+    client.get_invoices(
+        &InvoiceFilter {
+            status: None,
+            merchant: Some(merchant),
+            min_amount: None,
+            max_amount: None,
+        },
+        &0,
+        &10,
+    );
+    client.get_invoices(
+        &InvoiceFilter {
+            status: Some(InvoiceStatus::Pending as u32),
+            merchant: None,
+            min_amount: None,
+            max_amount: None,
+        },
+        &0,
+        &10,
+    );
+
+    for key in [
+        DataKey::Admin,
+        DataKey::ContractInfo,
+        DataKey::MerchantCount,
+        DataKey::InvoiceCount,
+        DataKey::MerchantInvoiceCount(1),
+        DataKey::MerchantInvoices(1, 0),
+        DataKey::InvoicesByStatus(InvoiceStatus::Pending as u32, 0),
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &client.address, &key),
+            PERSISTENT_BUMP_AMOUNT
+        );
+    }
+}
+
+#[test]
+fn test_bump_storage_extends_requested_keys() {
+    let (env, client, admin) = setup_test();
+    let (merchant, invoice_id) = create_invoice(&env, &client);
+    age_entries(&env);
+
+    let admin_ttl_before = persistent_ttl(&env, &client.address, &DataKey::Admin);
+    assert!(admin_ttl_before < PERSISTENT_BUMP_AMOUNT);
+
+    client.bump_storage(
+        &admin,
+        &vec![
+            &env,
+            StorageKey::Protocol,
+            StorageKey::Merchant(1),
+            StorageKey::Invoice(invoice_id),
+            StorageKey::InvoiceStatusIndex(InvoiceStatus::Pending, 0),
+            StorageKey::Invoice(42),
+        ],
+    );
+
+    for key in [
+        DataKey::Admin,
+        DataKey::ContractInfo,
+        DataKey::InvoiceCount,
+        DataKey::Merchant(1),
+        DataKey::MerchantId(merchant),
+        DataKey::Invoice(invoice_id),
+        DataKey::InvoicesByStatus(InvoiceStatus::Pending as u32, 0),
+    ] {
+        assert_eq!(
+            persistent_ttl(&env, &client.address, &key),
+            PERSISTENT_BUMP_AMOUNT
+        );
+    }
+}
+
+#[should_panic(expected = "HostError: Error(Contract, #1)")]
+#[test]
+fn test_bump_storage_non_admin() {
+    let (env, client, _admin) = setup_test();
+
+    let non_admin = Address::generate(&env);
+    client.bump_storage(&non_admin, &vec![&env, StorageKey::Protocol]);
+}
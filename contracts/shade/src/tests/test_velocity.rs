@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use crate::errors::{ComplianceError, ContractError};
+use crate::testutils::ShadeTestEnv;
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{Address, BytesN};
+
+const NOW: u64 = 1_000;
+const WINDOW: u64 = 3_600;
+
+fn setup_test<'a>() -> (ShadeTestEnv<'a>, Address) {
+    let t = ShadeTestEnv::new().with_token(0).with_merchant();
+    t.env.ledger().set_timestamp(NOW);
+
+    let payer = Address::generate(&t.env);
+    t.mint(&payer, 10_000);
+
+    (t, payer)
+}
+
+fn assert_contract_error<T, E>(
+    result: Result<T, Result<soroban_sdk::Error, E>>,
+    error: impl Into<soroban_sdk::Error>,
+) {
+    let expected_error = error.into();
+    assert!(matches!(result, Err(Ok(err)) if err == expected_error));
+}
+
+fn pay(
+    t: &ShadeTestEnv,
+    payer: &Address,
+    amount: i128,
+) -> Result<u64, Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    let now = t.env.ledger().timestamp();
+    t.client
+        .try_issue_gift_card(
+            payer,
+            &t.merchant_id(),
+            &t.token(),
+            &amount,
+            &(now + WINDOW),
+            &None,
+        )
+        .map(|card_id| card_id.unwrap())
+}
+
+#[test]
+fn test_velocity_limit_applies_over_rolling_window() {
+    let (t, payer) = setup_test();
+    t.client
+        .set_velocity_limit(&t.admin, &t.token(), &1_000, &WINDOW);
+
+    pay(&t, &payer, 600).unwrap();
+    t.env.ledger().set_timestamp(NOW + 1_800);
+    pay(&t, &payer, 400).unwrap();
+    assert_eq!(t.client.get_payer_volume(&payer, &t.token()), 1_000);
+
+    assert_contract_error(pay(&t, &payer, 1), ComplianceError::LimitExceeded);
+    assert_contract_error(
+        t.client.try_create_payment_link(
+            &payer,
+            &t.token(),
+            &1,
+            &BytesN::from_array(&t.env, &[0; 32]),
+            &(NOW + WINDOW * 2),
+        ),
+        ComplianceError::LimitExceeded,
+    );
+
+    // The first payment rolls out of the window; the second still counts.
+    t.env.ledger().set_timestamp(NOW + WINDOW);
+    assert_eq!(t.client.get_payer_volume(&payer, &t.token()), 400);
+    pay(&t, &payer, 600).unwrap();
+    assert_contract_error(pay(&t, &payer, 1), ComplianceError::LimitExceeded);
+
+    // Other payers have their own allowance.
+    let other = Address::generate(&t.env);
+    assert_eq!(t.client.get_payer_volume(&other, &t.token()), 0);
+}
+
+#[test]
+fn test_removing_velocity_limit_lifts_cap() {
+    let (t, payer) = setup_test();
+    t.client
+        .set_velocity_limit(&t.admin, &t.token(), &500, &WINDOW);
+    assert_contract_error(pay(&t, &payer, 501), ComplianceError::LimitExceeded);
+
+    t.client.remove_velocity_limit(&t.admin, &t.token());
+    assert!(t.client.get_velocity_limit(&t.token()).is_none());
+    pay(&t, &payer, 5_000).unwrap();
+}
+
+#[test]
+fn test_only_admin_sets_velocity_limit() {
+    let (t, _) = setup_test();
+    let outsider = Address::generate(&t.env);
+
+    assert_contract_error(
+        t.client
+            .try_set_velocity_limit(&outsider, &t.token(), &500, &WINDOW),
+        ContractError::NotAuthorized,
+    );
+    assert_contract_error(
+        t.client
+            .try_set_velocity_limit(&t.admin, &t.token(), &0, &WINDOW),
+        ContractError::InvalidAmount,
+    );
+
+    t.client
+        .set_velocity_limit(&t.admin, &t.token(), &500, &WINDOW);
+    let limit = t.client.get_velocity_limit(&t.token()).unwrap();
+    assert_eq!(limit.max_amount, 500);
+    assert_eq!(limit.window, WINDOW);
+}
@@ -0,0 +1,123 @@
+use crate::components::payment_link;
+use crate::shade::{Shade, ShadeClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{token, Address, Bytes, Env, String, Vec};
+
+// Shared setup for Shade tests, also exported to integrators behind the
+// `testutils` feature:
+//
+//     let t = ShadeTestEnv::new()
+//         .with_token(100)
+//         .with_merchant()
+//         .with_paid_invoice(1_000);
+pub struct ShadeTestEnv<'a> {
+    pub env: Env,
+    pub client: ShadeClient<'a>,
+    pub admin: Address,
+    pub token: Option<Address>,
+    pub merchant: Option<Address>,
+    pub merchant_id: Option<u64>,
+    pub paid_invoices: Vec<u64>,
+}
+
+impl<'a> Default for ShadeTestEnv<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ShadeTestEnv<'a> {
+    /// Registers and initializes a Shade contract with all auths mocked.
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(Shade, ());
+        let client = ShadeClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin);
+
+        ShadeTestEnv {
+            paid_invoices: Vec::new(&env),
+            env,
+            client,
+            admin,
+            token: None,
+            merchant: None,
+            merchant_id: None,
+        }
+    }
+
+    /// Deploys a Stellar Asset Contract and accepts it with `fee` basis points.
+    pub fn with_token(mut self, fee: i128) -> Self {
+        let token_admin = Address::generate(&self.env);
+        let token = self
+            .env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        self.client.add_accepted_token(&self.admin, &token);
+        self.client.set_fee(&self.admin, &token, &fee);
+
+        self.token = Some(token);
+        self
+    }
+
+    /// Registers a merchant and approves it so it can invoice right away.
+    pub fn with_merchant(mut self) -> Self {
+        let merchant = Address::generate(&self.env);
+        let merchant_id = self.client.register_merchant(&merchant);
+        self.client.approve_merchant(&self.admin, &merchant_id);
+
+        self.merchant = Some(merchant);
+        self.merchant_id = Some(merchant_id);
+        self
+    }
+
+    /// Creates an invoice for the merchant and pays it from a funded payer.
+    pub fn with_paid_invoice(mut self, amount: i128) -> Self {
+        let invoice_id = self.client.create_invoice(
+            &self.merchant(),
+            &String::from_str(&self.env, "Test invoice"),
+            &amount,
+            &self.token(),
+        );
+
+        let payer = Address::generate(&self.env);
+        self.mint(&payer, amount);
+        self.client.pay_invoice(&payer, &invoice_id);
+
+        self.paid_invoices.push_back(invoice_id);
+        self
+    }
+
+    pub fn token(&self) -> Address {
+        self.token.clone().expect("call with_token first")
+    }
+
+    pub fn token_client(&self) -> token::Client<'a> {
+        token::Client::new(&self.env, &self.token())
+    }
+
+    pub fn merchant(&self) -> Address {
+        self.merchant.clone().expect("call with_merchant first")
+    }
+
+    pub fn merchant_id(&self) -> u64 {
+        self.merchant_id.expect("call with_merchant first")
+    }
+
+    /// Commits `claimant` to a payment link secret and closes the ledger, so
+    /// the claim can be revealed next.
+    pub fn commit_claim(&self, claimant: &Address, link_id: u64, secret: &Bytes) {
+        let commitment = payment_link::claim_commitment(&self.env, claimant, secret);
+        self.client
+            .commit_payment_link_claim(claimant, &link_id, &commitment);
+        let sequence = self.env.ledger().sequence();
+        self.env.ledger().set_sequence_number(sequence + 1);
+    }
+
+    pub fn mint(&self, to: &Address, amount: i128) {
+        token::StellarAssetClient::new(&self.env, &self.token()).mint(to, &amount);
+    }
+}
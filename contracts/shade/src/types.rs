@@ -1,6 +1,9 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, Vec};
 
-#[contracttype]
+// Storage keys are internal. Kept out of the contract spec, which caps
+// unions at 50 cases.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
     Paused,
@@ -19,6 +22,122 @@ pub enum DataKey {
     InvoiceCount,
     ReentrancyStatus,
     Role(Address, Role),
+    MerchantInvoiceCount(u64),
+    MerchantInvoices(u64, u64),
+    PayerInvoiceCount(Address),
+    PayerInvoices(Address, u64),
+    InvoicesByStatus(u32, u64),
+    SchemaVersion,
+    LegacyEventFormat,
+    WasmHash,
+    PendingUpgrade,
+    UpgradeHistory,
+    NativeToken,
+    Oracle,
+    TrustedContract(Address),
+    CollectedFees(Address),
+    StreamCount,
+    Stream(u64),
+    GiftCardCount,
+    GiftCard(u64),
+    PaymentLinkCount,
+    PaymentLink(u64),
+    CampaignCount,
+    Campaign(u64),
+    CampaignContribution(u64, Address),
+    TokenMetadata(Address),
+    SettlementBatchCount,
+    SettlementBatch(u64),
+    InvoiceSettlementBatch(u64),
+    BlockedPayer(Address),
+    VelocityLimit(Address),
+    PayerVolume(Address, Address),
+    DefaultInvoiceRateLimit,
+    MerchantInvoiceRateLimit(u64),
+    MerchantInvoiceWindow(u64),
+    MerchantPendingApproval(u64),
+    PermissionlessRegistration,
+    RegistrationBond,
+    MerchantBond(u64),
+    MerchantBondReleaseAt(u64),
+    InvoiceStatusCount(u32),
+    GrossVolume(Address),
+    AmountBounds(Address),
+    DefaultInvoiceExpiry,
+    MerchantInvoiceExpiry(u64),
+    InvoiceExpiry(u64),
+    DistributionShares,
+    DistributedFees(Address),
+    DistributionClaimable(Address, Address),
+    Council,
+    ProposalCount,
+    Proposal(u64),
+    InvoiceDescriptionHash(u64),
+    InvoiceBalance(u64),
+    MerchantAccount(u64),
+    FeeExempt(Address),
+    InvoiceDelegate(u64, Address),
+    ActivityCount,
+    ActivityPage(u64),
+    InvoicesByExpiry(u64),
+    CustodyBalance(Address),
+    PaymentRoutes(u64),
+    CleanupBounty(Address),
+    InvoiceApprovalThreshold(u64, Address),
+    InvoicePendingApproval(u64),
+    ClawbackAsset(Address),
+    RejectClawbackAssets,
+    InvoiceLineItems(u64),
+    InvoiceMetadata(u64),
+    InvoiceDueDate(u64),
+    InvoiceLateFee(u64),
+    InvoiceTax(u64),
+    InvoiceTip(u64),
+    TipsFeeExempt,
+    OverpaymentPolicy(u64),
+    InvoiceMultiPayer(u64),
+    InvoiceContributions(u64),
+    InvoicePayerShares(u64),
+    InvoicePayerAllowlist(u64),
+    InvoiceRefundedAmount(u64),
+    InvoiceAccountCredit(u64),
+    InvoiceFee(u64),
+    RefundProtocolFees,
+    RefundSubsidy,
+    MerchantDebt(u64, Address),
+    InvoiceFinalSale(u64),
+    InvoiceEscrowPeriod(u64),
+    InvoiceEscrowHold(u64),
+    InvoiceMilestones(u64),
+    RecurringScheduleCount,
+    RecurringSchedule(u64),
+    InvoiceAmendments(u64),
+    CustomerProfile(Address),
+    PaymentHook(u64),
+    AffiliateCommission(u64),
+    AffiliateEarnings(Address, Address),
+    PaymentLinkClaimCommitment(u64, Address),
+}
+
+// Records an admin can keep live through `bump_storage`. Each maps onto the
+// internal storage entries that make up that record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKey {
+    // Admin, contract info, schema and upgrade state, accepted tokens and
+    // the id counters.
+    Protocol,
+    Token(Address),
+    Merchant(u64),
+    MerchantInvoices(u64, u64),
+    PayerInvoices(Address, u64),
+    Invoice(u64),
+    InvoiceStatusIndex(InvoiceStatus, u64),
+    Stream(u64),
+    GiftCard(u64),
+    PaymentLink(u64),
+    RecurringSchedule(u64),
+    Customer(Address),
 }
 
 #[contracttype]
@@ -26,6 +145,230 @@ pub enum DataKey {
 pub struct ContractInfo {
     pub admin: Address,
     pub timestamp: u64,
+    pub version: (u32, u32, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntityCounts {
+    pub merchants: u64,
+    pub invoices: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentRoute {
+    pub recipient: Address,
+    pub share_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSplit {
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PaymentBlocker {
+    ContractPaused = 0,
+    InvoiceNotPending = 1,
+    InvoiceExpired = 2,
+    AwaitingApproval = 3,
+    TokenNotAccepted = 4,
+    AmountMismatch = 5,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OverpaymentPolicy {
+    Reject = 0,
+    CreditMerchant = 1,
+    RefundPayer = 2,
+}
+
+// What paying an invoice would do right now. `blockers` lists every
+// condition that would make the payment fail; it is empty when the payment
+// would go through.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentPreview {
+    pub invoice_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub late_fee: i128,
+    pub tax: i128,
+    pub fee: i128,
+    pub merchant_net: i128,
+    pub splits: Vec<PayoutSplit>,
+    pub blockers: Vec<PaymentBlocker>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFee {
+    pub token: Address,
+    pub fee: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    pub admin: Address,
+    pub token_fees: Vec<TokenFee>,
+    pub native_token: Option<Address>,
+    pub default_invoice_expiry: Option<u64>,
+    pub paused: bool,
+    pub legacy_event_format: bool,
+    pub schema_version: u32,
+    pub version: (u32, u32, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenStats {
+    pub token: Address,
+    pub gross_volume: i128,
+    pub fees: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    pub merchants: u64,
+    pub invoices: u64,
+    pub pending_invoices: u64,
+    pub paid_invoices: u64,
+    pub cancelled_invoices: u64,
+    pub refunded_invoices: u64,
+    pub overdue_invoices: u64,
+    pub tokens: Vec<TokenStats>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub proposed_at: u64,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeRecord {
+    pub wasm_hash: BytesN<32>,
+    pub applied_at: u64,
+    pub schema_version: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub name: soroban_sdk::String,
+    pub symbol: soroban_sdk::String,
+    pub decimals: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelocityLimit {
+    pub max_amount: i128,
+    pub window: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceRateLimit {
+    pub max_invoices: u32,
+    pub window: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceWindow {
+    pub start: u64,
+    pub count: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerchantBond {
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmountBounds {
+    pub min: i128,
+    pub max: i128,
+    pub unverified_max: Option<i128>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributionShare {
+    pub recipient: Address,
+    pub weight: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub members: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParameterChange {
+    Fee(Address, i128),
+    DefaultInvoiceExpiry(Option<u64>),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProposalStatus {
+    Pending = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub change: ParameterChange,
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub executable_at: Option<u64>,
+    pub status: ProposalStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub address: Address,
+    pub max_price_age: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -38,6 +381,69 @@ pub struct Merchant {
     pub date_registered: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowHold {
+    pub amount: i128,
+    pub release_at: u64,
+}
+
+// `remaining` counts the invoices still to be issued; None means the
+// schedule runs until cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringSchedule {
+    pub id: u64,
+    pub merchant_id: u64,
+    pub description: soroban_sdk::String,
+    pub amount: i128,
+    pub token: Address,
+    pub period: u64,
+    pub next_at: u64,
+    pub remaining: Option<u32>,
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MilestoneStatus {
+    Pending = 0,
+    Requested = 1,
+    Released = 2,
+    Disputed = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub release_amount: i128,
+    pub status: MilestoneStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceTax {
+    pub tax_bps: u32,
+    pub tax_recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LateFeePolicy {
+    Flat(i128),
+    BpsPerDay(u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineItem {
+    pub description: soroban_sdk::String,
+    pub quantity: u32,
+    pub unit_price: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Invoice {
@@ -60,6 +466,26 @@ pub enum InvoiceStatus {
     Paid = 1,
     Cancelled = 2,
     Refunded = 3,
+    Overdue = 4,
+}
+
+// Compact copy of an invoice's status and amounts, kept alongside the full
+// record so polling clients don't deserialize the description every time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceBalance {
+    pub status: InvoiceStatus,
+    pub amount: i128,
+    pub paid: i128,
+    pub refunded: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvoiceExpiryPolicy {
+    Default,
+    Never,
+    After(u64),
 }
 
 #[contracttype]
@@ -85,3 +511,171 @@ pub enum Role {
     Manager,
     Operator,
 }
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum StreamStatus {
+    Active = 0,
+    Completed = 1,
+    Cancelled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stream {
+    pub id: u64,
+    pub payer: Address,
+    pub merchant_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub withdrawn: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub status: StreamStatus,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum GiftCardStatus {
+    Active = 0,
+    Depleted = 1,
+    Expired = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftCard {
+    pub id: u64,
+    pub merchant_id: u64,
+    pub token: Address,
+    pub buyer: Address,
+    pub holder: Option<Address>,
+    pub claim_hash: Option<BytesN<32>>,
+    pub balance: i128,
+    pub expires_at: u64,
+    pub status: GiftCardStatus,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PaymentLinkStatus {
+    Open = 0,
+    Claimed = 1,
+    Refunded = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentLink {
+    pub id: u64,
+    pub payer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub claim_hash: BytesN<32>,
+    pub expires_at: u64,
+    pub status: PaymentLinkStatus,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum CampaignStatus {
+    Active = 0,
+    Succeeded = 1,
+    Failed = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    pub id: u64,
+    pub merchant_id: u64,
+    pub token: Address,
+    pub goal: i128,
+    pub raised: i128,
+    pub deadline: u64,
+    pub status: CampaignStatus,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SettlementStatus {
+    Pending = 0,
+    Exported = 1,
+    Settled = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementBatch {
+    pub id: u64,
+    pub merchant_id: u64,
+    pub token: Address,
+    pub invoice_ids: Vec<u64>,
+    pub total: i128,
+    pub external_ref: BytesN<32>,
+    pub status: SettlementStatus,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ActivityKind {
+    InvoicePayment = 0,
+    CampaignPayout = 1,
+    StreamCharge = 2,
+    PaymentLinkRefund = 3,
+    CampaignRefund = 4,
+}
+
+// `reference_id` is the invoice, campaign, stream or payment link id,
+// depending on `kind`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRecord {
+    pub kind: ActivityKind,
+    pub reference_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+// One entry in an invoice's amendment history. Unchanged fields carry the
+// same old and new value.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvoiceAmendment {
+    pub actor: Address,
+    pub timestamp: u64,
+    pub old_amount: i128,
+    pub new_amount: i128,
+    pub old_description: soroban_sdk::String,
+    pub new_description: soroban_sdk::String,
+    pub old_expires_at: Option<u64>,
+    pub new_expires_at: Option<u64>,
+    pub old_token: Address,
+    pub new_token: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomerProfile {
+    pub preferred_token: Option<Address>,
+    pub refund_address: Option<Address>,
+    pub contact_hash: Option<BytesN<32>>,
+    pub registered_at: u64,
+}
+
+// Lifetime commission an affiliate has earned and claimed in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AffiliateEarnings {
+    pub accrued: i128,
+    pub claimed: i128,
+}
@@ -0,0 +1,115 @@
+use soroban_sdk::{contracttrait, contracttype, Address, BytesN, Env, Vec};
+
+// Public surface of the merchant account contract. It lives here rather than
+// in the account crate so Shade can call accounts through the generated
+// `MerchantAccountTraitClient` without keeping its own copy of the interface.
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountDetails {
+    pub merchant: Address,
+    pub manager: Address,
+    pub merchant_id: u64,
+    pub restricted: bool,
+    pub paused: bool,
+    pub verified: bool,
+    pub date_created: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenBalance {
+    pub token: Address,
+    pub balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    pub new_merchant: Address,
+    pub approvals: Vec<Address>,
+    pub initiated_at: u64,
+    pub executable_at: u64,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum StatementEntryKind {
+    Deposit = 0,
+    Withdrawal = 1,
+    Refund = 2,
+    InvoicePayment = 3,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatementEntry {
+    pub kind: StatementEntryKind,
+    pub token: Address,
+    pub amount: i128,
+    pub counterparty: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub requested_at: u64,
+}
+
+// One entry of the signature an account presents when it acts as a custom
+// account: an ed25519 signature over the auth payload by a configured signer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerSignature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+#[contracttrait]
+pub trait MerchantAccountTrait {
+    fn initialize(env: Env, merchant: Address, manager: Address, merchant_id: u64);
+    fn get_merchant(env: Env) -> Address;
+    fn get_account_info(env: Env) -> AccountDetails;
+    fn add_token(env: Env, token: Address);
+    fn refund(env: Env, invoice_id: u64, token: Address, amount: i128, to: Address);
+    fn has_token(env: Env, token: Address) -> bool;
+    fn get_balance(env: Env, token: Address) -> i128;
+    fn get_balances(env: Env) -> Vec<TokenBalance>;
+    fn verify_account(env: Env);
+    fn is_verified_account(env: Env) -> bool;
+    fn set_restricted(env: Env, restricted: bool);
+    fn withdraw_to(env: Env, token: Address, amount: i128, recipient: Address);
+    fn set_guardians(env: Env, guardians: Vec<Address>, threshold: u32);
+    fn get_guardians(env: Env) -> Vec<Address>;
+    fn get_recovery_threshold(env: Env) -> u32;
+    fn initiate_recovery(env: Env, guardian: Address, new_merchant: Address);
+    fn approve_recovery(env: Env, guardian: Address);
+    fn execute_recovery(env: Env);
+    fn cancel_recovery(env: Env);
+    fn get_recovery_request(env: Env) -> Option<RecoveryRequest>;
+    fn deposit(env: Env, from: Address, token: Address, amount: i128);
+    fn get_statement(env: Env, page: u64) -> Vec<StatementEntry>;
+    fn get_statement_count(env: Env) -> u64;
+    fn pause_account(env: Env);
+    fn unpause_account(env: Env);
+    fn is_account_paused(env: Env) -> bool;
+    fn deposit_for_invoice(env: Env, from: Address, invoice_id: u64, token: Address, amount: i128);
+    fn release_invoice_funds(env: Env, invoice_id: u64, token: Address);
+    fn get_invoice_funds(env: Env, invoice_id: u64, token: Address) -> i128;
+    fn get_earmarked_balance(env: Env, token: Address) -> i128;
+    fn set_earmark_protection(env: Env, enabled: bool);
+    fn is_earmark_protected(env: Env) -> bool;
+    fn request_withdrawal(env: Env, token: Address, amount: i128, recipient: Address) -> u64;
+    fn approve_withdrawal(env: Env, caller: Address, request_id: u64);
+    fn cancel_withdrawal_request(env: Env, request_id: u64);
+    fn get_withdrawal_request(env: Env, request_id: u64) -> Option<WithdrawalRequest>;
+    fn set_signers(env: Env, signers: Vec<BytesN<32>>, threshold: u32);
+    fn get_signers(env: Env) -> Vec<BytesN<32>>;
+    fn get_signer_threshold(env: Env) -> u32;
+}